@@ -0,0 +1,288 @@
+use crate::config::settings::Settings;
+use crate::error::AppError;
+use crate::github::models::{Repository, WorkflowConclusion, WorkflowRun, WorkflowStatus};
+use crate::ui::app::AppState;
+use crate::utils::icons::get_status_text;
+use crate::utils::time::format_relative_time;
+use serde::Serialize;
+use std::collections::HashSet;
+
+/// How `nighthub status` prints its snapshot.
+#[derive(Debug, Clone, Copy, Default, PartialEq, clap::ValueEnum)]
+pub enum OutputFormat {
+    /// Plain text, one block per repository
+    #[default]
+    Table,
+    /// One JSON document with every repository
+    Json,
+    /// One JSON object per repository and line
+    Ndjson,
+}
+
+/// Overall state of a repository, or of every repository in a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Health {
+    /// Every workflow's latest run succeeded or was skipped.
+    Passing,
+    /// No runs were found.
+    NoRuns,
+    /// A workflow's latest run is queued or in progress, and none failed.
+    Pending,
+    /// A workflow's latest run failed, was cancelled or timed out.
+    Failing,
+    /// The repository could not be refreshed.
+    Error,
+}
+
+impl Health {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Health::Passing => "passing",
+            Health::NoRuns => "no runs",
+            Health::Pending => "pending",
+            Health::Failing => "failing",
+            Health::Error => "error",
+        }
+    }
+
+    /// Health of a repository from its `runs`, newest first; `None` when it was not refreshed.
+    /// Only the latest run of each workflow counts, so a fixed failure no longer fails it.
+    pub fn of_runs(runs: Option<&[WorkflowRun]>) -> Health {
+        let Some(runs) = runs else {
+            return Health::Error;
+        };
+        let mut seen = HashSet::new();
+        let latest: Vec<&WorkflowRun> = runs.iter().filter(|run| seen.insert(run.name.as_str())).collect();
+        if latest.is_empty() {
+            return Health::NoRuns;
+        }
+
+        let failed = latest.iter().any(|run| {
+            run.status == WorkflowStatus::Completed
+                && matches!(
+                    run.conclusion,
+                    Some(WorkflowConclusion::Failure | WorkflowConclusion::Cancelled | WorkflowConclusion::TimedOut)
+                )
+        });
+        if failed {
+            Health::Failing
+        } else if latest.iter().any(|run| run.status != WorkflowStatus::Completed) {
+            Health::Pending
+        } else {
+            Health::Passing
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct RepositorySnapshot {
+    pub repository: Repository,
+    pub status: Health,
+    pub runs: Vec<WorkflowRun>,
+}
+
+/// Every monitored repository and its runs after one refresh.
+#[derive(Debug, Serialize)]
+pub struct StatusReport {
+    pub status: Health,
+    pub repositories: Vec<RepositorySnapshot>,
+}
+
+impl StatusReport {
+    /// Overall status: failing when any repository is, then error, pending and passing.
+    pub fn new(repositories: Vec<RepositorySnapshot>) -> Self {
+        let status = [Health::Failing, Health::Error, Health::Pending, Health::Passing]
+            .into_iter()
+            .find(|health| repositories.iter().any(|snapshot| snapshot.status == *health))
+            .unwrap_or(Health::NoRuns);
+        StatusReport { status, repositories }
+    }
+
+    pub fn from_app_state(app_state: &AppState) -> Self {
+        let repositories = app_state
+            .repositories
+            .iter()
+            .map(|repository| {
+                let runs = app_state.workflow_runs.get(&repository.full_name);
+                RepositorySnapshot {
+                    repository: repository.clone(),
+                    status: Health::of_runs(runs.map(Vec::as_slice)),
+                    runs: runs.cloned().unwrap_or_default(),
+                }
+            })
+            .collect();
+        StatusReport::new(repositories)
+    }
+
+    /// 1 when a workflow is failing, 2 when a repository could not be refreshed, 0 otherwise.
+    pub fn exit_code(&self) -> i32 {
+        match self.status {
+            Health::Failing => 1,
+            Health::Error => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn render(&self, format: OutputFormat) -> Result<String, AppError> {
+        let to_json = |error: serde_json::Error| AppError::ParseError(error.to_string());
+        match format {
+            OutputFormat::Json => serde_json::to_string_pretty(self).map_err(to_json),
+            OutputFormat::Ndjson => self
+                .repositories
+                .iter()
+                .map(|snapshot| serde_json::to_string(snapshot).map_err(to_json))
+                .collect::<Result<Vec<_>, _>>()
+                .map(|lines| lines.join("\n")),
+            OutputFormat::Table => Ok(self.render_table()),
+        }
+    }
+
+    fn render_table(&self) -> String {
+        let runs = self.repositories.iter().flat_map(|snapshot| &snapshot.runs);
+        let name_width = runs.clone().map(|run| run.name.chars().count()).max().unwrap_or(0);
+        let branch_width = runs.map(|run| run.branch.chars().count()).max().unwrap_or(0);
+
+        let mut lines = Vec::new();
+        for snapshot in &self.repositories {
+            lines.push(format!("{}  {}", snapshot.repository.full_name, snapshot.status.as_str()));
+            for run in &snapshot.runs {
+                lines.push(format!(
+                    "  {:<11}  {:<name_width$}  {:<branch_width$}  {}  {}",
+                    get_status_text(&run.status, &run.conclusion),
+                    run.name,
+                    run.branch,
+                    &run.commit_sha[..run.commit_sha.len().min(7)],
+                    format_relative_time(run.created_at)
+                ));
+            }
+        }
+        lines.join("\n")
+    }
+}
+
+/// Refresh every repository once and print the snapshot to stdout. Returns the exit code.
+pub async fn run(settings: Settings, format: OutputFormat) -> Result<i32, AppError> {
+    let mut app_state = AppState::new_without_refresh(settings).await?;
+    app_state.refresh(true).await?;
+
+    let report = StatusReport::from_app_state(&app_state);
+    for snapshot in report.repositories.iter().filter(|snapshot| snapshot.status == Health::Error) {
+        eprintln!("Warning: could not refresh {}", snapshot.repository.full_name);
+    }
+    println!("{}", report.render(format)?);
+    Ok(report.exit_code())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn create_test_run(id: u64, name: &str, status: WorkflowStatus, conclusion: Option<WorkflowConclusion>) -> WorkflowRun {
+        WorkflowRun {
+            id,
+            name: name.to_string(),
+            status,
+            conclusion,
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: "0123456789abcdef".to_string(),
+            actor: "testuser".to_string(),
+            html_url: format!("https://github.com/acme/website/actions/runs/{}", id),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn create_test_snapshot(name: &str, runs: Option<Vec<WorkflowRun>>) -> RepositorySnapshot {
+        RepositorySnapshot {
+            repository: Repository {
+                id: 0,
+                name: name.to_string(),
+                owner: "acme".to_string(),
+                full_name: format!("acme/{}", name),
+                html_url: format!("https://github.com/acme/{}", name),
+                default_branch: Some("main".to_string()),
+                host: None,
+                credential: None,
+            },
+            status: Health::of_runs(runs.as_deref()),
+            runs: runs.unwrap_or_default(),
+        }
+    }
+
+    #[test]
+    fn test_health_of_runs() {
+        let success = Some(WorkflowConclusion::Success);
+        let failure = Some(WorkflowConclusion::Failure);
+        assert_eq!(Health::of_runs(None), Health::Error);
+        assert_eq!(Health::of_runs(Some(&[])), Health::NoRuns);
+
+        // The failure was fixed by a later run of the same workflow
+        let fixed = [
+            create_test_run(2, "CI", WorkflowStatus::Completed, success),
+            create_test_run(1, "CI", WorkflowStatus::Completed, failure),
+        ];
+        assert_eq!(Health::of_runs(Some(&fixed)), Health::Passing);
+
+        let pending = [
+            create_test_run(3, "Deploy", WorkflowStatus::InProgress, None),
+            create_test_run(2, "CI", WorkflowStatus::Completed, success),
+        ];
+        assert_eq!(Health::of_runs(Some(&pending)), Health::Pending);
+
+        let failing = [
+            create_test_run(3, "Deploy", WorkflowStatus::InProgress, None),
+            create_test_run(2, "CI", WorkflowStatus::Completed, Some(WorkflowConclusion::TimedOut)),
+        ];
+        assert_eq!(Health::of_runs(Some(&failing)), Health::Failing);
+    }
+
+    #[test]
+    fn test_exit_code() {
+        let passing = Some(vec![create_test_run(1, "CI", WorkflowStatus::Completed, Some(WorkflowConclusion::Success))]);
+        let failing = Some(vec![create_test_run(2, "CI", WorkflowStatus::Completed, Some(WorkflowConclusion::Failure))]);
+
+        let report = StatusReport::new(vec![create_test_snapshot("website", passing.clone()), create_test_snapshot("api", Some(Vec::new()))]);
+        assert_eq!(report.status, Health::Passing);
+        assert_eq!(report.exit_code(), 0);
+
+        let report = StatusReport::new(vec![create_test_snapshot("website", passing), create_test_snapshot("api", None)]);
+        assert_eq!(report.status, Health::Error);
+        assert_eq!(report.exit_code(), 2);
+
+        // A failing workflow outranks a repository that could not be refreshed
+        let report = StatusReport::new(vec![create_test_snapshot("website", failing), create_test_snapshot("api", None)]);
+        assert_eq!(report.status, Health::Failing);
+        assert_eq!(report.exit_code(), 1);
+    }
+
+    #[test]
+    fn test_render_formats() {
+        let runs = vec![
+            create_test_run(2, "Deploy", WorkflowStatus::InProgress, None),
+            create_test_run(1, "CI", WorkflowStatus::Completed, Some(WorkflowConclusion::Success)),
+        ];
+        let report = StatusReport::new(vec![create_test_snapshot("website", Some(runs)), create_test_snapshot("api", Some(Vec::new()))]);
+
+        let json: serde_json::Value = serde_json::from_str(&report.render(OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(json["status"], "pending");
+        assert_eq!(json["repositories"][0]["repository"]["full_name"], "acme/website");
+        assert_eq!(json["repositories"][0]["runs"][0]["status"], "InProgress");
+        assert_eq!(json["repositories"][1]["status"], "no_runs");
+
+        let ndjson = report.render(OutputFormat::Ndjson).unwrap();
+        let lines: Vec<serde_json::Value> = ndjson.lines().map(|line| serde_json::from_str(line).unwrap()).collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[1]["repository"]["name"], "api");
+
+        let table = report.render(OutputFormat::Table).unwrap();
+        assert!(table.contains("acme/website  pending"));
+        assert!(table.contains("  In Progress  Deploy  main  0123456  Just now"));
+        assert!(table.contains("  Success      CI      main  0123456  Just now"));
+        assert!(table.contains("acme/api  no runs"));
+    }
+}
@@ -0,0 +1,344 @@
+use crate::config::settings::Settings;
+use crate::error::AppError;
+use crate::github::client::GithubClient;
+use crate::github::models::{Job, WorkflowConclusion, WorkflowRun, WorkflowStatus};
+use crate::notify::hooks::ShellHooks;
+use crate::notify::transitions::TransitionDetector;
+use crate::notify::Notifications;
+use crate::utils::icons::get_status_text;
+use crate::utils::local_git::{resolve_revision, LocalHead, PushState};
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Exit code when the runs did not finish within the watch timeout.
+pub const EXIT_TIMEOUT: i32 = 3;
+
+/// Runs `nighthub watch` follows.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WatchTarget {
+    /// Every run triggered for a commit, by full SHA.
+    Commit(String),
+    Run(u64),
+    /// The runs of the newest commit on a branch that has runs.
+    Branch(String),
+}
+
+impl WatchTarget {
+    /// Build the target from the command line flags; without any, the local checkout's HEAD
+    /// is followed. Revisions are resolved in the local checkout, and full SHAs of commits
+    /// that are not fetched are used as they are.
+    pub fn from_args(sha: Option<String>, run: Option<u64>, branch: Option<String>) -> Result<Self, AppError> {
+        if let Some(run) = run {
+            return Ok(WatchTarget::Run(run));
+        }
+        if let Some(branch) = branch {
+            return Ok(WatchTarget::Branch(branch));
+        }
+        let revision = sha.unwrap_or_else(|| "HEAD".to_string());
+        match resolve_revision(Path::new("."), &revision) {
+            Some(sha) => Ok(WatchTarget::Commit(sha)),
+            None if revision.len() == 40 && revision.chars().all(|c| c.is_ascii_hexdigit()) => {
+                Ok(WatchTarget::Commit(revision.to_ascii_lowercase()))
+            }
+            None => Err(AppError::ConfigError(format!("Cannot resolve '{}' to a commit of the local checkout", revision))),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            WatchTarget::Commit(sha) => format!("commit {}", &sha[..sha.len().min(7)]),
+            WatchTarget::Run(id) => format!("run #{}", id),
+            WatchTarget::Branch(branch) => format!("branch {}", branch),
+        }
+    }
+}
+
+/// Turns successive states of runs and their jobs into progress lines, printing each run, job
+/// and step again only when its state changes. Steps are only shown once they have started.
+#[derive(Debug, Default)]
+pub struct ProgressPrinter {
+    states: HashMap<String, &'static str>,
+    finished: HashSet<u64>,
+}
+
+impl ProgressPrinter {
+    /// Whether the run was already reported as completed, so its jobs need no more fetching.
+    pub fn is_finished(&self, run_id: u64) -> bool {
+        self.finished.contains(&run_id)
+    }
+
+    pub fn update(&mut self, run: &WorkflowRun, jobs: &[Job]) -> Vec<String> {
+        let mut lines = Vec::new();
+        for job in jobs {
+            let job_label = format!("{} / {}", run.name, job.name);
+            self.record(&mut lines, format!("job:{}", job.id), &job_label, get_status_text(&job.status, &job.conclusion));
+            for step in job.steps.iter().filter(|step| step.status != WorkflowStatus::Queued) {
+                self.record(
+                    &mut lines,
+                    format!("step:{}:{}", job.id, step.number),
+                    &format!("{} / {}", job_label, step.name),
+                    get_status_text(&step.status, &step.conclusion),
+                );
+            }
+        }
+
+        // The run is reported after its jobs so its conclusion comes last
+        let run_label = format!("{} #{} on {} ({})", run.name, run.id, run.branch, &run.commit_sha[..run.commit_sha.len().min(7)]);
+        let run_state = get_status_text(&run.status, &run.conclusion);
+        let completed = run.status == WorkflowStatus::Completed;
+        if self.record(&mut lines, format!("run:{}", run.id), &run_label, run_state) && completed {
+            // Link the finished run so a failure is one click away
+            if let Some(line) = lines.last_mut() {
+                line.push_str(&format!(" {}", run.html_url));
+            }
+        }
+        if completed {
+            self.finished.insert(run.id);
+        }
+        lines
+    }
+
+    /// Add a line for `label` when `state` differs from the last one recorded under `key`.
+    fn record(&mut self, lines: &mut Vec<String>, key: String, label: &str, state: &'static str) -> bool {
+        let changed = self.states.insert(key, state) != Some(state);
+        if changed {
+            lines.push(format!("{}: {}", label, state));
+        }
+        changed
+    }
+}
+
+/// Exit code once every run has completed: 0 when they all succeeded or were skipped, 1 when
+/// one failed, was cancelled or timed out. `None` while a run is still going or none exist.
+pub fn outcome(runs: &[WorkflowRun]) -> Option<i32> {
+    if runs.is_empty() || runs.iter().any(|run| run.status != WorkflowStatus::Completed) {
+        return None;
+    }
+    let succeeded = runs
+        .iter()
+        .all(|run| matches!(run.conclusion, Some(WorkflowConclusion::Success | WorkflowConclusion::Skipped)));
+    Some(if succeeded { 0 } else { 1 })
+}
+
+/// Runs of the newest commit among `runs`, which are sorted newest first.
+fn newest_commit_runs(mut runs: Vec<WorkflowRun>) -> Vec<WorkflowRun> {
+    if let Some(sha) = runs.first().map(|run| run.commit_sha.clone()) {
+        runs.retain(|run| run.commit_sha == sha);
+    }
+    runs
+}
+
+/// Follow `target` in the repository of the local checkout, printing progress to stdout until
+/// its runs have completed or `timeout` has passed. Changes of the runs after the first poll
+/// are announced like in the monitor, and the hooks and notifications are waited for before
+/// returning the exit code.
+pub async fn run(settings: Settings, target: WatchTarget, timeout: Duration, interval: Duration) -> Result<i32, AppError> {
+    let detected = settings.detect_current_repository()?;
+    // A repository that is also configured is accessed with its configured credential
+    let credential = settings
+        .repositories()
+        .iter()
+        .find(|config| {
+            config.host == detected.host
+                && config.owner.eq_ignore_ascii_case(&detected.owner)
+                && config.name.eq_ignore_ascii_case(&detected.name)
+        })
+        .and_then(|config| config.credential.clone());
+    let (owner, repo) = (detected.owner.clone(), detected.name.clone());
+    // Named like in the monitor, so the notification rules match the same way
+    let repo_name = match &detected.host {
+        Some(host) => format!("{}/{}/{}", host, owner, repo),
+        None => format!("{}/{}", owner, repo),
+    };
+    let notifications = Notifications::new(&settings.notifications)?;
+    let hooks = ShellHooks::new(&settings.hooks, &settings.notifications);
+    let client = GithubClient::for_account(settings, detected.host, credential)?;
+
+    if let (WatchTarget::Commit(sha), Some(head)) = (&target, LocalHead::detect(Path::new(".")))
+        && head.sha == *sha
+        && head.push_state != PushState::Pushed
+    {
+        eprintln!("Warning: HEAD is not pushed yet, its runs start once it is");
+    }
+
+    println!("Watching {} of {}/{}", target.describe(), owner, repo);
+    let deadline = Instant::now() + timeout;
+    let mut printer = ProgressPrinter::default();
+    let mut waiting_reported = false;
+    let mut detector = TransitionDetector::default();
+    let mut previous: Option<Vec<WorkflowRun>> = None;
+    let mut running_hooks = Vec::new();
+    let code = loop {
+        let runs = match &target {
+            WatchTarget::Commit(sha) => client.fetch_commit_runs(&owner, &repo, sha).await?,
+            WatchTarget::Run(id) => vec![client.fetch_workflow_run(&owner, &repo, *id).await?],
+            WatchTarget::Branch(branch) => newest_commit_runs(client.fetch_branch_runs(&owner, &repo, branch).await?),
+        };
+
+        // The first poll only sets the baseline
+        if (notifications.is_enabled() || hooks.is_enabled())
+            && let Some(previous) = &previous
+        {
+            let transitions = detector.detect(&repo_name, previous, &runs);
+            notifications.send(&transitions);
+            running_hooks.extend(hooks.run(&transitions));
+        }
+
+        if runs.is_empty() && !waiting_reported {
+            println!("No runs yet, waiting for them to start");
+            waiting_reported = true;
+        }
+        for run in &runs {
+            if printer.is_finished(run.id) {
+                continue;
+            }
+            let jobs = client.fetch_workflow_jobs(&owner, &repo, run.id).await?;
+            for line in printer.update(run, &jobs) {
+                println!("{}", line);
+            }
+        }
+
+        if let Some(code) = outcome(&runs) {
+            break code;
+        }
+        if Instant::now() >= deadline {
+            eprintln!("Gave up after {} seconds", timeout.as_secs());
+            break EXIT_TIMEOUT;
+        }
+        previous = Some(runs);
+        tokio::time::sleep(interval).await;
+    };
+
+    notifications.flush().await;
+    for running in running_hooks {
+        let _ = running.await;
+    }
+    Ok(code)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::Step;
+    use chrono::Utc;
+
+    fn create_test_run(id: u64, sha: &str, status: WorkflowStatus, conclusion: Option<WorkflowConclusion>) -> WorkflowRun {
+        WorkflowRun {
+            id,
+            name: "CI".to_string(),
+            status,
+            conclusion,
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: sha.to_string(),
+            actor: "testuser".to_string(),
+            html_url: format!("https://github.com/acme/website/actions/runs/{}", id),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn create_test_job(status: WorkflowStatus, steps: Vec<(WorkflowStatus, Option<WorkflowConclusion>)>) -> Job {
+        Job {
+            id: 7,
+            run_id: 1,
+            name: "build".to_string(),
+            status,
+            conclusion: None,
+            started_at: None,
+            completed_at: None,
+            html_url: None,
+            steps: steps
+                .into_iter()
+                .enumerate()
+                .map(|(index, (status, conclusion))| Step {
+                    number: index as u64 + 1,
+                    name: format!("Step {}", index + 1),
+                    status,
+                    conclusion,
+                    started_at: None,
+                    completed_at: None,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_progress_lines_only_on_change() {
+        let mut printer = ProgressPrinter::default();
+        let run = create_test_run(1, "0123456789", WorkflowStatus::InProgress, None);
+        let job = create_test_job(
+            WorkflowStatus::InProgress,
+            vec![(WorkflowStatus::InProgress, None), (WorkflowStatus::Queued, None)],
+        );
+        assert_eq!(printer.update(&run, std::slice::from_ref(&job)), vec![
+            "CI / build: In Progress",
+            "CI / build / Step 1: In Progress",
+            "CI #1 on main (0123456): In Progress",
+        ]);
+        assert!(printer.update(&run, &[job]).is_empty());
+
+        let mut job = create_test_job(
+            WorkflowStatus::Completed,
+            vec![
+                (WorkflowStatus::Completed, Some(WorkflowConclusion::Success)),
+                (WorkflowStatus::Completed, Some(WorkflowConclusion::Failure)),
+            ],
+        );
+        job.conclusion = Some(WorkflowConclusion::Failure);
+        let run = create_test_run(1, "0123456789", WorkflowStatus::Completed, Some(WorkflowConclusion::Failure));
+        assert_eq!(printer.update(&run, &[job]), vec![
+            "CI / build: Failure",
+            "CI / build / Step 1: Success",
+            "CI / build / Step 2: Failure",
+            "CI #1 on main (0123456): Failure https://github.com/acme/website/actions/runs/1",
+        ]);
+        assert!(printer.is_finished(1));
+    }
+
+    #[test]
+    fn test_outcome() {
+        let success = Some(WorkflowConclusion::Success);
+        assert_eq!(outcome(&[]), None);
+        assert_eq!(outcome(&[
+            create_test_run(1, "abc", WorkflowStatus::Completed, success),
+            create_test_run(2, "abc", WorkflowStatus::InProgress, None),
+        ]), None);
+        assert_eq!(outcome(&[
+            create_test_run(1, "abc", WorkflowStatus::Completed, success),
+            create_test_run(2, "abc", WorkflowStatus::Completed, Some(WorkflowConclusion::Skipped)),
+        ]), Some(0));
+        assert_eq!(outcome(&[
+            create_test_run(1, "abc", WorkflowStatus::Completed, success),
+            create_test_run(2, "abc", WorkflowStatus::Completed, Some(WorkflowConclusion::Cancelled)),
+        ]), Some(1));
+    }
+
+    #[test]
+    fn test_newest_commit_runs() {
+        let runs = newest_commit_runs(vec![
+            create_test_run(3, "new", WorkflowStatus::Queued, None),
+            create_test_run(2, "new", WorkflowStatus::InProgress, None),
+            create_test_run(1, "old", WorkflowStatus::Completed, Some(WorkflowConclusion::Failure)),
+        ]);
+        assert_eq!(runs.iter().map(|run| run.id).collect::<Vec<_>>(), vec![3, 2]);
+    }
+
+    #[test]
+    fn test_target_from_args() {
+        assert_eq!(WatchTarget::from_args(None, Some(42), None).unwrap(), WatchTarget::Run(42));
+        assert_eq!(
+            WatchTarget::from_args(None, None, Some("main".to_string())).unwrap(),
+            WatchTarget::Branch("main".to_string())
+        );
+        let sha = "0123456789ABCDEF0123456789abcdef01234567";
+        assert_eq!(
+            WatchTarget::from_args(Some(sha.to_string()), None, None).unwrap(),
+            WatchTarget::Commit(sha.to_ascii_lowercase())
+        );
+        assert!(WatchTarget::from_args(Some("no-such-branch".to_string()), None, None).is_err());
+    }
+}
@@ -0,0 +1,262 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Whose repositories a pattern searches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OwnerKind {
+    Org,
+    User,
+    /// Plain `owner/...` entries, which may be a user or an organization.
+    Any,
+}
+
+/// A REPOS entry or `discovery` pattern that expands to every matching repository, e.g.
+/// `org:acme`, `org:acme/topic:service`, `user:alice` or `acme/api-*`.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct RepositoryPattern {
+    /// GitHub Enterprise Server host, `None` for github.com.
+    pub host: Option<String>,
+    pub kind: OwnerKind,
+    pub owner: String,
+    pub topic: Option<String>,
+    /// Glob over the repository name, `*` and `?` as wildcards.
+    pub name: Option<String>,
+}
+
+/// Which repositories discovery adds next to the ones listed explicitly.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct DiscoveryConfig {
+    pub patterns: Vec<RepositoryPattern>,
+    /// Patterns of repositories to leave out, e.g. `acme/legacy-*` or `org:acme/topic:deprecated`.
+    pub exclude: Vec<RepositoryPattern>,
+    pub include_archived: bool,
+    /// How often the patterns are expanded again to pick up new repositories; 0 only expands
+    /// them at startup.
+    pub refresh_interval_minutes: u64,
+}
+
+impl Default for DiscoveryConfig {
+    fn default() -> Self {
+        Self {
+            patterns: Vec::new(),
+            exclude: Vec::new(),
+            include_archived: false,
+            refresh_interval_minutes: 60,
+        }
+    }
+}
+
+/// Whether a REPOS entry is a pattern rather than a single `owner/repo`.
+pub fn is_pattern(entry: &str) -> bool {
+    entry.contains("org:") || entry.contains("user:") || entry.contains("topic:") || entry.contains(['*', '?'])
+}
+
+fn is_valid_segment(segment: &str) -> bool {
+    !segment.is_empty() && !segment.contains(':')
+}
+
+impl RepositoryPattern {
+    pub fn parse(entry: &str) -> Result<Self, String> {
+        let invalid = |reason: &str| {
+            format!(
+                "Invalid repository pattern {}: {}. Expected org:NAME, user:NAME or OWNER, optionally with a host before and /topic:TOPIC or a /NAME-GLOB after.",
+                entry, reason
+            )
+        };
+        if entry.contains(['@', '#']) {
+            return Err(invalid("branch and workflow filters only apply to single repositories"));
+        }
+
+        let mut segments: Vec<&str> = entry.split('/').collect();
+        // Owners cannot contain dots, host names always do
+        let host = match segments.first() {
+            Some(first) if first.contains('.') && !first.contains(':') && segments.len() > 1 => {
+                Some(segments.remove(0).to_ascii_lowercase())
+            }
+            _ => None,
+        };
+
+        let (kind, owner) = match segments.first().copied().unwrap_or_default() {
+            owner if owner.starts_with("org:") => (OwnerKind::Org, &owner[4..]),
+            owner if owner.starts_with("user:") => (OwnerKind::User, &owner[5..]),
+            owner => (OwnerKind::Any, owner),
+        };
+        if !is_valid_segment(owner) || owner.contains(['*', '?']) {
+            return Err(invalid("missing or invalid owner"));
+        }
+
+        let (mut topic, mut name) = (None, None);
+        for segment in segments.iter().skip(1) {
+            match segment.strip_prefix("topic:") {
+                Some(value) if is_valid_segment(value) && topic.is_none() => topic = Some(value.to_ascii_lowercase()),
+                None if is_valid_segment(segment) && name.is_none() => name = Some(segment.to_string()),
+                _ => return Err(invalid(&format!("unexpected '{}'", segment))),
+            }
+        }
+
+        Ok(RepositoryPattern { host, kind, owner: owner.to_string(), topic, name })
+    }
+
+    /// Search query finding the candidates for this pattern; the name glob is applied to the
+    /// results since search only matches whole words.
+    pub fn search_query(&self, include_archived: bool) -> String {
+        let qualifier = match self.kind {
+            OwnerKind::Org => "org",
+            // `user:` also matches organizations
+            OwnerKind::User | OwnerKind::Any => "user",
+        };
+        let mut query = format!("{}:{}", qualifier, self.owner);
+        if let Some(topic) = &self.topic {
+            query.push_str(&format!("+topic:{}", topic));
+        }
+        if !include_archived {
+            query.push_str("+archived:false");
+        }
+        query
+    }
+
+    /// Whether a repository on `host` matches. `topics` are those GitHub reports for it.
+    pub fn matches(&self, host: Option<&str>, owner: &str, name: &str, topics: &[String]) -> bool {
+        self.host.as_deref() == host
+            && self.owner.eq_ignore_ascii_case(owner)
+            && self.topic.as_ref().is_none_or(|topic| topics.iter().any(|t| t.eq_ignore_ascii_case(topic)))
+            && self.name.as_ref().is_none_or(|glob| glob_matches(glob, name))
+    }
+}
+
+impl TryFrom<String> for RepositoryPattern {
+    type Error = String;
+
+    fn try_from(entry: String) -> Result<Self, Self::Error> {
+        RepositoryPattern::parse(entry.trim())
+    }
+}
+
+impl From<RepositoryPattern> for String {
+    fn from(pattern: RepositoryPattern) -> Self {
+        pattern.to_string()
+    }
+}
+
+impl fmt::Display for RepositoryPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(host) = &self.host {
+            write!(f, "{}/", host)?;
+        }
+        match self.kind {
+            OwnerKind::Org => write!(f, "org:{}", self.owner)?,
+            OwnerKind::User => write!(f, "user:{}", self.owner)?,
+            OwnerKind::Any => write!(f, "{}", self.owner)?,
+        }
+        if let Some(topic) = &self.topic {
+            write!(f, "/topic:{}", topic)?;
+        }
+        if let Some(name) = &self.name {
+            write!(f, "/{}", name)?;
+        }
+        Ok(())
+    }
+}
+
+/// Case-insensitive glob match with `*` for any run of characters and `?` for one.
+pub fn glob_matches(glob: &str, text: &str) -> bool {
+    let glob: Vec<char> = glob.to_lowercase().chars().collect();
+    let text: Vec<char> = text.to_lowercase().chars().collect();
+    let (mut g, mut t) = (0, 0);
+    // Position after the last `*` and the text position it was tried at
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match glob.get(g) {
+            Some('*') => {
+                backtrack = Some((g + 1, t));
+                g += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                g += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((after_star, tried)) => {
+                    g = after_star;
+                    t = tried + 1;
+                    backtrack = Some((after_star, tried + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    glob[g..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_patterns() {
+        let pattern = RepositoryPattern::parse("org:acme/topic:service").unwrap();
+        assert_eq!(pattern.kind, OwnerKind::Org);
+        assert_eq!(pattern.owner, "acme");
+        assert_eq!(pattern.topic.as_deref(), Some("service"));
+        assert_eq!(pattern.name, None);
+
+        let pattern = RepositoryPattern::parse("github.example.com/acme/api-*").unwrap();
+        assert_eq!(pattern.host.as_deref(), Some("github.example.com"));
+        assert_eq!(pattern.kind, OwnerKind::Any);
+        assert_eq!(pattern.name.as_deref(), Some("api-*"));
+
+        assert_eq!(RepositoryPattern::parse("user:alice").unwrap().kind, OwnerKind::User);
+        assert!(RepositoryPattern::parse("org:").is_err());
+        assert!(RepositoryPattern::parse("org:acme/topic:a/topic:b").is_err());
+        assert!(RepositoryPattern::parse("acme/api-*@main").is_err());
+    }
+
+    #[test]
+    fn test_pattern_round_trip() {
+        for entry in ["org:acme/topic:service", "github.example.com/user:alice", "acme/api-*"] {
+            assert_eq!(RepositoryPattern::parse(entry).unwrap().to_string(), entry);
+        }
+    }
+
+    #[test]
+    fn test_is_pattern() {
+        assert!(is_pattern("org:acme"));
+        assert!(is_pattern("acme/api-*"));
+        assert!(!is_pattern("acme/website"));
+        assert!(!is_pattern("github.example.com/acme/website@main"));
+    }
+
+    #[test]
+    fn test_search_query() {
+        let pattern = RepositoryPattern::parse("org:acme/topic:service").unwrap();
+        assert_eq!(pattern.search_query(false), "org:acme+topic:service+archived:false");
+        assert_eq!(RepositoryPattern::parse("acme/api-*").unwrap().search_query(true), "user:acme");
+    }
+
+    #[test]
+    fn test_matches() {
+        let topics = vec!["service".to_string()];
+        let pattern = RepositoryPattern::parse("org:acme/topic:service").unwrap();
+        assert!(pattern.matches(None, "Acme", "billing", &topics));
+        assert!(!pattern.matches(None, "acme", "billing", &[]));
+        assert!(!pattern.matches(Some("github.example.com"), "acme", "billing", &topics));
+
+        let pattern = RepositoryPattern::parse("acme/api-*").unwrap();
+        assert!(pattern.matches(None, "acme", "api-gateway", &[]));
+        assert!(!pattern.matches(None, "acme", "website", &[]));
+    }
+
+    #[test]
+    fn test_glob_matches() {
+        assert!(glob_matches("api-*", "API-gateway"));
+        assert!(glob_matches("*-service", "billing-service"));
+        assert!(glob_matches("a*b*c", "axxbyyc"));
+        assert!(glob_matches("svc-??", "svc-01"));
+        assert!(!glob_matches("svc-??", "svc-1"));
+        assert!(!glob_matches("api-*", "web-api"));
+        assert!(glob_matches("*", ""));
+    }
+}
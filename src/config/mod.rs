@@ -1 +1,4 @@
-pub mod settings;
\ No newline at end of file
+pub mod discovery;
+pub mod notifications;
+pub mod settings;
+pub mod token;
\ No newline at end of file
@@ -0,0 +1,213 @@
+use crate::config::discovery::glob_matches;
+use crate::notify::transitions::{Transition, TransitionKind};
+use serde::{Deserialize, Serialize};
+
+/// Where run state changes are announced, and which ones.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationConfig {
+    /// Desktop notifications through `notify-send`.
+    pub desktop: bool,
+    /// Ring the terminal bell.
+    pub bell: bool,
+    /// Transitions to notify about for runs no rule matches.
+    pub events: Vec<TransitionKind>,
+    /// Checked in order; the first rule matching a run decides which transitions are sent.
+    pub rules: Vec<NotificationRule>,
+    /// Endpoints every transition that passes the rules is posted to.
+    pub webhooks: Vec<WebhookConfig>,
+}
+
+impl Default for NotificationConfig {
+    fn default() -> Self {
+        Self {
+            desktop: false,
+            bell: false,
+            events: vec![TransitionKind::Failed, TransitionKind::Broken, TransitionKind::Fixed],
+            rules: Vec::new(),
+            webhooks: Vec::new(),
+        }
+    }
+}
+
+/// Payload layout of a webhook.
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Default, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookFormat {
+    /// Slack incoming webhook message.
+    Slack,
+    /// Microsoft Teams incoming webhook message card.
+    Teams,
+    /// The transition and its run as JSON, for other services.
+    #[default]
+    Json,
+}
+
+/// An HTTP endpoint transitions are posted to.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(deny_unknown_fields)]
+pub struct WebhookConfig {
+    /// Kept out of the log, since incoming webhook URLs embed their credentials.
+    pub url: String,
+    #[serde(default)]
+    pub format: WebhookFormat,
+    /// Attempts after the first when the endpoint cannot be reached or answers with a server
+    /// error or 429.
+    #[serde(default = "default_webhook_retries")]
+    pub retries: u32,
+}
+
+/// Most retries a webhook may ask for, so that a dead endpoint cannot hold deliveries forever.
+pub const MAX_WEBHOOK_RETRIES: u32 = 10;
+
+fn default_webhook_retries() -> u32 {
+    3
+}
+
+/// Shell commands run when a run changes state. Each gets the run in `NIGHTHUB_*` environment
+/// variables. The `notifications` rules silence them too, but `notifications.events` does not
+/// apply, since configuring a command already picks its events.
+#[derive(Debug, Deserialize, Serialize, Clone)]
+#[serde(default, deny_unknown_fields)]
+pub struct HooksConfig {
+    pub on_queued: Option<String>,
+    pub on_start: Option<String>,
+    /// Also run when a run fixes its workflow.
+    pub on_success: Option<String>,
+    /// Also run when a run breaks its workflow, and for timed out runs.
+    pub on_failure: Option<String>,
+    pub on_cancel: Option<String>,
+    /// Commands still running after this long are killed.
+    pub timeout_seconds: u64,
+}
+
+impl Default for HooksConfig {
+    fn default() -> Self {
+        Self {
+            on_queued: None,
+            on_start: None,
+            on_success: None,
+            on_failure: None,
+            on_cancel: None,
+            timeout_seconds: 60,
+        }
+    }
+}
+
+impl HooksConfig {
+    /// Command to run for a transition of `kind`, if one is configured.
+    pub fn command_for(&self, kind: TransitionKind) -> Option<&str> {
+        match kind {
+            TransitionKind::Queued => self.on_queued.as_deref(),
+            TransitionKind::Started => self.on_start.as_deref(),
+            TransitionKind::Succeeded | TransitionKind::Fixed => self.on_success.as_deref(),
+            TransitionKind::Failed | TransitionKind::Broken => self.on_failure.as_deref(),
+            TransitionKind::Cancelled => self.on_cancel.as_deref(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        [&self.on_queued, &self.on_start, &self.on_success, &self.on_failure, &self.on_cancel]
+            .iter()
+            .all(|command| command.is_none())
+    }
+}
+
+/// Overrides `notifications.events` for the runs of some repositories or branches.
+#[derive(Debug, Deserialize, Serialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct NotificationRule {
+    /// Repository as shown in the UI, `*` and `?` as wildcards, e.g. `acme/*`; any when not set.
+    pub repo: Option<String>,
+    /// Branch, `*` and `?` as wildcards, e.g. `dependabot/*`; any when not set.
+    pub branch: Option<String>,
+    /// Transitions to notify about; a rule without any silences the runs it matches.
+    pub events: Vec<TransitionKind>,
+}
+
+impl NotificationRule {
+    fn matches(&self, repo: &str, branch: &str) -> bool {
+        self.repo.as_ref().is_none_or(|glob| glob_matches(glob, repo))
+            && self.branch.as_ref().is_none_or(|glob| glob_matches(glob, branch))
+    }
+}
+
+impl NotificationConfig {
+    /// Events of the first rule matching the run of `transition`, if any.
+    fn rule_events(&self, transition: &Transition) -> Option<&Vec<TransitionKind>> {
+        self.rules
+            .iter()
+            .find(|rule| rule.matches(&transition.repo, &transition.run.branch))
+            .map(|rule| &rule.events)
+    }
+
+    /// Whether `transition` passes the rules.
+    pub fn wants(&self, transition: &Transition) -> bool {
+        self.rule_events(transition).unwrap_or(&self.events).contains(&transition.kind)
+    }
+
+    /// Whether a rule matching the run of `transition` leaves it out, ignoring `events`.
+    pub fn silences(&self, transition: &Transition) -> bool {
+        self.rule_events(transition).is_some_and(|events| !events.contains(&transition.kind))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{WorkflowConclusion, WorkflowRun, WorkflowStatus};
+    use chrono::Utc;
+
+    fn create_test_transition(repo: &str, branch: &str, kind: TransitionKind) -> Transition {
+        Transition {
+            repo: repo.to_string(),
+            kind,
+            run: WorkflowRun {
+                id: 1,
+                name: "CI".to_string(),
+                status: WorkflowStatus::Completed,
+                conclusion: Some(WorkflowConclusion::Failure),
+                run_attempt: 1,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                branch: branch.to_string(),
+                commit_sha: "abc123".to_string(),
+                actor: "alice".to_string(),
+                html_url: "https://github.com/acme/website/actions/runs/1".to_string(),
+                logs_url: None,
+                pull_requests: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_rules() {
+        let config = NotificationConfig {
+            rules: vec![
+                NotificationRule {
+                    repo: None,
+                    branch: Some("dependabot/*".to_string()),
+                    events: Vec::new(),
+                },
+                NotificationRule {
+                    repo: Some("acme/deploy-*".to_string()),
+                    branch: None,
+                    events: vec![TransitionKind::Started, TransitionKind::Failed],
+                },
+            ],
+            ..NotificationConfig::default()
+        };
+
+        assert!(config.wants(&create_test_transition("acme/website", "main", TransitionKind::Broken)));
+        assert!(!config.wants(&create_test_transition("acme/website", "main", TransitionKind::Started)));
+        assert!(!config.wants(&create_test_transition("acme/website", "dependabot/npm/lodash", TransitionKind::Broken)));
+        assert!(config.wants(&create_test_transition("Acme/Deploy-Prod", "main", TransitionKind::Started)));
+        assert!(!config.wants(&create_test_transition("acme/deploy-prod", "main", TransitionKind::Fixed)));
+
+        // Runs no rule matches are not silenced, whatever `events` holds
+        assert!(!config.silences(&create_test_transition("acme/website", "main", TransitionKind::Started)));
+        assert!(config.silences(&create_test_transition("acme/website", "dependabot/npm/lodash", TransitionKind::Broken)));
+        assert!(!config.silences(&create_test_transition("acme/deploy-prod", "main", TransitionKind::Started)));
+        assert!(config.silences(&create_test_transition("acme/deploy-prod", "main", TransitionKind::Fixed)));
+    }
+}
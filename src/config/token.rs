@@ -0,0 +1,218 @@
+use config::ConfigError;
+use secrecy::SecretString;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use yaml_rust2::YamlLoader;
+
+/// Where a token is read from.
+#[derive(Debug, Clone)]
+pub enum TokenSource {
+    Env(String),
+    /// File holding only the token, which must not be accessible by other users.
+    File(PathBuf),
+    /// Shell command printing the token on stdout, like a git credential helper.
+    Command(String),
+    /// Token the gh CLI stored in its `hosts.yml` after `gh auth login` for this host.
+    GhCli(String),
+}
+
+impl TokenSource {
+    pub fn read(&self, env_var: &impl Fn(&str) -> Option<String>) -> Result<SecretString, ConfigError> {
+        match self {
+            TokenSource::Env(var) => env_var(var)
+                .filter(|token| !token.trim().is_empty())
+                .map(|token| SecretString::new(token.trim().to_string()))
+                .ok_or_else(|| ConfigError::Message(format!("Environment variable {} is not set", var))),
+            TokenSource::File(path) => read_token_file(&expand_home(path)),
+            TokenSource::Command(command) => run_token_command(command),
+            TokenSource::GhCli(host) => gh_cli_token(env_var, host)?.ok_or_else(|| {
+                ConfigError::Message(format!("No gh CLI login found for {}; run `gh auth login -h {}`", host, host))
+            }),
+        }
+    }
+}
+
+/// Resolve a leading `~/` to the home directory, since config files cannot use `$HOME`.
+fn expand_home(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), dirs::home_dir()) {
+        (Ok(rest), Some(home)) => home.join(rest),
+        _ => path.to_path_buf(),
+    }
+}
+
+fn non_empty_token(token: &str, source: &str) -> Result<SecretString, ConfigError> {
+    let token = token.trim();
+    if token.is_empty() {
+        return Err(ConfigError::Message(format!("{} did not contain a token", source)));
+    }
+    Ok(SecretString::new(token.to_string()))
+}
+
+fn read_token_file(path: &Path) -> Result<SecretString, ConfigError> {
+    let contents = read_private_file(path, "Token file")?;
+    non_empty_token(&contents, &format!("Token file {}", path.display()))
+}
+
+/// Read a GitHub App private key, with the same permission check as token files.
+pub fn read_private_key(path: &Path) -> Result<SecretString, ConfigError> {
+    let path = expand_home(path);
+    let contents = read_private_file(&path, "Private key")?;
+    Ok(SecretString::new(contents))
+}
+
+fn read_private_file(path: &Path, what: &str) -> Result<String, ConfigError> {
+    let metadata = std::fs::metadata(path)
+        .map_err(|e| ConfigError::Message(format!("Cannot read {} {}: {}", what.to_lowercase(), path.display(), e)))?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = metadata.permissions().mode() & 0o777;
+        if mode & 0o077 != 0 {
+            return Err(ConfigError::Message(format!(
+                "{} {} is accessible by other users (mode {:o}); run `chmod 600 {}`",
+                what,
+                path.display(),
+                mode,
+                path.display()
+            )));
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = metadata;
+
+    std::fs::read_to_string(path)
+        .map_err(|e| ConfigError::Message(format!("Cannot read {} {}: {}", what.to_lowercase(), path.display(), e)))
+}
+
+fn run_token_command(command: &str) -> Result<SecretString, ConfigError> {
+    let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+    let output = Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| ConfigError::Message(format!("Cannot run token_command `{}`: {}", command, e)))?;
+    if !output.status.success() {
+        return Err(ConfigError::Message(format!(
+            "token_command `{}` failed with {}: {}",
+            command,
+            output.status,
+            String::from_utf8_lossy(&output.stderr).trim()
+        )));
+    }
+
+    let stdout = String::from_utf8(output.stdout)
+        .map_err(|_| ConfigError::Message(format!("token_command `{}` printed invalid UTF-8", command)))?;
+    non_empty_token(&stdout, &format!("Output of token_command `{}`", command))
+}
+
+/// Directory the gh CLI keeps its configuration in, following its own lookup order.
+fn gh_config_dir(env_var: &impl Fn(&str) -> Option<String>) -> Option<PathBuf> {
+    if let Some(dir) = env_var("GH_CONFIG_DIR").filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir));
+    }
+    if let Some(dir) = env_var("XDG_CONFIG_HOME").filter(|dir| !dir.is_empty()) {
+        return Some(PathBuf::from(dir).join("gh"));
+    }
+    if cfg!(windows) {
+        dirs::config_dir().map(|dir| dir.join("GitHub CLI"))
+    } else {
+        dirs::home_dir().map(|home| home.join(".config").join("gh"))
+    }
+}
+
+/// Token stored for `host` in the gh CLI's `hosts.yml`. `None` when gh is not logged in to
+/// that host, or keeps its tokens in the system keyring instead.
+pub fn gh_cli_token(env_var: &impl Fn(&str) -> Option<String>, host: &str) -> Result<Option<SecretString>, ConfigError> {
+    let Some(path) = gh_config_dir(env_var).map(|dir| dir.join("hosts.yml")) else {
+        return Ok(None);
+    };
+    let Ok(contents) = std::fs::read_to_string(&path) else {
+        return Ok(None);
+    };
+    let documents = YamlLoader::load_from_str(&contents)
+        .map_err(|e| ConfigError::Message(format!("Invalid gh CLI config {}: {}", path.display(), e)))?;
+
+    Ok(documents
+        .first()
+        .and_then(|hosts| hosts[host]["oauth_token"].as_str())
+        .filter(|token| !token.is_empty())
+        .map(|token| SecretString::new(token.to_string())))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use secrecy::ExposeSecret;
+
+    fn no_env(_: &str) -> Option<String> {
+        None
+    }
+
+    #[cfg(unix)]
+    fn write_private(path: &Path, contents: &str) {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::write(path, contents).unwrap();
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o600)).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_token_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("token");
+        write_private(&path, "ghp_from_file\n");
+
+        let token = TokenSource::File(path.clone()).read(&no_env).unwrap();
+        assert_eq!(token.expose_secret(), "ghp_from_file");
+
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+        let error = TokenSource::File(path).read(&no_env).unwrap_err().to_string();
+        assert!(error.contains("accessible by other users"), "{}", error);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_token_command() {
+        let token = TokenSource::Command("echo ghp_from_command".to_string()).read(&no_env).unwrap();
+        assert_eq!(token.expose_secret(), "ghp_from_command");
+
+        let error = TokenSource::Command("echo denied >&2; exit 3".to_string())
+            .read(&no_env)
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("failed") && error.contains("denied"), "{}", error);
+
+        assert!(TokenSource::Command("true".to_string()).read(&no_env).is_err());
+    }
+
+    #[test]
+    fn test_gh_cli_token() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("hosts.yml"), r#"
+github.com:
+    user: octocat
+    oauth_token: gho_from_gh
+    git_protocol: https
+github.example.com:
+    user: octocat
+"#).unwrap();
+        let config_dir = dir.path().to_string_lossy().to_string();
+        let env_var = |key: &str| (key == "GH_CONFIG_DIR").then(|| config_dir.clone());
+
+        let token = gh_cli_token(&env_var, "github.com").unwrap().unwrap();
+        assert_eq!(token.expose_secret(), "gho_from_gh");
+        // Logged in, but with the token in the keyring
+        assert!(gh_cli_token(&env_var, "github.example.com").unwrap().is_none());
+        assert!(TokenSource::GhCli("other.example.com".to_string()).read(&env_var).is_err());
+    }
+
+    #[test]
+    fn test_env_token() {
+        let env_var = |key: &str| (key == "WORK_TOKEN").then(|| " ghp_from_env ".to_string());
+        let token = TokenSource::Env("WORK_TOKEN".to_string()).read(&env_var).unwrap();
+        assert_eq!(token.expose_secret(), "ghp_from_env");
+        assert!(TokenSource::Env("OTHER_TOKEN".to_string()).read(&env_var).is_err());
+    }
+}
@@ -1,11 +1,14 @@
 use std::fmt;
 
 #[derive(Debug)]
+#[allow(clippy::enum_variant_names)]
 pub enum AppError {
     ConfigError(String),
     GithubError(String),
     IoError(std::io::Error),
     ParseError(String),
+    /// Rejected by a primary or secondary rate limit; requests may resume at the given time.
+    RateLimited(chrono::DateTime<chrono::Utc>),
 }
 
 impl fmt::Display for AppError {
@@ -15,6 +18,11 @@ impl fmt::Display for AppError {
             AppError::GithubError(e) => write!(f, "GitHub API error: {}", e),
             AppError::IoError(e) => write!(f, "IO error: {}", e),
             AppError::ParseError(e) => write!(f, "Parse error: {}", e),
+            AppError::RateLimited(until) => write!(
+                f,
+                "GitHub API rate limit exceeded, retry after {}",
+                until.with_timezone(&chrono::Local).format("%H:%M:%S")
+            ),
         }
     }
 }
@@ -81,6 +89,13 @@ mod tests {
         assert!(display.contains("Parse error: Test parse error"));
     }
 
+    #[test]
+    fn test_app_error_display_rate_limited() {
+        let error = AppError::RateLimited(chrono::Utc::now());
+        let display = format!("{}", error);
+        assert!(display.contains("GitHub API rate limit exceeded, retry after"));
+    }
+
     #[test]
     fn test_app_error_from_io_error() {
         let io_error = io::Error::new(io::ErrorKind::PermissionDenied, "Access denied");
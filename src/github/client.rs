@@ -1,43 +1,295 @@
-use crate::config::settings::Settings;
+use crate::config::discovery::RepositoryPattern;
+use crate::config::settings::{RepositoryConfig, Settings};
 use crate::error::AppError;
-use crate::github::models::{Repository, WorkflowRun, WorkflowStatus, WorkflowConclusion};
+use crate::github::dispatch::parse_dispatch_inputs;
+use crate::github::logs::{parse_run_log_archive, parse_text_log};
+use crate::github::rate_limit::{rate_limited_until, RateLimit, RateLimitTracker};
+use crate::github::token_info::TokenInfo;
+use crate::utils::logging::log_warn;
+use crate::github::models::{CheckRun, Job, PullRequestRef, PullRequestStatus, Repository, RunAction, RunHistoryPage, Step, Workflow, WorkflowInput, WorkflowRun, WorkflowStatus, WorkflowConclusion};
+use base64::Engine;
 use octocrab::Octocrab;
-use secrecy::SecretString;
+use secrecy::ExposeSecret;
+use serde::de::DeserializeOwned;
 use serde::Deserialize;
 use chrono::{DateTime, Utc};
 use async_trait::async_trait;
+use http_body_util::BodyExt;
+use std::collections::HashMap;
 use std::time::Duration;
 
 #[async_trait]
 pub trait GitHubApiClient {
-    async fn get_workflow_runs(&self, route: &str) -> Result<WorkflowRunsResponse, AppError>;
+    /// With `etag` set the request is conditional and may come back `NotModified`.
+    async fn get_workflow_runs(&self, route: &str, etag: Option<String>) -> Result<Conditional<WorkflowRunsResponse>, AppError>;
+    async fn get_workflow_run(&self, route: &str) -> Result<ApiWorkflowRun, AppError>;
     async fn get_repository(&self, route: &str) -> Result<ApiRepository, AppError>;
+    async fn get_workflow_jobs(&self, route: &str) -> Result<WorkflowJobsResponse, AppError>;
+    async fn get_logs(&self, route: &str) -> Result<Vec<u8>, AppError>;
+    async fn post_action(&self, route: &str) -> Result<(), AppError>;
+    async fn get_workflows(&self, route: &str) -> Result<WorkflowsResponse, AppError>;
+    async fn get_content(&self, route: &str) -> Result<ApiContent, AppError>;
+    async fn post_dispatch(&self, route: &str, body: serde_json::Value) -> Result<(), AppError>;
+    async fn get_token_info(&self, route: &str) -> Result<TokenInfo, AppError>;
+    async fn search_repositories(&self, route: &str) -> Result<RepositorySearchResponse, AppError>;
+    async fn get_pull_requests(&self, route: &str) -> Result<Vec<ApiPullRequest>, AppError>;
+    async fn get_check_runs(&self, route: &str) -> Result<CheckRunsResponse, AppError>;
 }
 
 #[derive(Clone)]
 pub struct GithubClient {
     client: std::sync::Arc<Box<dyn GitHubApiClient + Send + Sync>>,
     settings: Settings,
+    etag_cache: EtagCache,
+    pull_request_cache: PullRequestCache,
+    rate_limits: RateLimitTracker,
+    /// GitHub Enterprise Server host this client talks to, `None` for github.com.
+    host: Option<String>,
+    /// Named credential the client authenticates with, `None` for `github_token`.
+    credential: Option<String>,
 }
 
 struct OctocrabAdapter {
-    inner: Octocrab,
+    /// Token client, or the app client authenticating with a JWT when `installations` is set.
+    inner: std::sync::Arc<Octocrab>,
+    installations: Option<InstallationCache>,
+    rate_limits: RateLimitTracker,
+}
+
+/// Installation clients of a GitHub App per repository owner. Each keeps its installation
+/// token, which octocrab renews shortly before the one-hour expiry, so the clients are shared
+/// rather than cloned.
+type InstallationCache = std::sync::Arc<std::sync::Mutex<HashMap<String, std::sync::Arc<Octocrab>>>>;
+
+/// Owner and name of the repository a `/repos/{owner}/{repo}/...` route targets.
+fn route_repository(route: &str) -> Option<(&str, &str)> {
+    let mut segments = route.strip_prefix("/repos/")?.split(['/', '?']);
+    match (segments.next(), segments.next()) {
+        (Some(owner), Some(repo)) if !owner.is_empty() && !repo.is_empty() => Some((owner, repo)),
+        _ => None,
+    }
+}
+
+/// Account a GitHub App needs the installation of to send `route`, with the routes to look
+/// that installation up with, tried in order: the owner of a `/repos/{owner}/{repo}/...` route,
+/// or the `org:` or `user:` owner a repository search is scoped to. Search rejects the app's
+/// own JWT, so it also has to go through an installation.
+fn route_installation(route: &str) -> Option<(&str, Vec<String>)> {
+    if let Some((owner, repo)) = route_repository(route) {
+        return Some((owner, vec![format!("/repos/{}/{}/installation", owner, repo)]));
+    }
+    let qualifier = route.strip_prefix("/search/repositories?q=")?.split(['+', '&']).next()?;
+    match qualifier.split_once(':')? {
+        ("org", owner) if !owner.is_empty() => Some((owner, vec![format!("/orgs/{}/installation", owner)])),
+        // `user:` searches also cover organizations
+        ("user", owner) if !owner.is_empty() => Some((
+            owner,
+            vec![format!("/users/{}/installation", owner), format!("/orgs/{}/installation", owner)],
+        )),
+        _ => None,
+    }
+}
+
+fn is_not_found(error: &octocrab::Error) -> bool {
+    matches!(error, octocrab::Error::GitHub { source, .. } if source.status_code == http::StatusCode::NOT_FOUND)
+}
+
+impl OctocrabAdapter {
+    /// Client to send `route` with: the installation on the repository's or searched owner
+    /// when authenticating as a GitHub App, looked up on first use.
+    async fn client_for(&self, route: &str) -> Result<std::sync::Arc<Octocrab>, AppError> {
+        let (Some(installations), Some((owner, lookups))) = (&self.installations, route_installation(route)) else {
+            return Ok(self.inner.clone());
+        };
+        if let Some(client) = installations.lock().unwrap().get(owner) {
+            return Ok(client.clone());
+        }
+
+        let mut installation = None;
+        for lookup in &lookups {
+            match self.inner.get::<octocrab::models::Installation, _, ()>(lookup, None).await {
+                Ok(found) => {
+                    installation = Some(found);
+                    break;
+                }
+                Err(e) if is_not_found(&e) => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+        let installation =
+            installation.ok_or_else(|| AppError::ConfigError(format!("The GitHub App is not installed on {}", owner)))?;
+        let client = std::sync::Arc::new(self.inner.installation(installation.id));
+        installations.lock().unwrap().insert(owner.to_string(), client.clone());
+        Ok(client)
+    }
+
+    /// Record the rate limit headers of a response and turn rate limit rejections into
+    /// `AppError::RateLimited` instead of a generic GitHub error.
+    fn inspect_response(&self, status: http::StatusCode, headers: &http::HeaderMap) -> Result<(), AppError> {
+        // Search has a small budget of its own that says nothing about the one refreshes use
+        let resource = headers.get("x-ratelimit-resource").and_then(|value| value.to_str().ok());
+        if resource.is_some_and(|resource| resource != "core") {
+            return Ok(());
+        }
+        if let Some(rate_limit) = RateLimit::from_headers(headers) {
+            self.rate_limits.record(rate_limit);
+        }
+        if let Some(until) = rate_limited_until(status, headers, Utc::now()) {
+            self.rate_limits.pause_until(until);
+            return Err(AppError::RateLimited(until));
+        }
+        Ok(())
+    }
+
+    async fn get_json<T: DeserializeOwned>(&self, route: &str) -> Result<T, AppError> {
+        let response = self.client_for(route).await?._get(route).await?;
+        self.inspect_response(response.status(), response.headers())?;
+        let response = octocrab::map_github_error(response).await?;
+        let body = response.into_body().collect().await?.to_bytes();
+        serde_json::from_slice(&body).map_err(|e| AppError::ParseError(format!("Invalid response from {}: {}", route, e)))
+    }
 }
 
 #[async_trait]
 impl GitHubApiClient for OctocrabAdapter {
-    async fn get_workflow_runs(&self, route: &str) -> Result<WorkflowRunsResponse, AppError> {
-        self.inner.get(route, None::<&()>).await.map_err(AppError::from)
+    async fn get_workflow_runs(&self, route: &str, etag: Option<String>) -> Result<Conditional<WorkflowRunsResponse>, AppError> {
+        let mut headers = http::HeaderMap::new();
+        if let Some(value) = etag.as_deref().and_then(|etag| http::HeaderValue::from_str(etag).ok()) {
+            headers.insert(http::header::IF_NONE_MATCH, value);
+        }
+
+        let response = self.client_for(route).await?._get_with_headers(route, Some(headers)).await?;
+        self.inspect_response(response.status(), response.headers())?;
+        if response.status() == http::StatusCode::NOT_MODIFIED {
+            return Ok(Conditional::NotModified);
+        }
+        let response = octocrab::map_github_error(response).await?;
+        let etag = response
+            .headers()
+            .get(http::header::ETAG)
+            .and_then(|value| value.to_str().ok())
+            .map(str::to_string);
+        let next_page = next_page(response.headers());
+        let body = response.into_body().collect().await?.to_bytes();
+        let mut value: WorkflowRunsResponse = serde_json::from_slice(&body)
+            .map_err(|e| AppError::ParseError(format!("Invalid workflow runs response: {}", e)))?;
+        value.next_page = next_page;
+        Ok(Conditional::Modified { value, etag })
+    }
+
+    async fn get_workflow_run(&self, route: &str) -> Result<ApiWorkflowRun, AppError> {
+        self.get_json(route).await
     }
 
     async fn get_repository(&self, route: &str) -> Result<ApiRepository, AppError> {
-        self.inner.get(route, None::<&()>).await.map_err(AppError::from)
+        self.get_json(route).await
+    }
+
+    async fn get_workflow_jobs(&self, route: &str) -> Result<WorkflowJobsResponse, AppError> {
+        self.get_json(route).await
     }
+
+    async fn get_logs(&self, route: &str) -> Result<Vec<u8>, AppError> {
+        // Log endpoints answer with a redirect to a short-lived download URL
+        let client = self.client_for(route).await?;
+        let response = client._get(route).await?;
+        self.inspect_response(response.status(), response.headers())?;
+        let response = client.follow_location_to_data(response).await?;
+        let response = octocrab::map_github_error(response).await?;
+        let body = response.into_body().collect().await?;
+        Ok(body.to_bytes().to_vec())
+    }
+
+    async fn post_action(&self, route: &str) -> Result<(), AppError> {
+        // Action endpoints answer 201/202 with an empty body
+        let response = self.client_for(route).await?._post(route, None::<&()>).await?;
+        self.inspect_response(response.status(), response.headers())?;
+        octocrab::map_github_error(response).await?;
+        Ok(())
+    }
+
+    async fn get_workflows(&self, route: &str) -> Result<WorkflowsResponse, AppError> {
+        self.get_json(route).await
+    }
+
+    async fn get_content(&self, route: &str) -> Result<ApiContent, AppError> {
+        self.get_json(route).await
+    }
+
+    async fn post_dispatch(&self, route: &str, body: serde_json::Value) -> Result<(), AppError> {
+        // The dispatch endpoint answers 204 with an empty body
+        let response = self.client_for(route).await?._post(route, Some(&body)).await?;
+        self.inspect_response(response.status(), response.headers())?;
+        octocrab::map_github_error(response).await?;
+        Ok(())
+    }
+
+    async fn get_token_info(&self, route: &str) -> Result<TokenInfo, AppError> {
+        if self.installations.is_some() {
+            // Fetching the app itself checks the app ID and private key
+            let response = self.inner._get("/app").await?;
+            if response.status() == http::StatusCode::UNAUTHORIZED {
+                return Err(AppError::ConfigError(
+                    "GitHub rejected the app's JWT; check app_id and the private key".to_string(),
+                ));
+            }
+            octocrab::map_github_error(response).await?;
+            return Ok(TokenInfo::default());
+        }
+
+        let response = self.inner._get(route).await?;
+        self.inspect_response(response.status(), response.headers())?;
+        let scopes = TokenInfo::scopes_from_headers(response.headers());
+        match response.status() {
+            http::StatusCode::UNAUTHORIZED => {
+                return Err(AppError::ConfigError("GitHub rejected the token (401 Bad credentials)".to_string()));
+            }
+            // Installation tokens are valid but may not read `/user`
+            http::StatusCode::FORBIDDEN => return Ok(TokenInfo { login: None, scopes }),
+            _ => {}
+        }
+        let response = octocrab::map_github_error(response).await?;
+        let body = response.into_body().collect().await?.to_bytes();
+        let user: ApiUser = serde_json::from_slice(&body)
+            .map_err(|e| AppError::ParseError(format!("Invalid response from {}: {}", route, e)))?;
+        Ok(TokenInfo { login: Some(user.login), scopes })
+    }
+
+    async fn search_repositories(&self, route: &str) -> Result<RepositorySearchResponse, AppError> {
+        self.get_json(route).await
+    }
+
+    async fn get_pull_requests(&self, route: &str) -> Result<Vec<ApiPullRequest>, AppError> {
+        self.get_json(route).await
+    }
+
+    async fn get_check_runs(&self, route: &str) -> Result<CheckRunsResponse, AppError> {
+        self.get_json(route).await
+    }
+}
+
+/// Outcome of a conditional GET sent with `If-None-Match`.
+#[derive(Debug, Clone)]
+pub enum Conditional<T> {
+    Modified { value: T, etag: Option<String> },
+    /// 304: unchanged since the ETag was issued, which GitHub does not count against the rate limit.
+    NotModified,
 }
 
+/// Last runs listing and its ETag per route, shared by all clones of a `GithubClient`.
+type EtagCache = std::sync::Arc<std::sync::Mutex<HashMap<String, (String, WorkflowRunsResponse)>>>;
+
+/// Title and author of pull requests per `owner/repo`, shared by all clones of a `GithubClient`.
+/// `None` marks numbers that were not among the open pull requests, so they are not looked up
+/// again on every refresh.
+type PullRequestCache = std::sync::Arc<std::sync::Mutex<HashMap<String, HashMap<u64, Option<(String, String)>>>>>;
+
 #[derive(Debug, Deserialize, Clone)]
 pub struct WorkflowRunsResponse {
     workflow_runs: Vec<ApiWorkflowRun>,
+    /// Page linked as `rel="next"` in the `Link` header, `None` on the last page.
+    #[serde(skip)]
+    next_page: Option<u32>,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -46,6 +298,8 @@ pub struct ApiWorkflowRun {
     name: String,
     status: String,
     conclusion: Option<String>,
+    #[serde(default = "first_attempt")]
+    run_attempt: u64,
     created_at: DateTime<Utc>,
     updated_at: DateTime<Utc>,
     head_branch: Option<String>,
@@ -53,6 +307,93 @@ pub struct ApiWorkflowRun {
     html_url: String,
     logs_url: Option<String>,
     actor: ApiUser,
+    #[serde(default)]
+    pull_requests: Vec<ApiPullRequestRef>,
+}
+
+/// Pull request as embedded in a workflow run, without title or author.
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiPullRequestRef {
+    number: u64,
+    head: ApiBranch,
+    base: ApiBranch,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiBranch {
+    #[serde(rename = "ref")]
+    branch: String,
+    sha: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiPullRequest {
+    number: u64,
+    title: String,
+    user: ApiUser,
+    head: ApiBranch,
+    base: ApiBranch,
+    html_url: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct CheckRunsResponse {
+    check_runs: Vec<ApiCheckRun>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiCheckRun {
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WorkflowJobsResponse {
+    jobs: Vec<ApiJob>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiJob {
+    id: u64,
+    run_id: u64,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+    html_url: Option<String>,
+    #[serde(default)]
+    steps: Vec<ApiStep>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiStep {
+    number: u64,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct WorkflowsResponse {
+    workflows: Vec<ApiWorkflow>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiWorkflow {
+    id: u64,
+    name: String,
+    path: String,
+    state: String,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct ApiContent {
+    content: String,
+    encoding: String,
 }
 
 #[derive(Debug, Deserialize, Clone)]
@@ -68,26 +409,173 @@ pub struct ApiRepository {
     html_url: String,
     default_branch: Option<String>,
     owner: ApiUser,
+    #[serde(default)]
+    archived: bool,
+    #[serde(default)]
+    topics: Vec<String>,
+}
+
+#[derive(Debug, Deserialize, Clone)]
+pub struct RepositorySearchResponse {
+    /// Matches of the query, including those beyond the results the search API returns.
+    #[serde(default)]
+    total_count: u64,
+    items: Vec<ApiRepository>,
+}
+
+fn parse_status(status: &str) -> WorkflowStatus {
+    match status {
+        "queued" => WorkflowStatus::Queued,
+        "in_progress" => WorkflowStatus::InProgress,
+        "completed" => WorkflowStatus::Completed,
+        _ => WorkflowStatus::Queued,
+    }
+}
+
+fn parse_conclusion(conclusion: Option<&str>) -> Option<WorkflowConclusion> {
+    conclusion.map(|c| match c {
+        "success" => WorkflowConclusion::Success,
+        "failure" => WorkflowConclusion::Failure,
+        "cancelled" => WorkflowConclusion::Cancelled,
+        "skipped" => WorkflowConclusion::Skipped,
+        "timed_out" => WorkflowConclusion::TimedOut,
+        _ => WorkflowConclusion::Skipped,
+    })
+}
+
+/// Number of the page linked as `rel="next"` in a `Link` header, e.g.
+/// `<https://api.github.com/repositories/1/actions/runs?per_page=30&page=2>; rel="next"`.
+fn next_page(headers: &http::HeaderMap) -> Option<u32> {
+    let link = headers.get(http::header::LINK)?.to_str().ok()?;
+    link.split(',').find_map(|entry| {
+        let (url, params) = entry.split_once(';')?;
+        if !params.split(';').any(|param| param.trim() == "rel=\"next\"") {
+            return None;
+        }
+        let query = url.trim().trim_start_matches('<').trim_end_matches('>').split_once('?')?.1;
+        query.split('&').find_map(|pair| pair.strip_prefix("page=")?.parse().ok())
+    })
+}
+
+fn first_attempt() -> u64 {
+    1
+}
+
+fn to_workflow_run(raw_run: ApiWorkflowRun) -> WorkflowRun {
+    WorkflowRun {
+        id: raw_run.id,
+        name: raw_run.name,
+        status: parse_status(&raw_run.status),
+        conclusion: parse_conclusion(raw_run.conclusion.as_deref()),
+        run_attempt: raw_run.run_attempt,
+        created_at: raw_run.created_at,
+        updated_at: raw_run.updated_at,
+        branch: raw_run.head_branch.unwrap_or_default(),
+        commit_sha: raw_run.head_sha,
+        actor: raw_run.actor.login,
+        html_url: raw_run.html_url,
+        logs_url: raw_run.logs_url,
+        pull_requests: raw_run
+            .pull_requests
+            .into_iter()
+            .map(|pull_request| PullRequestRef {
+                number: pull_request.number,
+                title: None,
+                author: None,
+                head_branch: pull_request.head.branch,
+                base_branch: pull_request.base.branch,
+            })
+            .collect(),
+    }
+}
+
+/// Percent-encode a query parameter value, keeping `/` so branch names stay readable.
+fn encode_query_value(value: &str) -> String {
+    value
+        .bytes()
+        .map(|byte| match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'/' => (byte as char).to_string(),
+            _ => format!("%{:02X}", byte),
+        })
+        .collect()
+}
+
+fn decode_content(content: &ApiContent) -> Result<String, AppError> {
+    if content.encoding != "base64" {
+        return Err(AppError::ParseError(format!("Unsupported content encoding: {}", content.encoding)));
+    }
+    // GitHub wraps the base64 payload every 60 characters
+    let encoded: String = content.content.chars().filter(|c| !c.is_whitespace()).collect();
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(|e| AppError::ParseError(format!("Invalid base64 content: {}", e)))?;
+    String::from_utf8(bytes).map_err(|e| AppError::ParseError(format!("Workflow file is not UTF-8: {}", e)))
 }
 
 impl GithubClient {
     pub fn new(settings: Settings) -> Result<Self, AppError> {
-        let octocrab = Octocrab::builder()
-            .personal_token(SecretString::new(settings.github_token().to_string()))
-            .build()?;
-        let client = std::sync::Arc::new(Box::new(OctocrabAdapter { inner: octocrab }) as Box<dyn GitHubApiClient + Send + Sync>);
-        Ok(GithubClient { client, settings })
+        Self::for_account(settings, None, None)
+    }
+
+    /// Client for the repositories on `host` (a GitHub Enterprise Server configured under
+    /// `hosts`, or github.com when `None`) that are accessed with `credential`.
+    pub fn for_account(settings: Settings, host: Option<String>, credential: Option<String>) -> Result<Self, AppError> {
+        let mut builder = Octocrab::builder();
+        let app = settings.app_for(credential.as_deref());
+        match (app, settings.token_for(credential.as_deref()), &credential) {
+            (Some(app), _, _) => {
+                let private_key = app.private_key.as_ref().ok_or_else(|| {
+                    AppError::ConfigError(format!("Private key {} was not loaded", app.private_key_file.display()))
+                })?;
+                let key = jsonwebtoken::EncodingKey::from_rsa_pem(private_key.expose_secret().as_bytes())
+                    .map_err(|e| AppError::ConfigError(format!("Invalid private key: {}", e)))?;
+                builder = builder.app(octocrab::models::AppId(app.app_id), key);
+            }
+            (None, Some(token), _) => builder = builder.personal_token(token.clone()),
+            // Without a token of its own the default client is only kept for its budget display,
+            // since every repository then uses a named credential
+            (None, None, None) => {}
+            (None, None, Some(name)) => {
+                return Err(AppError::ConfigError(format!("Unknown credential '{}'", name)));
+            }
+        }
+        if let Some(api_url) = settings.api_url(host.as_deref()) {
+            builder = builder.base_uri(api_url.as_str()).map_err(|e| {
+                AppError::ConfigError(format!("Invalid API URL {} for host {}: {}", api_url, host.as_deref().unwrap_or_default(), e))
+            })?;
+        }
+        let octocrab = builder.build()?;
+        let rate_limits = RateLimitTracker::default();
+        let adapter = OctocrabAdapter {
+            inner: std::sync::Arc::new(octocrab),
+            installations: app.map(|_| InstallationCache::default()),
+            rate_limits: rate_limits.clone(),
+        };
+        let client = std::sync::Arc::new(Box::new(adapter) as Box<dyn GitHubApiClient + Send + Sync>);
+        Ok(GithubClient {
+            client,
+            settings,
+            etag_cache: EtagCache::default(),
+            pull_request_cache: PullRequestCache::default(),
+            rate_limits,
+            host,
+            credential,
+        })
     }
 
-    async fn retry_with_backoff<F, T, E>(&self, operation: F) -> Result<T, E>
+    async fn retry_with_backoff<F, T>(&self, operation: F) -> Result<T, AppError>
     where
-        F: Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<T, E>> + Send>>,
-        E: std::fmt::Display,
+        F: Fn() -> std::pin::Pin<Box<dyn std::future::Future<Output = Result<T, AppError>> + Send>>,
     {
         let mut delay = Duration::from_secs(1);
         let max_retries = self.settings.monitoring.max_retries;
         
         for attempt in 0..=max_retries {
+            // Requests sent while rate limited would only be rejected again
+            if let Some(until) = self.rate_limits.paused_until(Utc::now()) {
+                return Err(AppError::RateLimited(until));
+            }
+
             match operation().await {
                 Ok(result) => return Ok(result),
                 Err(e) => {
@@ -95,14 +583,15 @@ impl GithubClient {
                         return Err(e);
                     }
                     
-                    // Check if error is retryable (rate limit, server error, etc.)
+                    // Check if error is retryable (timeout, server error, etc.). Rate limits are
+                    // left to the refresh scheduler, which waits for the reset instead.
                     let error_str = e.to_string();
-                    let is_retryable = error_str.contains("rate limit") 
-                        || error_str.contains("timeout")
-                        || error_str.contains("connection")
-                        || error_str.contains("502")
-                        || error_str.contains("503")
-                        || error_str.contains("504");
+                    let is_retryable = !matches!(e, AppError::RateLimited(_))
+                        && (error_str.contains("timeout")
+                            || error_str.contains("connection")
+                            || error_str.contains("502")
+                            || error_str.contains("503")
+                            || error_str.contains("504"));
                     
                     if !is_retryable {
                         return Err(e);
@@ -125,7 +614,40 @@ impl GithubClient {
     pub fn new_with_client(settings: Settings, client: Box<dyn GitHubApiClient + Send + Sync>) -> Self {
         GithubClient { 
             client: std::sync::Arc::new(client as Box<dyn GitHubApiClient + Send + Sync>), 
-            settings 
+            settings,
+            etag_cache: EtagCache::default(),
+            pull_request_cache: PullRequestCache::default(),
+            rate_limits: RateLimitTracker::default(),
+            host: None,
+            credential: None,
+        }
+    }
+
+    /// Rate limit budget observed on the responses of this client and its clones.
+    pub fn rate_limits(&self) -> &RateLimitTracker {
+        &self.rate_limits
+    }
+
+    pub fn host(&self) -> Option<&str> {
+        self.host.as_deref()
+    }
+
+    pub fn credential(&self) -> Option<&str> {
+        self.credential.as_deref()
+    }
+
+    /// Whether this client serves the repositories configured with `host` and `credential`.
+    pub fn serves(&self, host: Option<&str>, credential: Option<&str>) -> bool {
+        self.host() == host && self.credential() == credential
+    }
+
+    /// `credential@host` with the defaults left out, `None` for the default github.com client.
+    pub fn account_label(&self) -> Option<String> {
+        match (self.credential(), self.host()) {
+            (None, None) => None,
+            (Some(credential), None) => Some(credential.to_string()),
+            (None, Some(host)) => Some(host.to_string()),
+            (Some(credential), Some(host)) => Some(format!("{}@{}", credential, host)),
         }
     }
 
@@ -134,160 +656,762 @@ impl GithubClient {
         &self.settings
     }
 
+    /// Routes listing `page` of the runs of a repository, `per_page` runs each, narrowed to the
+    /// branch and workflows set in its `RepositoryConfig`. Each workflow filter needs its own
+    /// request.
+    fn workflow_run_routes(&self, owner: &str, repo: &str, per_page: usize, page: Option<u32>) -> Vec<String> {
+        let config = self.settings.repositories().iter().find(|config| {
+            self.serves(config.host.as_deref(), config.credential.as_deref())
+                && config.owner.eq_ignore_ascii_case(owner)
+                && config.name.eq_ignore_ascii_case(repo)
+        });
+        let mut params: Vec<String> = config
+            .and_then(|config| config.branch.as_deref())
+            .map(|branch| format!("branch={}", encode_query_value(branch)))
+            .into_iter()
+            .collect();
+        // GitHub caps pages at 100 runs
+        params.push(format!("per_page={}", per_page.clamp(1, 100)));
+        params.extend(page.map(|page| format!("page={}", page)));
+        let query = format!("?{}", params.join("&"));
+
+        match config.and_then(|config| config.workflows.as_ref()) {
+            Some(workflows) => workflows
+                .iter()
+                .map(|workflow| format!("/repos/{}/{}/actions/workflows/{}/runs{}", owner, repo, workflow, query))
+                .collect(),
+            None => vec![format!("/repos/{}/{}/actions/runs{}", owner, repo, query)],
+        }
+    }
+
     pub async fn fetch_workflow_runs(
         &self,
         owner: &str,
         repo: &str,
     ) -> Result<Vec<WorkflowRun>, AppError> {
         let client = self.client.clone();
-        let owner = owner.to_string();
-        let repo = repo.to_string();
+        let etag_cache = self.etag_cache.clone();
         let workflow_runs_per_repo = self.settings.monitoring.workflow_runs_per_repo;
+        let routes = self.workflow_run_routes(owner, repo, workflow_runs_per_repo, None);
         
-        self.retry_with_backoff(move || {
+        let mut runs = self.retry_with_backoff(move || {
             let client = client.clone();
-            let owner = owner.clone();
-            let repo = repo.clone();
-            let route = format!("/repos/{}/{}/actions/runs", owner, repo);
+            let etag_cache = etag_cache.clone();
+            let routes = routes.clone();
             
             Box::pin(async move {
-                // Add timeout to prevent hanging requests
-                let response = tokio::time::timeout(
-                    Duration::from_secs(30),
-                    client.get_workflow_runs(&route)
-                ).await
-                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
-
-                let mut all_runs: Vec<WorkflowRun> = response.workflow_runs
-                    .into_iter()
-                    .map(|raw_run| {
-                        let status = match raw_run.status.as_str() {
-                            "queued" => WorkflowStatus::Queued,
-                            "in_progress" => WorkflowStatus::InProgress,
-                            "completed" => WorkflowStatus::Completed,
-                            _ => WorkflowStatus::Queued,
-                        };
-
-                        let conclusion = raw_run.conclusion.as_ref().map(|c| match c.as_str() {
-                            "success" => WorkflowConclusion::Success,
-                            "failure" => WorkflowConclusion::Failure,
-                            "cancelled" => WorkflowConclusion::Cancelled,
-                            "skipped" => WorkflowConclusion::Skipped,
-                            "timed_out" => WorkflowConclusion::TimedOut,
-                            _ => WorkflowConclusion::Skipped,
-                        });
-
-                        WorkflowRun {
-                            id: raw_run.id,
-                            name: raw_run.name,
-                            status,
-                            conclusion,
-                            created_at: raw_run.created_at,
-                            updated_at: raw_run.updated_at,
-                            branch: raw_run.head_branch.unwrap_or_default(),
-                            commit_sha: raw_run.head_sha,
-                            actor: raw_run.actor.login,
-                            html_url: raw_run.html_url,
-                            logs_url: raw_run.logs_url,
+                let mut all_runs: Vec<WorkflowRun> = Vec::new();
+                for route in routes {
+                    let etag = etag_cache.lock().unwrap().get(&route).map(|(etag, _)| etag.clone());
+
+                    // Add timeout to prevent hanging requests
+                    let conditional = tokio::time::timeout(
+                        Duration::from_secs(30),
+                        client.get_workflow_runs(&route, etag)
+                    ).await
+                    .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                    let response = match conditional {
+                        Conditional::Modified { value, etag } => {
+                            let mut cache = etag_cache.lock().unwrap();
+                            match etag {
+                                Some(etag) => cache.insert(route.clone(), (etag, value.clone())),
+                                None => cache.remove(&route),
+                            };
+                            value
                         }
-                    })
-                    .collect();
+                        Conditional::NotModified => etag_cache
+                            .lock()
+                            .unwrap()
+                            .get(&route)
+                            .map(|(_, cached)| cached.clone())
+                            .ok_or_else(|| AppError::GithubError(format!("Not modified without a cached response for {}", route)))?,
+                    };
+
+                    all_runs.extend(response.workflow_runs.into_iter().map(to_workflow_run));
+                }
 
-                all_runs.sort_by(|a, b| b.created_at.cmp(&a.created_at));
+                all_runs.sort_by_key(|run| std::cmp::Reverse(run.created_at));
                 all_runs.truncate(workflow_runs_per_repo);
 
                 Ok(all_runs)
             })
+        }).await?;
+
+        self.annotate_pull_requests(owner, repo, &mut runs).await;
+        Ok(runs)
+    }
+
+    /// Fill in the title and author of the pull requests the runs were triggered for. Numbers
+    /// not seen before cost one listing of the open pull requests; when it fails, the runs are
+    /// returned without titles rather than failing the refresh.
+    async fn annotate_pull_requests(&self, owner: &str, repo: &str, runs: &mut [WorkflowRun]) {
+        let key = format!("{}/{}", owner, repo);
+        let is_known = |number: u64| {
+            self.pull_request_cache
+                .lock()
+                .unwrap()
+                .get(&key)
+                .is_some_and(|known| known.contains_key(&number))
+        };
+        let unknown: Vec<u64> = runs
+            .iter()
+            .flat_map(|run| run.pull_requests.iter().map(|pull_request| pull_request.number))
+            .filter(|number| !is_known(*number))
+            .collect();
+
+        if !unknown.is_empty() {
+            let route = pull_requests_route(owner, repo);
+            match tokio::time::timeout(Duration::from_secs(30), self.client.get_pull_requests(&route)).await {
+                Ok(Ok(pull_requests)) => {
+                    let mut cache = self.pull_request_cache.lock().unwrap();
+                    let known = cache.entry(key.clone()).or_default();
+                    for pull_request in pull_requests {
+                        known.insert(pull_request.number, Some((pull_request.title, pull_request.user.login)));
+                    }
+                    for number in unknown {
+                        known.entry(number).or_insert(None);
+                    }
+                }
+                Ok(Err(e)) => log_warn(format!("Failed to fetch pull requests of {}: {}", key, e)),
+                Err(_) => log_warn(format!("Timed out fetching pull requests of {}", key)),
+            }
+        }
+
+        let cache = self.pull_request_cache.lock().unwrap();
+        let Some(known) = cache.get(&key) else {
+            return;
+        };
+        for pull_request in runs.iter_mut().flat_map(|run| run.pull_requests.iter_mut()) {
+            if let Some(Some((title, author))) = known.get(&pull_request.number) {
+                pull_request.title = Some(title.clone());
+                pull_request.author = Some(author.clone());
+            }
+        }
+    }
+
+    /// One page of a repository's run history, newest first, for the history view. Pages are
+    /// not cached; with workflow filters, the same page of every workflow is merged.
+    pub async fn fetch_run_history(
+        &self,
+        owner: &str,
+        repo: &str,
+        page: u32,
+    ) -> Result<RunHistoryPage, AppError> {
+        let client = self.client.clone();
+        let routes = self.workflow_run_routes(owner, repo, HISTORY_PAGE_SIZE, Some(page));
+
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let routes = routes.clone();
+
+            Box::pin(async move {
+                let mut history = RunHistoryPage::default();
+                for route in routes {
+                    // Add timeout to prevent hanging requests
+                    let conditional = tokio::time::timeout(
+                        Duration::from_secs(30),
+                        client.get_workflow_runs(&route, None)
+                    ).await
+                    .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                    if let Conditional::Modified { value, .. } = conditional {
+                        history.has_more |= value.next_page.is_some();
+                        history.runs.extend(value.workflow_runs.into_iter().map(to_workflow_run));
+                    }
+                }
+
+                history.runs.sort_by_key(|run| std::cmp::Reverse(run.created_at));
+                Ok(history)
+            })
         }).await
     }
 
-    pub async fn fetch_repository_info(
+    pub async fn fetch_workflow_run(
         &self,
         owner: &str,
         repo: &str,
-    ) -> Result<Repository, AppError> {
+        run_id: u64,
+    ) -> Result<WorkflowRun, AppError> {
         let client = self.client.clone();
-        let owner = owner.to_string();
-        let repo = repo.to_string();
-        
+        let route = format!("/repos/{}/{}/actions/runs/{}", owner, repo, run_id);
+
         self.retry_with_backoff(move || {
             let client = client.clone();
-            let owner = owner.clone();
-            let repo = repo.clone();
-            let route = format!("/repos/{}/{}", owner, repo);
-            
+            let route = route.clone();
+
             Box::pin(async move {
                 // Add timeout to prevent hanging requests
-                let repo_info = tokio::time::timeout(
+                let raw_run = tokio::time::timeout(
                     Duration::from_secs(30),
-                    client.get_repository(&route)
+                    client.get_workflow_run(&route)
                 ).await
                 .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
 
-                Ok(Repository {
-                    id: repo_info.id,
-                    name: repo_info.name.clone(),
-                    owner: repo_info.owner.login,
-                    full_name: repo_info.full_name,
-                    html_url: repo_info.html_url,
-                    default_branch: repo_info.default_branch,
-                })
+                Ok(to_workflow_run(raw_run))
             })
         }).await
     }
 
-    pub async fn fetch_repositories(&self) -> Result<Vec<Repository>, AppError> {
-        let mut repositories = Vec::new();
-        for repo_config in self.settings.repositories() {
-            let repository = self.fetch_repository_info(&repo_config.owner, &repo_config.name).await?;
-            repositories.push(repository);
-        }
-        Ok(repositories)
+    /// Runs of every workflow triggered for commit `head_sha`, newest first.
+    pub async fn fetch_commit_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        head_sha: &str,
+    ) -> Result<Vec<WorkflowRun>, AppError> {
+        self.fetch_listed_runs(format!("/repos/{}/{}/actions/runs?head_sha={}&per_page=100", owner, repo, head_sha)).await
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use crate::config::settings::{Settings, RepositoryConfig};
-    use crate::error::AppError;
-    use mockall::mock;
-    use mockall::predicate::*;
-    use async_trait::async_trait;
-    use chrono::{Utc, TimeZone};
+    /// Latest runs on `branch`, newest first, regardless of the branch and workflow filters
+    /// of the repository's configuration.
+    pub async fn fetch_branch_runs(
+        &self,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Result<Vec<WorkflowRun>, AppError> {
+        let route = format!("/repos/{}/{}/actions/runs?branch={}&per_page=100", owner, repo, encode_query_value(branch));
+        self.fetch_listed_runs(route).await
+    }
 
-    mock! {
-        GitHubApiClient {}
+    async fn fetch_listed_runs(&self, route: String) -> Result<Vec<WorkflowRun>, AppError> {
+        let client = self.client.clone();
 
-        #[async_trait]
-        impl GitHubApiClient for GitHubApiClient {
-            async fn get_workflow_runs(&self, route: &str) -> Result<WorkflowRunsResponse, AppError>;
-            async fn get_repository(&self, route: &str) -> Result<ApiRepository, AppError>;
-        }
-    }
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let route = route.clone();
 
-    fn create_test_settings() -> Settings {
-        let mut settings = Settings::default();
-        settings.set_github_token("test_token".to_string());
-        settings.set_repositories(vec![
-            RepositoryConfig {
-                owner: "testowner".to_string(),
-                name: "testrepo".to_string(),
+            Box::pin(async move {
+                // Add timeout to prevent hanging requests
+                let conditional = tokio::time::timeout(
+                    Duration::from_secs(30),
+                    client.get_workflow_runs(&route, None)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                let mut runs: Vec<WorkflowRun> = match conditional {
+                    Conditional::Modified { value, .. } => value.workflow_runs.into_iter().map(to_workflow_run).collect(),
+                    Conditional::NotModified => Vec::new(),
+                };
+                runs.sort_by_key(|run| std::cmp::Reverse(run.created_at));
+                Ok(runs)
+            })
+        }).await
+    }
+
+    /// Open pull requests of a repository, each with the checks on its head commit. The
+    /// check runs are requested concurrently, at most `max_concurrent_requests` at a time.
+    pub async fn fetch_pull_requests(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<PullRequestStatus>, AppError> {
+        let client = self.client.clone();
+        let pull_request_cache = self.pull_request_cache.clone();
+        let max_concurrent_requests = self.settings.monitoring.max_concurrent_requests.max(1);
+        let (owner, repo) = (owner.to_string(), repo.to_string());
+
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let pull_request_cache = pull_request_cache.clone();
+            let (owner, repo) = (owner.clone(), repo.clone());
+
+            Box::pin(async move {
+                let route = pull_requests_route(&owner, &repo);
+                // Add timeout to prevent hanging requests
+                let pull_requests = tokio::time::timeout(
+                    Duration::from_secs(30),
+                    client.get_pull_requests(&route)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                pull_request_cache
+                    .lock()
+                    .unwrap()
+                    .entry(format!("{}/{}", owner, repo))
+                    .or_default()
+                    .extend(pull_requests.iter().map(|pull_request| {
+                        (pull_request.number, Some((pull_request.title.clone(), pull_request.user.login.clone())))
+                    }));
+
+                let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(max_concurrent_requests));
+                let mut requests = tokio::task::JoinSet::new();
+                for (index, pull_request) in pull_requests.iter().enumerate() {
+                    let client = client.clone();
+                    let semaphore = semaphore.clone();
+                    let route = format!("/repos/{}/{}/commits/{}/check-runs?per_page=100", owner, repo, pull_request.head.sha);
+                    requests.spawn(async move {
+                        let _permit = semaphore.acquire_owned().await;
+                        let response = tokio::time::timeout(
+                            Duration::from_secs(30),
+                            client.get_check_runs(&route)
+                        ).await
+                        .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))?;
+                        response.map(|response| (index, response))
+                    });
+                }
+
+                let mut check_runs: Vec<Option<CheckRunsResponse>> = vec![None; pull_requests.len()];
+                while let Some(result) = requests.join_next().await {
+                    let (index, response) = result
+                        .map_err(|e| AppError::GithubError(format!("Check runs request failed: {}", e)))??;
+                    check_runs[index] = Some(response);
+                }
+
+                Ok(pull_requests
+                    .into_iter()
+                    .zip(check_runs)
+                    .map(|(pull_request, response)| PullRequestStatus {
+                        number: pull_request.number,
+                        title: pull_request.title,
+                        author: pull_request.user.login,
+                        head_branch: pull_request.head.branch,
+                        base_branch: pull_request.base.branch,
+                        head_sha: pull_request.head.sha,
+                        html_url: pull_request.html_url,
+                        checks: response
+                            .map(|response| response.check_runs)
+                            .unwrap_or_default()
+                            .into_iter()
+                            .map(|check| CheckRun {
+                                name: check.name,
+                                status: parse_status(&check.status),
+                                conclusion: parse_conclusion(check.conclusion.as_deref()),
+                            })
+                            .collect(),
+                    })
+                    .collect())
+            })
+        }).await
+    }
+
+    pub async fn fetch_workflow_jobs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<Vec<Job>, AppError> {
+        let client = self.client.clone();
+        let route = format!("/repos/{}/{}/actions/runs/{}/jobs", owner, repo, run_id);
+
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let route = route.clone();
+
+            Box::pin(async move {
+                // Add timeout to prevent hanging requests
+                let response = tokio::time::timeout(
+                    Duration::from_secs(30),
+                    client.get_workflow_jobs(&route)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                let jobs = response.jobs
+                    .into_iter()
+                    .map(|raw_job| Job {
+                        id: raw_job.id,
+                        run_id: raw_job.run_id,
+                        name: raw_job.name,
+                        status: parse_status(&raw_job.status),
+                        conclusion: parse_conclusion(raw_job.conclusion.as_deref()),
+                        started_at: raw_job.started_at,
+                        completed_at: raw_job.completed_at,
+                        html_url: raw_job.html_url,
+                        steps: raw_job.steps
+                            .into_iter()
+                            .map(|raw_step| Step {
+                                number: raw_step.number,
+                                name: raw_step.name,
+                                status: parse_status(&raw_step.status),
+                                conclusion: parse_conclusion(raw_step.conclusion.as_deref()),
+                                started_at: raw_step.started_at,
+                                completed_at: raw_step.completed_at,
+                            })
+                            .collect(),
+                    })
+                    .collect();
+
+                Ok(jobs)
+            })
+        }).await
+    }
+
+    async fn fetch_logs(&self, route: String) -> Result<Vec<u8>, AppError> {
+        let client = self.client.clone();
+
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let route = route.clone();
+
+            Box::pin(async move {
+                // Log archives can be large, so allow more time than for JSON endpoints
+                tokio::time::timeout(
+                    Duration::from_secs(60),
+                    client.get_logs(&route)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 60 seconds".to_string()))?
+            })
+        }).await
+    }
+
+    /// Download the logs of every job in a run, which GitHub serves as a zip archive.
+    pub async fn fetch_run_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+    ) -> Result<Vec<String>, AppError> {
+        let data = self.fetch_logs(format!("/repos/{}/{}/actions/runs/{}/logs", owner, repo, run_id)).await?;
+        parse_run_log_archive(&data)
+    }
+
+    /// Download the plain-text log of a single job.
+    pub async fn fetch_job_logs(
+        &self,
+        owner: &str,
+        repo: &str,
+        job_id: u64,
+    ) -> Result<Vec<String>, AppError> {
+        let data = self.fetch_logs(format!("/repos/{}/{}/actions/jobs/{}/logs", owner, repo, job_id)).await?;
+        Ok(parse_text_log(&data))
+    }
+
+    /// Trigger `action` on a workflow run.
+    ///
+    /// Not retried: a timed out request may still have been applied, and re-running twice
+    /// would queue the run again.
+    pub async fn run_action(
+        &self,
+        owner: &str,
+        repo: &str,
+        run_id: u64,
+        action: RunAction,
+    ) -> Result<(), AppError> {
+        let endpoint = match action {
+            RunAction::Rerun => "rerun",
+            RunAction::RerunFailed => "rerun-failed-jobs",
+            RunAction::Cancel => "cancel",
+        };
+        let route = format!("/repos/{}/{}/actions/runs/{}/{}", owner, repo, run_id, endpoint);
+
+        tokio::time::timeout(
+            Duration::from_secs(30),
+            self.client.post_action(&route)
+        ).await
+        .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))?
+    }
+
+    /// List the active workflows of a repository.
+    pub async fn fetch_workflows(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Vec<Workflow>, AppError> {
+        let client = self.client.clone();
+        let route = format!("/repos/{}/{}/actions/workflows?per_page=100", owner, repo);
+
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let route = route.clone();
+
+            Box::pin(async move {
+                // Add timeout to prevent hanging requests
+                let response = tokio::time::timeout(
+                    Duration::from_secs(30),
+                    client.get_workflows(&route)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                Ok(response.workflows
+                    .into_iter()
+                    .filter(|raw_workflow| raw_workflow.state == "active")
+                    .map(|raw_workflow| Workflow {
+                        id: raw_workflow.id,
+                        name: raw_workflow.name,
+                        path: raw_workflow.path,
+                        state: raw_workflow.state,
+                    })
+                    .collect())
+            })
+        }).await
+    }
+
+    /// Whether a token or GitHub App is configured for this client's account. The default
+    /// github.com client may run unauthenticated when every repository uses a named credential.
+    pub fn has_token(&self) -> bool {
+        let credential = self.credential.as_deref();
+        self.settings.app_for(credential).is_some() || self.settings.token_for(credential).is_some()
+    }
+
+    /// Look up the account and scopes of the token, so a bad token is reported at startup
+    /// instead of as failing refreshes. Not retried: a rejected token will not recover.
+    pub async fn check_token(&self) -> Result<TokenInfo, AppError> {
+        tokio::time::timeout(Duration::from_secs(10), self.client.get_token_info("/user"))
+            .await
+            .map_err(|_| AppError::GithubError("Request timeout after 10 seconds".to_string()))?
+    }
+
+    /// Fetch a workflow file at `git_ref` and read its `workflow_dispatch` inputs.
+    ///
+    /// Returns `None` when the workflow has no `workflow_dispatch` trigger.
+    pub async fn fetch_dispatch_inputs(
+        &self,
+        owner: &str,
+        repo: &str,
+        path: &str,
+        git_ref: &str,
+    ) -> Result<Option<Vec<WorkflowInput>>, AppError> {
+        let client = self.client.clone();
+        let route = format!("/repos/{}/{}/contents/{}?ref={}", owner, repo, path, encode_query_value(git_ref));
+
+        let content = self.retry_with_backoff(move || {
+            let client = client.clone();
+            let route = route.clone();
+
+            Box::pin(async move {
+                // Add timeout to prevent hanging requests
+                tokio::time::timeout(
+                    Duration::from_secs(30),
+                    client.get_content(&route)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))?
+            })
+        }).await?;
+
+        parse_dispatch_inputs(&decode_content(&content)?)
+    }
+
+    /// Start a `workflow_dispatch` run of `workflow_id` on `git_ref`. Like `run_action`, this
+    /// is not retried so a slow response cannot start the workflow twice.
+    pub async fn dispatch_workflow(
+        &self,
+        owner: &str,
+        repo: &str,
+        workflow_id: u64,
+        git_ref: &str,
+        inputs: &[(String, String)],
+    ) -> Result<(), AppError> {
+        let route = format!("/repos/{}/{}/actions/workflows/{}/dispatches", owner, repo, workflow_id);
+        let inputs: serde_json::Map<String, serde_json::Value> = inputs
+            .iter()
+            .map(|(name, value)| (name.clone(), serde_json::Value::String(value.clone())))
+            .collect();
+        let body = serde_json::json!({ "ref": git_ref, "inputs": inputs });
+
+        tokio::time::timeout(
+            Duration::from_secs(30),
+            self.client.post_dispatch(&route, body)
+        ).await
+        .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))?
+    }
+
+    pub async fn fetch_repository_info(
+        &self,
+        owner: &str,
+        repo: &str,
+    ) -> Result<Repository, AppError> {
+        let client = self.client.clone();
+        let owner = owner.to_string();
+        let repo = repo.to_string();
+        let (host, credential) = (self.host.clone(), self.credential.clone());
+        
+        self.retry_with_backoff(move || {
+            let client = client.clone();
+            let owner = owner.clone();
+            let repo = repo.clone();
+            let (host, credential) = (host.clone(), credential.clone());
+            let route = format!("/repos/{}/{}", owner, repo);
+            
+            Box::pin(async move {
+                // Add timeout to prevent hanging requests
+                let repo_info = tokio::time::timeout(
+                    Duration::from_secs(30),
+                    client.get_repository(&route)
+                ).await
+                .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))??;
+
+                // Enterprise repositories are prefixed with their host so they never clash
+                // with a github.com repository of the same name
+                let full_name = match &host {
+                    Some(host) => format!("{}/{}", host, repo_info.full_name),
+                    None => repo_info.full_name,
+                };
+                Ok(Repository {
+                    id: repo_info.id,
+                    name: repo_info.name.clone(),
+                    owner: repo_info.owner.login,
+                    full_name,
+                    html_url: repo_info.html_url,
+                    default_branch: repo_info.default_branch,
+                    host,
+                    credential,
+                })
+            })
+        }).await
+    }
+
+    /// Repository details for every configured repository served by this client.
+    pub async fn fetch_repositories(&self) -> Result<Vec<Repository>, AppError> {
+        let mut repositories = Vec::new();
+        let served = self.settings.repositories().iter()
+            .filter(|config| self.serves(config.host.as_deref(), config.credential.as_deref()));
+        for repo_config in served {
+            let repository = self.fetch_repository_info(&repo_config.owner, &repo_config.name).await?;
+            repositories.push(repository);
+        }
+        Ok(repositories)
+    }
+
+    /// Repositories matching `pattern` but none of `exclude`, found with the search API. Forks
+    /// are skipped, as are archived repositories unless `include_archived` is set.
+    pub async fn discover_repositories(
+        &self,
+        pattern: &RepositoryPattern,
+        exclude: &[RepositoryPattern],
+        include_archived: bool,
+    ) -> Result<Vec<RepositoryConfig>, AppError> {
+        let query = pattern.search_query(include_archived);
+        let mut found = Vec::new();
+        let (mut total_count, mut searched) = (0, 0);
+        for page in 1..=SEARCH_MAX_PAGES {
+            let client = self.client.clone();
+            let route = format!("/search/repositories?q={}&per_page=100&page={}", query, page);
+            let response = self.retry_with_backoff(move || {
+                let client = client.clone();
+                let route = route.clone();
+
+                Box::pin(async move {
+                    // Add timeout to prevent hanging requests
+                    tokio::time::timeout(
+                        Duration::from_secs(30),
+                        client.search_repositories(&route)
+                    ).await
+                    .map_err(|_| AppError::GithubError("Request timeout after 30 seconds".to_string()))?
+                })
+            }).await?;
+
+            let last_page = response.items.len() < 100;
+            total_count = response.total_count;
+            searched += response.items.len() as u64;
+            found.extend(
+                response.items
+                    .into_iter()
+                    .filter(|repo| include_archived || !repo.archived)
+                    .filter(|repo| pattern.matches(self.host(), &repo.owner.login, &repo.name, &repo.topics))
+                    .filter(|repo| {
+                        !exclude.iter().any(|excluded| excluded.matches(self.host(), &repo.owner.login, &repo.name, &repo.topics))
+                    })
+                    .map(|repo| RepositoryConfig {
+                        host: self.host.clone(),
+                        credential: self.credential.clone(),
+                        owner: repo.owner.login,
+                        name: repo.name,
+                        branch: None,
+                        workflows: None,
+                        enabled: true,
+                        min_refresh_seconds: None,
+                        max_refresh_seconds: None,
+                        history_runs: None,
+                    }),
+            );
+            if last_page {
+                break;
+            }
+        }
+        if total_count > searched {
+            log_warn(format!(
+                "Repository pattern {} matches {} repositories, but search only returns the first {}; narrow it, e.g. with topics",
+                pattern, total_count, searched
+            ));
+        }
+        Ok(found)
+    }
+}
+
+/// Open pull requests shown in the pull request view, most recently updated first.
+const PULL_REQUESTS_SHOWN: usize = 30;
+
+fn pull_requests_route(owner: &str, repo: &str) -> String {
+    format!(
+        "/repos/{}/{}/pulls?state=open&sort=updated&direction=desc&per_page={}",
+        owner, repo, PULL_REQUESTS_SHOWN
+    )
+}
+
+/// Runs per page of the history view.
+const HISTORY_PAGE_SIZE: usize = 30;
+
+/// Search returns at most 1000 results, 100 per page.
+const SEARCH_MAX_PAGES: u32 = 10;
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::config::settings::{Settings, RepositoryConfig};
+    use crate::error::AppError;
+    use mockall::mock;
+    use mockall::predicate::*;
+    use async_trait::async_trait;
+    use chrono::{Utc, TimeZone};
+
+    mock! {
+        pub GitHubApiClient {}
+
+        #[async_trait]
+        impl GitHubApiClient for GitHubApiClient {
+            async fn get_workflow_runs(&self, route: &str, etag: Option<String>) -> Result<Conditional<WorkflowRunsResponse>, AppError>;
+            async fn get_workflow_run(&self, route: &str) -> Result<ApiWorkflowRun, AppError>;
+            async fn get_repository(&self, route: &str) -> Result<ApiRepository, AppError>;
+            async fn get_workflow_jobs(&self, route: &str) -> Result<WorkflowJobsResponse, AppError>;
+            async fn get_logs(&self, route: &str) -> Result<Vec<u8>, AppError>;
+            async fn post_action(&self, route: &str) -> Result<(), AppError>;
+            async fn get_workflows(&self, route: &str) -> Result<WorkflowsResponse, AppError>;
+            async fn get_content(&self, route: &str) -> Result<ApiContent, AppError>;
+            async fn post_dispatch(&self, route: &str, body: serde_json::Value) -> Result<(), AppError>;
+            async fn get_token_info(&self, route: &str) -> Result<TokenInfo, AppError>;
+            async fn search_repositories(&self, route: &str) -> Result<RepositorySearchResponse, AppError>;
+            async fn get_pull_requests(&self, route: &str) -> Result<Vec<ApiPullRequest>, AppError>;
+            async fn get_check_runs(&self, route: &str) -> Result<CheckRunsResponse, AppError>;
+        }
+    }
+
+    fn create_test_settings() -> Settings {
+        let mut settings = Settings::default();
+        settings.set_github_token("test_token".to_string());
+        settings.set_repositories(vec![
+            RepositoryConfig {
+                host: None,
+                credential: None,
+                owner: "testowner".to_string(),
+                name: "testrepo".to_string(),
                 branch: None,
                 workflows: None,
                 enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
             },
             RepositoryConfig {
+                host: None,
+                credential: None,
                 owner: "owner2".to_string(),
                 name: "repo2".to_string(),
                 branch: None,
                 workflows: None,
                 enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
             },
         ]);
         settings
     }
 
+    fn modified(response: WorkflowRunsResponse) -> Conditional<WorkflowRunsResponse> {
+        Conditional::Modified { value: response, etag: None }
+    }
+
     fn create_mock_workflow_runs_response() -> WorkflowRunsResponse {
         WorkflowRunsResponse {
             workflow_runs: vec![
@@ -296,6 +1420,7 @@ mod tests {
                     name: "CI".to_string(),
                     status: "completed".to_string(),
                     conclusion: Some("success".to_string()),
+                    run_attempt: 1,
                     created_at: Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 1, 1, 10, 30, 0).unwrap(),
                     head_branch: Some("main".to_string()),
@@ -305,12 +1430,14 @@ mod tests {
                     actor: ApiUser {
                         login: "user1".to_string(),
                     },
+                    pull_requests: vec![],
                 },
                 ApiWorkflowRun {
                     id: 2,
                     name: "Build".to_string(),
                     status: "in_progress".to_string(),
                     conclusion: None,
+                    run_attempt: 1,
                     created_at: Utc.with_ymd_and_hms(2023, 1, 1, 9, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 1, 1, 9, 15, 0).unwrap(),
                     head_branch: Some("develop".to_string()),
@@ -320,12 +1447,14 @@ mod tests {
                     actor: ApiUser {
                         login: "user2".to_string(),
                     },
+                    pull_requests: vec![],
                 },
                 ApiWorkflowRun {
                     id: 3,
                     name: "Deploy".to_string(),
                     status: "queued".to_string(),
                     conclusion: None,
+                    run_attempt: 1,
                     created_at: Utc.with_ymd_and_hms(2023, 1, 1, 8, 0, 0).unwrap(),
                     updated_at: Utc.with_ymd_and_hms(2023, 1, 1, 8, 0, 0).unwrap(),
                     head_branch: None,
@@ -335,8 +1464,10 @@ mod tests {
                     actor: ApiUser {
                         login: "user3".to_string(),
                     },
+                    pull_requests: vec![],
                 },
             ],
+            next_page: None,
         }
     }
 
@@ -350,6 +1481,8 @@ mod tests {
             owner: ApiUser {
                 login: "testowner".to_string(),
             },
+            archived: false,
+            topics: vec![],
         }
     }
 
@@ -360,10 +1493,10 @@ mod tests {
         
         mock_client
             .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=4"), always())
             .times(1)
-            .returning(move |_| {
-                Ok(expected_response.clone())
+            .returning(move |_, _| {
+                Ok(modified(expected_response.clone()))
             });
 
         let settings = create_test_settings();
@@ -417,6 +1550,7 @@ mod tests {
                 name: format!("Run{}", i),
                 status: "completed".to_string(),
                 conclusion: Some("success".to_string()),
+                run_attempt: 1,
                 created_at: Utc.with_ymd_and_hms(2023, 1, 1, (10 - i) as u32, 0, 0).unwrap(),
                 updated_at: Utc.with_ymd_and_hms(2023, 1, 1, (10 - i) as u32, 30, 0).unwrap(),
                 head_branch: Some("main".to_string()),
@@ -426,15 +1560,16 @@ mod tests {
                 actor: ApiUser {
                     login: format!("user{}", i),
                 },
+                pull_requests: vec![],
             });
         }
         
         mock_client
             .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=5"), always())
             .times(1)
-            .returning(move |_| {
-                Ok(response.clone())
+            .returning(move |_, _| {
+                Ok(modified(response.clone()))
             });
 
         let mut settings = create_test_settings();
@@ -453,43 +1588,1064 @@ mod tests {
     }
 
     #[tokio::test]
-    async fn test_fetch_workflow_runs_empty_response() {
+    async fn test_fetch_workflow_runs_branch_filter() {
         let mut mock_client = MockGitHubApiClient::new();
-        let empty_response = WorkflowRunsResponse {
-            workflow_runs: vec![],
-        };
-        
+
         mock_client
             .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .with(eq("/repos/testowner/testrepo/actions/runs?branch=release/v1%2Bhotfix&per_page=4"), always())
             .times(1)
-            .returning(move |_| {
-                Ok(empty_response.clone())
-            });
+            .returning(|_, _| Ok(modified(create_mock_workflow_runs_response())));
 
-        let settings = create_test_settings();
+        let mut settings = create_test_settings();
+        settings.repositories[0].branch = Some("release/v1+hotfix".to_string());
         let github_client = GithubClient::new_with_client(
             settings,
             Box::new(mock_client)
         );
 
         let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
-        
+
         assert!(result.is_ok());
-        let runs = result.unwrap();
-        assert_eq!(runs.len(), 0);
     }
 
     #[tokio::test]
-    async fn test_fetch_workflow_runs_network_error() {
+    async fn test_fetch_workflow_runs_workflow_filter() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let response = create_mock_workflow_runs_response();
+        let (deploy_runs, release_runs) = response.workflow_runs.split_at(2);
+        let (deploy_runs, release_runs) = (deploy_runs.to_vec(), release_runs.to_vec());
+
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/workflows/deploy.yml/runs?branch=main&per_page=4"), always())
+            .times(1)
+            .returning(move |_, _| Ok(modified(WorkflowRunsResponse { workflow_runs: deploy_runs.clone(), next_page: None })));
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/workflows/release.yml/runs?branch=main&per_page=4"), always())
+            .times(1)
+            .returning(move |_, _| Ok(modified(WorkflowRunsResponse { workflow_runs: release_runs.clone(), next_page: None })));
+
+        let mut settings = create_test_settings();
+        settings.repositories[0].branch = Some("main".to_string());
+        settings.repositories[0].workflows = Some(vec!["deploy.yml".to_string(), "release.yml".to_string()]);
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let runs = github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap();
+
+        // Runs of both workflows are merged, newest first
+        assert_eq!(runs.len(), 3);
+        assert!(runs.windows(2).all(|pair| pair[0].created_at >= pair[1].created_at));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_run_history() {
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=30&page=2"), eq(None))
+            .times(1)
+            .returning(|_, _| {
+                let mut response = create_mock_workflow_runs_response();
+                response.next_page = Some(3);
+                Ok(modified(response))
+            });
+
+        // History pages are not limited to workflow_runs_per_repo
+        let mut settings = create_test_settings();
+        settings.monitoring.workflow_runs_per_repo = 1;
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let history = github_client.fetch_run_history("testowner", "testrepo", 2).await.unwrap();
+
+        assert_eq!(history.runs.len(), 3);
+        assert!(history.has_more);
+        assert!(history.runs.windows(2).all(|pair| pair[0].created_at >= pair[1].created_at));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_runs_to_watch() {
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?head_sha=abc123&per_page=100"), eq(None))
+            .times(1)
+            .returning(|_, _| Ok(modified(create_mock_workflow_runs_response())));
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?branch=feature/login&per_page=100"), eq(None))
+            .times(1)
+            .returning(|_, _| Ok(modified(create_mock_workflow_runs_response())));
+        mock_client
+            .expect_get_workflow_run()
+            .with(eq("/repos/testowner/testrepo/actions/runs/2"))
+            .times(1)
+            .returning(|_| Ok(create_mock_workflow_runs_response().workflow_runs.remove(1)));
+
+        // Watching is not limited to workflow_runs_per_repo
+        let mut settings = create_test_settings();
+        settings.monitoring.workflow_runs_per_repo = 1;
+        let github_client = GithubClient::new_with_client(settings, Box::new(mock_client));
+
+        let runs = github_client.fetch_commit_runs("testowner", "testrepo", "abc123").await.unwrap();
+        assert_eq!(runs.len(), 3);
+        assert!(runs.windows(2).all(|pair| pair[0].created_at >= pair[1].created_at));
+
+        let runs = github_client.fetch_branch_runs("testowner", "testrepo", "feature/login").await.unwrap();
+        assert_eq!(runs.len(), 3);
+
+        let run = github_client.fetch_workflow_run("testowner", "testrepo", 2).await.unwrap();
+        assert_eq!(run.name, "Build");
+        assert_eq!(run.status, WorkflowStatus::InProgress);
+    }
+
+    #[test]
+    fn test_next_page() {
+        let mut headers = http::HeaderMap::new();
+        assert_eq!(next_page(&headers), None);
+
+        headers.insert(http::header::LINK, concat!(
+            r#"<https://api.github.com/repositories/1/actions/runs?per_page=30&page=1>; rel="prev", "#,
+            r#"<https://api.github.com/repositories/1/actions/runs?per_page=30&page=3>; rel="next", "#,
+            r#"<https://api.github.com/repositories/1/actions/runs?per_page=30&page=9>; rel="last""#,
+        ).parse().unwrap());
+        assert_eq!(next_page(&headers), Some(3));
+
+        headers.insert(
+            http::header::LINK,
+            r#"<https://api.github.com/repositories/1/actions/runs?page=1>; rel="first""#.parse().unwrap(),
+        );
+        assert_eq!(next_page(&headers), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_etag_cache() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let route = "/repos/testowner/testrepo/actions/runs?per_page=4";
+
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq(route), eq(None))
+            .times(1)
+            .returning(|_, _| {
+                Ok(Conditional::Modified {
+                    value: create_mock_workflow_runs_response(),
+                    etag: Some("W/\"abc\"".to_string()),
+                })
+            });
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq(route), eq(Some("W/\"abc\"".to_string())))
+            .times(2)
+            .returning(|_, _| Ok(Conditional::NotModified));
+
+        let github_client = GithubClient::new_with_client(
+            create_test_settings(),
+            Box::new(mock_client)
+        );
+
+        let first = github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap();
+        let second = github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap();
+        // Refresh tasks work on clones, which share the cache
+        let third = github_client.clone().fetch_workflow_runs("testowner", "testrepo").await.unwrap();
+
+        assert_eq!(first.len(), 3);
+        assert_eq!(second.iter().map(|run| run.id).collect::<Vec<_>>(), first.iter().map(|run| run.id).collect::<Vec<_>>());
+        assert_eq!(third.len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_etag_replaced() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let mut sequence = mockall::Sequence::new();
+        let route = "/repos/testowner/testrepo/actions/runs?per_page=4";
+
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq(route), eq(None))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| {
+                Ok(Conditional::Modified {
+                    value: create_mock_workflow_runs_response(),
+                    etag: Some("\"v1\"".to_string()),
+                })
+            });
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq(route), eq(Some("\"v1\"".to_string())))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(modified(WorkflowRunsResponse { workflow_runs: vec![], next_page: None })));
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq(route), eq(None))
+            .times(1)
+            .in_sequence(&mut sequence)
+            .returning(|_, _| Ok(modified(WorkflowRunsResponse { workflow_runs: vec![], next_page: None })));
+
+        let github_client = GithubClient::new_with_client(
+            create_test_settings(),
+            Box::new(mock_client)
+        );
+
+        assert_eq!(github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap().len(), 3);
+        // A changed listing without an ETag drops the cached one
+        assert!(github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap().is_empty());
+        assert!(github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_empty_response() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let empty_response = WorkflowRunsResponse {
+            workflow_runs: vec![],
+            next_page: None,
+        };
+        
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=4"), always())
+            .times(1)
+            .returning(move |_, _| {
+                Ok(modified(empty_response.clone()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+        
+        assert!(result.is_ok());
+        let runs = result.unwrap();
+        assert_eq!(runs.len(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_network_error() {
+        let mut mock_client = MockGitHubApiClient::new();
+        
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=4"), always())
+            .times(1)
+            .returning(|_, _| {
+                Err(AppError::GithubError("Network error".to_string()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+        
+        assert!(result.is_err());
+        match result.unwrap_err() {
+            AppError::GithubError(_) => {}, // Expected
+            _ => panic!("Expected GitHub error"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_authentication_error() {
+        let mut mock_client = MockGitHubApiClient::new();
+        
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=4"), always())
+            .times(1)
+            .returning(|_, _| {
+                Err(AppError::GithubError("Authentication failed".to_string()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+        
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_rate_limited_not_retried() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let reset_at = Utc::now() + chrono::Duration::minutes(10);
+
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=4"), always())
+            .times(1)
+            .returning(move |_, _| Err(AppError::RateLimited(reset_at)));
+
+        let github_client = GithubClient::new_with_client(
+            create_test_settings(),
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+
+        assert!(matches!(result, Err(AppError::RateLimited(until)) if until == reset_at));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_skipped_while_paused() {
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client.expect_get_workflow_runs().times(0);
+
+        let github_client = GithubClient::new_with_client(
+            create_test_settings(),
+            Box::new(mock_client)
+        );
+        let until = Utc::now() + chrono::Duration::minutes(1);
+        github_client.rate_limits().pause_until(until);
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+
+        assert!(matches!(result, Err(AppError::RateLimited(_))));
+    }
+
+    #[test]
+    fn test_route_repository() {
+        assert_eq!(route_repository("/repos/acme/website/actions/runs?per_page=4"), Some(("acme", "website")));
+        assert_eq!(route_repository("/repos/acme/website"), Some(("acme", "website")));
+        assert_eq!(route_repository("/repos/acme/website?ref=main"), Some(("acme", "website")));
+        assert_eq!(route_repository("/user"), None);
+        assert_eq!(route_repository("/repos/acme"), None);
+    }
+
+    #[test]
+    fn test_route_installation() {
+        assert_eq!(
+            route_installation("/repos/acme/website/actions/runs?per_page=4"),
+            Some(("acme", vec!["/repos/acme/website/installation".to_string()]))
+        );
+        assert_eq!(
+            route_installation("/search/repositories?q=org:acme+topic:service+archived:false&per_page=100&page=1"),
+            Some(("acme", vec!["/orgs/acme/installation".to_string()]))
+        );
+        assert_eq!(
+            route_installation("/search/repositories?q=user:alice&per_page=100&page=1"),
+            Some(("alice", vec!["/users/alice/installation".to_string(), "/orgs/alice/installation".to_string()]))
+        );
+        assert_eq!(route_installation("/search/repositories?q=topic:service"), None);
+        assert_eq!(route_installation("/user"), None);
+    }
+
+    #[tokio::test]
+    async fn test_inspect_response_tracks_rate_limits() {
+        let adapter = OctocrabAdapter {
+            inner: std::sync::Arc::new(Octocrab::default()),
+            installations: None,
+            rate_limits: RateLimitTracker::default(),
+        };
+        let reset = (Utc::now() + chrono::Duration::minutes(30)).timestamp().to_string();
+        let mut headers = http::HeaderMap::new();
+        headers.insert("x-ratelimit-limit", "5000".parse().unwrap());
+        headers.insert("x-ratelimit-remaining", "12".parse().unwrap());
+        headers.insert("x-ratelimit-reset", reset.parse().unwrap());
+
+        assert!(adapter.inspect_response(http::StatusCode::OK, &headers).is_ok());
+        assert_eq!(adapter.rate_limits.latest().map(|rate_limit| rate_limit.remaining), Some(12));
+
+        headers.insert("retry-after", "30".parse().unwrap());
+        let result = adapter.inspect_response(http::StatusCode::FORBIDDEN, &headers);
+        assert!(matches!(result, Err(AppError::RateLimited(_))));
+        assert!(adapter.rate_limits.paused_until(Utc::now()).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_malformed_response() {
+        let mut mock_client = MockGitHubApiClient::new();
+        
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=4"), always())
+            .times(1)
+            .returning(|_, _| {
+                Err(AppError::ParseError("Malformed JSON".to_string()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+        
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_all_statuses_and_conclusions() {
+        let mut mock_client = MockGitHubApiClient::new();
+        
+        let base_time = Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
+        let response = WorkflowRunsResponse {
+            workflow_runs: vec![
+                // All possible statuses
+                ApiWorkflowRun {
+                    id: 1,
+                    name: "Queued".to_string(),
+                    status: "queued".to_string(),
+                    conclusion: None,
+                    run_attempt: 1,
+                    created_at: base_time,
+                    updated_at: base_time,
+                    head_branch: Some("main".to_string()),
+                    head_sha: "abc123".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/1".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 2,
+                    name: "InProgress".to_string(),
+                    status: "in_progress".to_string(),
+                    conclusion: None,
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(10),
+                    updated_at: base_time - chrono::Duration::minutes(10),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "def456".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/2".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 3,
+                    name: "Completed".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: None,
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(20),
+                    updated_at: base_time - chrono::Duration::minutes(20),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "ghi789".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/3".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 4,
+                    name: "UnknownStatus".to_string(),
+                    status: "unknown".to_string(),
+                    conclusion: None,
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(30),
+                    updated_at: base_time - chrono::Duration::minutes(30),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "jkl012".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/4".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                // All possible conclusions
+                ApiWorkflowRun {
+                    id: 5,
+                    name: "Success".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("success".to_string()),
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(40),
+                    updated_at: base_time - chrono::Duration::minutes(40),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "mno345".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/5".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 6,
+                    name: "Failure".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("failure".to_string()),
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(50),
+                    updated_at: base_time - chrono::Duration::minutes(50),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "pqr678".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/6".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 7,
+                    name: "Cancelled".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("cancelled".to_string()),
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(60),
+                    updated_at: base_time - chrono::Duration::minutes(60),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "stu901".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/7".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 8,
+                    name: "Skipped".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("skipped".to_string()),
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(70),
+                    updated_at: base_time - chrono::Duration::minutes(70),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "vwx234".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/8".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 9,
+                    name: "TimedOut".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("timed_out".to_string()),
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(80),
+                    updated_at: base_time - chrono::Duration::minutes(80),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "yza567".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/9".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+                ApiWorkflowRun {
+                    id: 10,
+                    name: "UnknownConclusion".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("unknown".to_string()),
+                    run_attempt: 1,
+                    created_at: base_time - chrono::Duration::minutes(90),
+                    updated_at: base_time - chrono::Duration::minutes(90),
+                    head_branch: Some("main".to_string()),
+                    head_sha: "bcd890".to_string(),
+                    html_url: "https://github.com/test/test/actions/runs/10".to_string(),
+                    logs_url: None,
+                    actor: ApiUser { login: "user".to_string() },
+                    pull_requests: vec![],
+                },
+            ],
+            next_page: None,
+        };
+        
+        mock_client
+            .expect_get_workflow_runs()
+            .with(eq("/repos/testowner/testrepo/actions/runs?per_page=20"), always())
+            .times(1)
+            .returning(move |_, _| {
+                Ok(modified(response.clone()))
+            });
+
+        let mut settings = create_test_settings();
+        settings.monitoring.workflow_runs_per_repo = 20; // Ensure no truncation
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
+        
+        assert!(result.is_ok());
+        let runs = result.unwrap();
+        assert_eq!(runs.len(), 10);
+        
+        // Verify status mapping
+        assert_eq!(runs[0].status, WorkflowStatus::Queued);
+        assert_eq!(runs[1].status, WorkflowStatus::InProgress);
+        assert_eq!(runs[2].status, WorkflowStatus::Completed);
+        assert_eq!(runs[3].status, WorkflowStatus::Queued); // Unknown defaults to Queued
+        
+        // Verify conclusion mapping
+        assert_eq!(runs[4].conclusion, Some(WorkflowConclusion::Success));
+        assert_eq!(runs[5].conclusion, Some(WorkflowConclusion::Failure));
+        assert_eq!(runs[6].conclusion, Some(WorkflowConclusion::Cancelled));
+        assert_eq!(runs[7].conclusion, Some(WorkflowConclusion::Skipped));
+        assert_eq!(runs[8].conclusion, Some(WorkflowConclusion::TimedOut));
+        assert_eq!(runs[9].conclusion, Some(WorkflowConclusion::Skipped)); // Unknown defaults to Skipped
+    }
+
+    fn create_mock_workflow_jobs_response() -> WorkflowJobsResponse {
+        WorkflowJobsResponse {
+            jobs: vec![
+                ApiJob {
+                    id: 101,
+                    run_id: 1,
+                    name: "build".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("success".to_string()),
+                    started_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap()),
+                    completed_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 2, 0).unwrap()),
+                    html_url: Some("https://github.com/testowner/testrepo/actions/runs/1/job/101".to_string()),
+                    steps: vec![
+                        ApiStep {
+                            number: 1,
+                            name: "Set up job".to_string(),
+                            status: "completed".to_string(),
+                            conclusion: Some("success".to_string()),
+                            started_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap()),
+                            completed_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 5).unwrap()),
+                        },
+                        ApiStep {
+                            number: 2,
+                            name: "Run cargo build".to_string(),
+                            status: "completed".to_string(),
+                            conclusion: Some("success".to_string()),
+                            started_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 5).unwrap()),
+                            completed_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 2, 0).unwrap()),
+                        },
+                    ],
+                },
+                ApiJob {
+                    id: 102,
+                    run_id: 1,
+                    name: "test".to_string(),
+                    status: "completed".to_string(),
+                    conclusion: Some("failure".to_string()),
+                    started_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 2, 0).unwrap()),
+                    completed_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 5, 0).unwrap()),
+                    html_url: None,
+                    steps: vec![
+                        ApiStep {
+                            number: 1,
+                            name: "Run cargo test".to_string(),
+                            status: "completed".to_string(),
+                            conclusion: Some("failure".to_string()),
+                            started_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 2, 0).unwrap()),
+                            completed_at: Some(Utc.with_ymd_and_hms(2023, 1, 1, 10, 5, 0).unwrap()),
+                        },
+                    ],
+                },
+                ApiJob {
+                    id: 103,
+                    run_id: 1,
+                    name: "deploy".to_string(),
+                    status: "queued".to_string(),
+                    conclusion: None,
+                    started_at: None,
+                    completed_at: None,
+                    html_url: None,
+                    steps: vec![],
+                },
+            ],
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_jobs_success() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let expected_response = create_mock_workflow_jobs_response();
+
+        mock_client
+            .expect_get_workflow_jobs()
+            .with(eq("/repos/testowner/testrepo/actions/runs/1/jobs"))
+            .times(1)
+            .returning(move |_| {
+                Ok(expected_response.clone())
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_jobs("testowner", "testrepo", 1).await;
+
+        assert!(result.is_ok());
+        let jobs = result.unwrap();
+        assert_eq!(jobs.len(), 3);
+
+        assert_eq!(jobs[0].name, "build");
+        assert_eq!(jobs[0].conclusion, Some(WorkflowConclusion::Success));
+        assert_eq!(jobs[0].steps.len(), 2);
+        assert_eq!(jobs[0].steps[1].name, "Run cargo build");
+        assert_eq!(jobs[0].duration(), Some(Duration::from_secs(120)));
+
+        assert_eq!(jobs[1].conclusion, Some(WorkflowConclusion::Failure));
+        assert_eq!(jobs[1].steps[0].conclusion, Some(WorkflowConclusion::Failure));
+
+        assert_eq!(jobs[2].status, WorkflowStatus::Queued);
+        assert_eq!(jobs[2].conclusion, None);
+        assert!(jobs[2].steps.is_empty());
+        assert_eq!(jobs[2].duration(), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_pull_requests() {
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client
+            .expect_get_pull_requests()
+            .with(eq("/repos/testowner/testrepo/pulls?state=open&sort=updated&direction=desc&per_page=30"))
+            .times(1)
+            .returning(|_| {
+                Ok(serde_json::from_value(serde_json::json!([{
+                    "number": 412,
+                    "title": "Add billing export",
+                    "user": { "login": "alice" },
+                    "head": { "ref": "billing-export", "sha": "abc123" },
+                    "base": { "ref": "main", "sha": "def456" },
+                    "html_url": "https://github.com/testowner/testrepo/pull/412"
+                }])).unwrap())
+            });
+        mock_client
+            .expect_get_check_runs()
+            .with(eq("/repos/testowner/testrepo/commits/abc123/check-runs?per_page=100"))
+            .times(1)
+            .returning(|_| {
+                Ok(serde_json::from_value(serde_json::json!({
+                    "total_count": 4,
+                    "check_runs": [
+                        { "name": "build", "status": "completed", "conclusion": "success" },
+                        { "name": "test", "status": "completed", "conclusion": "success" },
+                        { "name": "docs", "status": "completed", "conclusion": "skipped" },
+                        { "name": "lint", "status": "completed", "conclusion": "failure" }
+                    ]
+                })).unwrap())
+            });
+
+        let github_client = GithubClient::new_with_client(
+            create_test_settings(),
+            Box::new(mock_client)
+        );
+
+        let pull_requests = github_client.fetch_pull_requests("testowner", "testrepo").await.unwrap();
+
+        assert_eq!(pull_requests.len(), 1);
+        assert_eq!(pull_requests[0].author, "alice");
+        assert_eq!(pull_requests[0].base_branch, "main");
+        assert_eq!(pull_requests[0].check_summary(), "3/4 checks green, lint failed");
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_fetch_pull_requests_checks_concurrently() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client
+            .expect_get_pull_requests()
+            .times(1)
+            .returning(|_| {
+                let pull_requests: Vec<serde_json::Value> = (1..=4)
+                    .map(|number| serde_json::json!({
+                        "number": number,
+                        "title": format!("Change {}", number),
+                        "user": { "login": "alice" },
+                        "head": { "ref": format!("change-{}", number), "sha": format!("sha{}", number) },
+                        "base": { "ref": "main", "sha": "def456" },
+                        "html_url": format!("https://github.com/testowner/testrepo/pull/{}", number)
+                    }))
+                    .collect();
+                Ok(serde_json::from_value(serde_json::Value::Array(pull_requests)).unwrap())
+            });
+        let in_flight = std::sync::Arc::new(AtomicUsize::new(0));
+        let peak = std::sync::Arc::new(AtomicUsize::new(0));
+        for number in 1..=4 {
+            let (in_flight, peak) = (in_flight.clone(), peak.clone());
+            // The first pull request's checks fail, the others pass
+            let conclusion = if number == 1 { "failure" } else { "success" };
+            mock_client
+                .expect_get_check_runs()
+                .with(eq(format!("/repos/testowner/testrepo/commits/sha{}/check-runs?per_page=100", number)))
+                .times(1)
+                .returning(move |_| {
+                    let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+                    peak.fetch_max(current, Ordering::SeqCst);
+                    std::thread::sleep(Duration::from_millis(50));
+                    in_flight.fetch_sub(1, Ordering::SeqCst);
+                    Ok(serde_json::from_value(serde_json::json!({
+                        "total_count": 1,
+                        "check_runs": [{ "name": "lint", "status": "completed", "conclusion": conclusion }]
+                    })).unwrap())
+                });
+        }
+
+        let mut settings = create_test_settings();
+        settings.monitoring.max_concurrent_requests = 2;
+        let github_client = GithubClient::new_with_client(settings, Box::new(mock_client));
+
+        let pull_requests = github_client.fetch_pull_requests("testowner", "testrepo").await.unwrap();
+
+        assert_eq!(pull_requests.iter().map(|pr| pr.number).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(pull_requests[0].check_summary(), "0/1 checks green, lint failed");
+        assert_eq!(pull_requests[3].check_summary(), "1/1 checks green");
+        assert_eq!(peak.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_runs_joins_pull_requests() {
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client
+            .expect_get_workflow_runs()
+            .times(2)
+            .returning(|_, _| {
+                Ok(modified(serde_json::from_value(serde_json::json!({
+                    "workflow_runs": [{
+                        "id": 1,
+                        "name": "CI",
+                        "status": "completed",
+                        "conclusion": "success",
+                        "created_at": "2023-01-01T10:00:00Z",
+                        "updated_at": "2023-01-01T10:30:00Z",
+                        "head_branch": "feature",
+                        "head_sha": "abc123",
+                        "html_url": "https://github.com/testowner/testrepo/actions/runs/1",
+                        "logs_url": null,
+                        "actor": { "login": "user1" },
+                        "pull_requests": [
+                            { "number": 412, "head": { "ref": "feature", "sha": "abc123" }, "base": { "ref": "main", "sha": "def456" } },
+                            { "number": 7, "head": { "ref": "feature", "sha": "abc123" }, "base": { "ref": "release", "sha": "fed654" } }
+                        ]
+                    }]
+                })).unwrap()))
+            });
+        // Looked up once: the closed pull request #7 is remembered as unknown
+        mock_client
+            .expect_get_pull_requests()
+            .with(eq("/repos/testowner/testrepo/pulls?state=open&sort=updated&direction=desc&per_page=30"))
+            .times(1)
+            .returning(|_| {
+                Ok(serde_json::from_value(serde_json::json!([{
+                    "number": 412,
+                    "title": "Add billing export",
+                    "user": { "login": "alice" },
+                    "head": { "ref": "feature", "sha": "abc123" },
+                    "base": { "ref": "main", "sha": "def456" },
+                    "html_url": "https://github.com/testowner/testrepo/pull/412"
+                }])).unwrap())
+            });
+
+        let github_client = GithubClient::new_with_client(create_test_settings(), Box::new(mock_client));
+
+        for _ in 0..2 {
+            let runs = github_client.fetch_workflow_runs("testowner", "testrepo").await.unwrap();
+            let pull_requests = &runs[0].pull_requests;
+            assert_eq!(pull_requests[0].title.as_deref(), Some("Add billing export"));
+            assert_eq!(pull_requests[0].author.as_deref(), Some("alice"));
+            assert_eq!(pull_requests[1].title, None);
+            assert_eq!(pull_requests[1].author, None);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflow_jobs_error() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        mock_client
+            .expect_get_workflow_jobs()
+            .with(eq("/repos/testowner/testrepo/actions/runs/42/jobs"))
+            .times(1)
+            .returning(|_| {
+                Err(AppError::GithubError("Not Found".to_string()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_workflow_jobs("testowner", "testrepo", 42).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_job_logs_success() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        mock_client
+            .expect_get_logs()
+            .with(eq("/repos/testowner/testrepo/actions/jobs/101/logs"))
+            .times(1)
+            .returning(|_| {
+                Ok(b"2023-01-01T10:00:00.0000000Z \x1b[36;1mcargo test\x1b[0m\n2023-01-01T10:00:01.0000000Z ##[error]Process completed with exit code 101.\n".to_vec())
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_job_logs("testowner", "testrepo", 101).await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            result.unwrap(),
+            vec!["cargo test", "##[error]Process completed with exit code 101."]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_fetch_run_logs_invalid_archive() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        mock_client
+            .expect_get_logs()
+            .with(eq("/repos/testowner/testrepo/actions/runs/1/logs"))
+            .times(1)
+            .returning(|_| {
+                Ok(b"not a zip archive".to_vec())
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_run_logs("testowner", "testrepo", 1).await;
+
+        assert!(matches!(result, Err(AppError::ParseError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_fetch_run_logs_expired() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        mock_client
+            .expect_get_logs()
+            .with(eq("/repos/testowner/testrepo/actions/runs/1/logs"))
+            .times(1)
+            .returning(|_| {
+                Err(AppError::GithubError("Gone".to_string()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.fetch_run_logs("testowner", "testrepo", 1).await;
+
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_run_action_routes() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        for route in [
+            "/repos/testowner/testrepo/actions/runs/1/rerun",
+            "/repos/testowner/testrepo/actions/runs/1/rerun-failed-jobs",
+            "/repos/testowner/testrepo/actions/runs/1/cancel",
+        ] {
+            mock_client
+                .expect_post_action()
+                .with(eq(route))
+                .times(1)
+                .returning(|_| Ok(()));
+        }
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        for action in [RunAction::Rerun, RunAction::RerunFailed, RunAction::Cancel] {
+            let result = github_client.run_action("testowner", "testrepo", 1, action).await;
+            assert!(result.is_ok());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_action_is_not_retried() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        mock_client
+            .expect_post_action()
+            .times(1)
+            .returning(|_| {
+                Err(AppError::GithubError("503 Service Unavailable".to_string()))
+            });
+
+        let settings = create_test_settings();
+        let github_client = GithubClient::new_with_client(
+            settings,
+            Box::new(mock_client)
+        );
+
+        let result = github_client.run_action("testowner", "testrepo", 1, RunAction::Rerun).await;
+
+        assert!(matches!(result, Err(AppError::GithubError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_check_token() {
+        let mut mock_client = MockGitHubApiClient::new();
+
+        mock_client
+            .expect_get_token_info()
+            .with(eq("/user"))
+            .times(1)
+            .returning(|_| {
+                Ok(TokenInfo {
+                    login: Some("octocat".to_string()),
+                    scopes: Some(vec!["repo".to_string()]),
+                })
+            });
+
+        let github_client = GithubClient::new_with_client(create_test_settings(), Box::new(mock_client));
+        assert!(github_client.has_token());
+
+        let info = github_client.check_token().await.unwrap();
+        assert_eq!(info.login.as_deref(), Some("octocat"));
+        assert!(info.warnings().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_fetch_workflows_skips_disabled() {
         let mut mock_client = MockGitHubApiClient::new();
-        
+
         mock_client
-            .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .expect_get_workflows()
+            .with(eq("/repos/testowner/testrepo/actions/workflows?per_page=100"))
             .times(1)
             .returning(|_| {
-                Err(AppError::GithubError("Network error".to_string()))
+                Ok(WorkflowsResponse {
+                    workflows: vec![
+                        ApiWorkflow {
+                            id: 1,
+                            name: "CI".to_string(),
+                            path: ".github/workflows/ci.yml".to_string(),
+                            state: "active".to_string(),
+                        },
+                        ApiWorkflow {
+                            id: 2,
+                            name: "Old".to_string(),
+                            path: ".github/workflows/old.yml".to_string(),
+                            state: "disabled_manually".to_string(),
+                        },
+                    ],
+                })
             });
 
         let settings = create_test_settings();
@@ -498,25 +2654,27 @@ mod tests {
             Box::new(mock_client)
         );
 
-        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
-        
-        assert!(result.is_err());
-        match result.unwrap_err() {
-            AppError::GithubError(_) => {}, // Expected
-            _ => panic!("Expected GitHub error"),
-        }
+        let workflows = github_client.fetch_workflows("testowner", "testrepo").await.unwrap();
+
+        assert_eq!(workflows.len(), 1);
+        assert_eq!(workflows[0].name, "CI");
     }
 
     #[tokio::test]
-    async fn test_fetch_workflow_runs_authentication_error() {
+    async fn test_fetch_dispatch_inputs_success() {
         let mut mock_client = MockGitHubApiClient::new();
-        
+
         mock_client
-            .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .expect_get_content()
+            .with(eq("/repos/testowner/testrepo/contents/.github/workflows/deploy.yml?ref=main"))
             .times(1)
             .returning(|_| {
-                Err(AppError::GithubError("Authentication failed".to_string()))
+                // "on:\n  workflow_dispatch:\n    inputs:\n      version:\n        required: true\n"
+                // split over two lines like the contents API does
+                Ok(ApiContent {
+                    content: "b246CiAgd29ya2Zsb3dfZGlzcGF0Y2g6CiAgICBpbnB1dHM6CiAgICAgIHZl\ncnNpb246CiAgICAgICAgcmVxdWlyZWQ6IHRydWUK\n".to_string(),
+                    encoding: "base64".to_string(),
+                })
             });
 
         let settings = create_test_settings();
@@ -525,210 +2683,76 @@ mod tests {
             Box::new(mock_client)
         );
 
-        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
-        
-        assert!(result.is_err());
+        let inputs = github_client
+            .fetch_dispatch_inputs("testowner", "testrepo", ".github/workflows/deploy.yml", "main")
+            .await
+            .unwrap()
+            .unwrap();
+
+        assert_eq!(inputs.len(), 1);
+        assert_eq!(inputs[0].name, "version");
+        assert!(inputs[0].required);
     }
 
     #[tokio::test]
-    async fn test_fetch_workflow_runs_malformed_response() {
+    async fn test_fetch_dispatch_inputs_encodes_ref() {
         let mut mock_client = MockGitHubApiClient::new();
-        
+
         mock_client
-            .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .expect_get_content()
+            .with(eq("/repos/testowner/testrepo/contents/.github/workflows/deploy.yml?ref=release/1.0%2Bhotfix%20%231%26x"))
             .times(1)
             .returning(|_| {
-                Err(AppError::ParseError("Malformed JSON".to_string()))
+                // "on: push\n"
+                Ok(ApiContent {
+                    content: "b246IHB1c2gK".to_string(),
+                    encoding: "base64".to_string(),
+                })
             });
 
-        let settings = create_test_settings();
-        let github_client = GithubClient::new_with_client(
-            settings,
-            Box::new(mock_client)
-        );
+        let github_client = GithubClient::new_with_client(create_test_settings(), Box::new(mock_client));
 
-        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
-        
-        assert!(result.is_err());
+        let inputs = github_client
+            .fetch_dispatch_inputs("testowner", "testrepo", ".github/workflows/deploy.yml", "release/1.0+hotfix #1&x")
+            .await
+            .unwrap();
+        assert!(inputs.is_none());
     }
 
     #[tokio::test]
-    async fn test_fetch_workflow_runs_all_statuses_and_conclusions() {
+    async fn test_dispatch_workflow_body() {
         let mut mock_client = MockGitHubApiClient::new();
-        
-        let base_time = Utc.with_ymd_and_hms(2023, 1, 1, 10, 0, 0).unwrap();
-        let response = WorkflowRunsResponse {
-            workflow_runs: vec![
-                // All possible statuses
-                ApiWorkflowRun {
-                    id: 1,
-                    name: "Queued".to_string(),
-                    status: "queued".to_string(),
-                    conclusion: None,
-                    created_at: base_time,
-                    updated_at: base_time,
-                    head_branch: Some("main".to_string()),
-                    head_sha: "abc123".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/1".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 2,
-                    name: "InProgress".to_string(),
-                    status: "in_progress".to_string(),
-                    conclusion: None,
-                    created_at: base_time - chrono::Duration::minutes(10),
-                    updated_at: base_time - chrono::Duration::minutes(10),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "def456".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/2".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 3,
-                    name: "Completed".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: None,
-                    created_at: base_time - chrono::Duration::minutes(20),
-                    updated_at: base_time - chrono::Duration::minutes(20),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "ghi789".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/3".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 4,
-                    name: "UnknownStatus".to_string(),
-                    status: "unknown".to_string(),
-                    conclusion: None,
-                    created_at: base_time - chrono::Duration::minutes(30),
-                    updated_at: base_time - chrono::Duration::minutes(30),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "jkl012".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/4".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                // All possible conclusions
-                ApiWorkflowRun {
-                    id: 5,
-                    name: "Success".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: Some("success".to_string()),
-                    created_at: base_time - chrono::Duration::minutes(40),
-                    updated_at: base_time - chrono::Duration::minutes(40),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "mno345".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/5".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 6,
-                    name: "Failure".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: Some("failure".to_string()),
-                    created_at: base_time - chrono::Duration::minutes(50),
-                    updated_at: base_time - chrono::Duration::minutes(50),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "pqr678".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/6".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 7,
-                    name: "Cancelled".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: Some("cancelled".to_string()),
-                    created_at: base_time - chrono::Duration::minutes(60),
-                    updated_at: base_time - chrono::Duration::minutes(60),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "stu901".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/7".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 8,
-                    name: "Skipped".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: Some("skipped".to_string()),
-                    created_at: base_time - chrono::Duration::minutes(70),
-                    updated_at: base_time - chrono::Duration::minutes(70),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "vwx234".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/8".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 9,
-                    name: "TimedOut".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: Some("timed_out".to_string()),
-                    created_at: base_time - chrono::Duration::minutes(80),
-                    updated_at: base_time - chrono::Duration::minutes(80),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "yza567".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/9".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-                ApiWorkflowRun {
-                    id: 10,
-                    name: "UnknownConclusion".to_string(),
-                    status: "completed".to_string(),
-                    conclusion: Some("unknown".to_string()),
-                    created_at: base_time - chrono::Duration::minutes(90),
-                    updated_at: base_time - chrono::Duration::minutes(90),
-                    head_branch: Some("main".to_string()),
-                    head_sha: "bcd890".to_string(),
-                    html_url: "https://github.com/test/test/actions/runs/10".to_string(),
-                    logs_url: None,
-                    actor: ApiUser { login: "user".to_string() },
-                },
-            ],
-        };
-        
+
         mock_client
-            .expect_get_workflow_runs()
-            .with(eq("/repos/testowner/testrepo/actions/runs"))
+            .expect_post_dispatch()
+            .with(
+                eq("/repos/testowner/testrepo/actions/workflows/7/dispatches"),
+                eq(serde_json::json!({ "ref": "main", "inputs": { "version": "1.2.3" } })),
+            )
             .times(1)
-            .returning(move |_| {
-                Ok(response.clone())
-            });
+            .returning(|_, _| Ok(()));
 
-        let mut settings = create_test_settings();
-        settings.monitoring.workflow_runs_per_repo = 20; // Ensure no truncation
+        let settings = create_test_settings();
         let github_client = GithubClient::new_with_client(
             settings,
             Box::new(mock_client)
         );
 
-        let result = github_client.fetch_workflow_runs("testowner", "testrepo").await;
-        
+        let result = github_client
+            .dispatch_workflow("testowner", "testrepo", 7, "main", &[("version".to_string(), "1.2.3".to_string())])
+            .await;
+
         assert!(result.is_ok());
-        let runs = result.unwrap();
-        assert_eq!(runs.len(), 10);
-        
-        // Verify status mapping
-        assert_eq!(runs[0].status, WorkflowStatus::Queued);
-        assert_eq!(runs[1].status, WorkflowStatus::InProgress);
-        assert_eq!(runs[2].status, WorkflowStatus::Completed);
-        assert_eq!(runs[3].status, WorkflowStatus::Queued); // Unknown defaults to Queued
-        
-        // Verify conclusion mapping
-        assert_eq!(runs[4].conclusion, Some(WorkflowConclusion::Success));
-        assert_eq!(runs[5].conclusion, Some(WorkflowConclusion::Failure));
-        assert_eq!(runs[6].conclusion, Some(WorkflowConclusion::Cancelled));
-        assert_eq!(runs[7].conclusion, Some(WorkflowConclusion::Skipped));
-        assert_eq!(runs[8].conclusion, Some(WorkflowConclusion::TimedOut));
-        assert_eq!(runs[9].conclusion, Some(WorkflowConclusion::Skipped)); // Unknown defaults to Skipped
+    }
+
+    #[test]
+    fn test_decode_content_unsupported_encoding() {
+        let content = ApiContent {
+            content: "on: push".to_string(),
+            encoding: "none".to_string(),
+        };
+
+        assert!(matches!(decode_content(&content), Err(AppError::ParseError(_))));
     }
 
     #[tokio::test]
@@ -812,6 +2836,67 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[tokio::test]
+    async fn test_discover_repositories() {
+        let mut mock_client = MockGitHubApiClient::new();
+        let repo = |name: &str, archived: bool, topics: &[&str]| {
+            let mut response = create_mock_repository_response();
+            response.name = name.to_string();
+            response.full_name = format!("testowner/{}", name);
+            response.archived = archived;
+            response.topics = topics.iter().map(|topic| topic.to_string()).collect();
+            response
+        };
+        let items = vec![
+            repo("api-gateway", false, &["service"]),
+            repo("api-legacy", false, &[]),
+            repo("api-old", true, &[]),
+            repo("website", false, &["service"]),
+        ];
+
+        mock_client
+            .expect_search_repositories()
+            .with(eq("/search/repositories?q=user:testowner+archived:false&per_page=100&page=1"))
+            .times(1)
+            .returning(move |_| Ok(RepositorySearchResponse { total_count: 4, items: items.clone() }));
+
+        let github_client = GithubClient::new_with_client(create_test_settings(), Box::new(mock_client));
+        let pattern = RepositoryPattern::parse("testowner/api-*").unwrap();
+        let exclude = vec![RepositoryPattern::parse("testowner/*-legacy").unwrap()];
+
+        let repos = github_client.discover_repositories(&pattern, &exclude, false).await.unwrap();
+        let names: Vec<&str> = repos.iter().map(|repo| repo.name.as_str()).collect();
+        assert_eq!(names, vec!["api-gateway"]);
+        assert_eq!(repos[0].owner, "testowner");
+        assert_eq!(repos[0].host, None);
+    }
+
+    #[tokio::test]
+    async fn test_discover_repositories_stops_at_the_search_limit() {
+        let mut mock_client = MockGitHubApiClient::new();
+        // Every page is full; the 11th page is never requested
+        mock_client
+            .expect_search_repositories()
+            .times(SEARCH_MAX_PAGES as usize)
+            .returning(|route| {
+                let page: usize = route.rsplit("page=").next().unwrap().parse().unwrap();
+                let items = (0..100)
+                    .map(|index| {
+                        let mut repo = create_mock_repository_response();
+                        repo.name = format!("service-{}", (page - 1) * 100 + index);
+                        repo
+                    })
+                    .collect();
+                Ok(RepositorySearchResponse { total_count: 1500, items })
+            });
+
+        let github_client = GithubClient::new_with_client(create_test_settings(), Box::new(mock_client));
+        let pattern = RepositoryPattern::parse("testowner/*").unwrap();
+
+        let repos = github_client.discover_repositories(&pattern, &[], false).await.unwrap();
+        assert_eq!(repos.len(), 1000);
+    }
+
     #[tokio::test]
     async fn test_fetch_repositories_success() {
         let mut mock_client = MockGitHubApiClient::new();
@@ -993,6 +3078,77 @@ mod tests {
         assert_eq!(run.conclusion, None);
         assert_eq!(run.head_branch, None);
         assert_eq!(run.logs_url, None);
+        assert!(run.pull_requests.is_empty());
+    }
+
+    #[test]
+    fn test_workflow_run_pull_requests() {
+        let json_data = serde_json::json!({
+            "id": 1,
+            "name": "CI",
+            "status": "completed",
+            "conclusion": "success",
+            "created_at": "2023-01-01T10:00:00Z",
+            "updated_at": "2023-01-01T10:30:00Z",
+            "head_branch": "feature",
+            "head_sha": "abc123",
+            "html_url": "https://github.com/test/repo/actions/runs/1",
+            "logs_url": null,
+            "actor": { "login": "user1" },
+            "pull_requests": [{
+                "id": 1001,
+                "number": 412,
+                "url": "https://api.github.com/repos/test/repo/pulls/412",
+                "head": { "ref": "feature", "sha": "abc123", "repo": { "id": 1 } },
+                "base": { "ref": "main", "sha": "def456", "repo": { "id": 1 } }
+            }]
+        });
+
+        let run = to_workflow_run(serde_json::from_value(json_data).unwrap());
+        assert_eq!(run.pull_requests, vec![PullRequestRef {
+            number: 412,
+            title: None,
+            author: None,
+            head_branch: "feature".to_string(),
+            base_branch: "main".to_string(),
+        }]);
+    }
+
+    #[test]
+    fn test_workflow_jobs_response_deserialization() {
+        let json_data = serde_json::json!({
+            "total_count": 1,
+            "jobs": [
+                {
+                    "id": 101,
+                    "run_id": 1,
+                    "name": "build",
+                    "status": "in_progress",
+                    "conclusion": null,
+                    "started_at": "2023-01-01T10:00:00Z",
+                    "completed_at": null,
+                    "html_url": "https://github.com/test/repo/actions/runs/1/job/101",
+                    "steps": [
+                        {
+                            "number": 1,
+                            "name": "Set up job",
+                            "status": "completed",
+                            "conclusion": "success",
+                            "started_at": "2023-01-01T10:00:00Z",
+                            "completed_at": "2023-01-01T10:00:03Z"
+                        }
+                    ]
+                }
+            ]
+        });
+
+        let response: Result<WorkflowJobsResponse, _> = serde_json::from_value(json_data);
+        assert!(response.is_ok());
+        let jobs_response = response.unwrap();
+        assert_eq!(jobs_response.jobs.len(), 1);
+        assert_eq!(jobs_response.jobs[0].name, "build");
+        assert_eq!(jobs_response.jobs[0].completed_at, None);
+        assert_eq!(jobs_response.jobs[0].steps.len(), 1);
     }
 
     #[test]
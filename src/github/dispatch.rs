@@ -0,0 +1,141 @@
+use crate::error::AppError;
+use crate::github::models::{InputKind, WorkflowInput};
+use yaml_rust2::{Yaml, YamlLoader};
+
+const DISPATCH_EVENT: &str = "workflow_dispatch";
+
+fn scalar_to_string(value: &Yaml) -> Option<String> {
+    match value {
+        Yaml::String(value) | Yaml::Real(value) => Some(value.clone()),
+        Yaml::Integer(value) => Some(value.to_string()),
+        Yaml::Boolean(value) => Some(value.to_string()),
+        _ => None,
+    }
+}
+
+fn parse_input(name: &Yaml, spec: &Yaml) -> Option<WorkflowInput> {
+    let kind = match spec["type"].as_str().unwrap_or("string") {
+        "boolean" => InputKind::Boolean,
+        "number" => InputKind::Number,
+        "environment" => InputKind::Environment,
+        "choice" => InputKind::Choice(
+            spec["options"]
+                .as_vec()
+                .map(|options| options.iter().filter_map(scalar_to_string).collect())
+                .unwrap_or_default(),
+        ),
+        _ => InputKind::String,
+    };
+
+    Some(WorkflowInput {
+        name: scalar_to_string(name)?,
+        description: spec["description"].as_str().map(str::to_string),
+        required: spec["required"].as_bool().unwrap_or(false),
+        default: scalar_to_string(&spec["default"]),
+        kind,
+    })
+}
+
+/// Read the inputs declared under `on.workflow_dispatch.inputs` in a workflow file.
+///
+/// Returns `None` when the workflow cannot be dispatched manually. The trigger may be written
+/// as a single event name, a list of events or a map of event configurations.
+pub fn parse_dispatch_inputs(contents: &str) -> Result<Option<Vec<WorkflowInput>>, AppError> {
+    let documents = YamlLoader::load_from_str(contents)
+        .map_err(|e| AppError::ParseError(format!("Invalid workflow file: {}", e)))?;
+    let Some(document) = documents.first() else {
+        return Ok(None);
+    };
+
+    let dispatch = match &document["on"] {
+        Yaml::String(event) => return Ok((event == DISPATCH_EVENT).then(Vec::new)),
+        Yaml::Array(events) => {
+            let dispatchable = events.iter().any(|event| event.as_str() == Some(DISPATCH_EVENT));
+            return Ok(dispatchable.then(Vec::new));
+        }
+        Yaml::Hash(events) => match events.get(&Yaml::String(DISPATCH_EVENT.to_string())) {
+            Some(dispatch) => dispatch,
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+
+    let inputs = dispatch["inputs"]
+        .as_hash()
+        .map(|inputs| inputs.iter().filter_map(|(name, spec)| parse_input(name, spec)).collect())
+        .unwrap_or_default();
+
+    Ok(Some(inputs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dispatch_inputs() {
+        let contents = r#"
+name: Deploy
+on:
+  push:
+    branches: [main]
+  workflow_dispatch:
+    inputs:
+      environment:
+        description: Target environment
+        required: true
+        type: choice
+        options:
+          - staging
+          - production
+      dry_run:
+        type: boolean
+        default: true
+      replicas:
+        type: number
+        default: 3
+      note:
+        description: Free text
+jobs:
+  deploy:
+    runs-on: ubuntu-latest
+"#;
+
+        let inputs = parse_dispatch_inputs(contents).unwrap().unwrap();
+
+        assert_eq!(inputs.len(), 4);
+        assert_eq!(inputs[0].name, "environment");
+        assert_eq!(inputs[0].description, Some("Target environment".to_string()));
+        assert!(inputs[0].required);
+        assert_eq!(
+            inputs[0].kind,
+            InputKind::Choice(vec!["staging".to_string(), "production".to_string()])
+        );
+        assert_eq!(inputs[1].kind, InputKind::Boolean);
+        assert_eq!(inputs[1].default, Some("true".to_string()));
+        assert_eq!(inputs[2].kind, InputKind::Number);
+        assert_eq!(inputs[2].default, Some("3".to_string()));
+        assert_eq!(inputs[3].kind, InputKind::String);
+        assert!(!inputs[3].required);
+    }
+
+    #[test]
+    fn test_parse_dispatch_without_inputs() {
+        assert_eq!(parse_dispatch_inputs("on: workflow_dispatch\n").unwrap(), Some(vec![]));
+        assert_eq!(parse_dispatch_inputs("on: [push, workflow_dispatch]\n").unwrap(), Some(vec![]));
+        assert_eq!(parse_dispatch_inputs("on:\n  workflow_dispatch:\n").unwrap(), Some(vec![]));
+    }
+
+    #[test]
+    fn test_parse_not_dispatchable() {
+        assert_eq!(parse_dispatch_inputs("on: push\n").unwrap(), None);
+        assert_eq!(parse_dispatch_inputs("on:\n  pull_request:\n").unwrap(), None);
+        assert_eq!(parse_dispatch_inputs("").unwrap(), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_yaml() {
+        let result = parse_dispatch_inputs("on: [push\n");
+        assert!(matches!(result, Err(AppError::ParseError(_))));
+    }
+}
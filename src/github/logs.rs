@@ -0,0 +1,234 @@
+use crate::error::AppError;
+use std::io::{Cursor, Read};
+
+/// Marker GitHub Actions prepends to lines emitted through `::error::`.
+const ERROR_MARKER: &str = "##[error]";
+
+/// Remove ANSI escape sequences (colors, cursor movement) from a log line.
+pub fn strip_ansi(line: &str) -> String {
+    let mut result = String::with_capacity(line.len());
+    let mut chars = line.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '\u{1b}' {
+            result.push(c);
+            continue;
+        }
+        match chars.peek() {
+            // CSI sequence: ESC [ parameters... final byte in '@'..='~'
+            Some('[') => {
+                chars.next();
+                for next in chars.by_ref() {
+                    if ('@'..='~').contains(&next) {
+                        break;
+                    }
+                }
+            }
+            // Character set designation: ESC ( B
+            Some('(') | Some(')') => {
+                chars.next();
+                chars.next();
+            }
+            // Two-character escape such as ESC =
+            Some(_) => {
+                chars.next();
+            }
+            None => {}
+        }
+    }
+
+    result
+}
+
+/// Remove the RFC 3339 timestamp GitHub prefixes to every log line.
+pub fn strip_timestamp(line: &str) -> &str {
+    match line.split_once(' ') {
+        Some((prefix, rest)) if chrono::DateTime::parse_from_rfc3339(prefix).is_ok() => rest,
+        None if chrono::DateTime::parse_from_rfc3339(line).is_ok() => "",
+        _ => line,
+    }
+}
+
+pub fn clean_line(line: &str) -> String {
+    let line = line.trim_start_matches('\u{feff}').trim_end_matches('\r');
+    strip_ansi(strip_timestamp(line))
+}
+
+pub fn is_error_line(line: &str) -> bool {
+    if line.starts_with(ERROR_MARKER) {
+        return true;
+    }
+    // Match `error:` and `error[E0308]` but not paths such as `error::tests`
+    let lower = line.to_lowercase();
+    lower.match_indices("error").any(|(index, _)| {
+        let rest = &lower[index + "error".len()..];
+        rest.starts_with('[') || (rest.starts_with(':') && !rest.starts_with("::"))
+    })
+}
+
+pub fn parse_text_log(data: &[u8]) -> Vec<String> {
+    String::from_utf8_lossy(data).lines().map(clean_line).collect()
+}
+
+/// Split an archive entry such as `build/3_Run tests.txt` into its directory, the numeric
+/// prefix GitHub uses for ordering, and the display name.
+fn split_log_file_name(name: &str) -> (&str, u64, &str) {
+    let name = name.trim_end_matches(".txt");
+    let (dir, file) = name.rsplit_once('/').unwrap_or(("", name));
+    match file.split_once('_') {
+        Some((prefix, rest)) => match prefix.parse() {
+            Ok(order) => (dir, order, rest),
+            Err(_) => (dir, u64::MAX, file),
+        },
+        None => (dir, u64::MAX, file),
+    }
+}
+
+/// Extract the zip archive returned for a whole run into one log, with a header per job.
+///
+/// The archive holds one `N_job.txt` file per job at the top level plus per-step copies in
+/// sub-directories; only the top-level files are used so each line appears once.
+pub fn parse_run_log_archive(data: &[u8]) -> Result<Vec<String>, AppError> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data))
+        .map_err(|e| AppError::ParseError(format!("Invalid log archive: {}", e)))?;
+
+    let mut names: Vec<String> = archive
+        .file_names()
+        .filter(|name| name.ends_with(".txt"))
+        .map(str::to_string)
+        .collect();
+    if names.iter().any(|name| !name.contains('/')) {
+        names.retain(|name| !name.contains('/'));
+    }
+    names.sort_by(|a, b| {
+        let (a_dir, a_order, _) = split_log_file_name(a);
+        let (b_dir, b_order, _) = split_log_file_name(b);
+        (a_dir, a_order).cmp(&(b_dir, b_order))
+    });
+
+    let mut lines = Vec::new();
+    for name in names {
+        let mut file = archive
+            .by_name(&name)
+            .map_err(|e| AppError::ParseError(format!("Invalid log archive entry {}: {}", name, e)))?;
+        let mut contents = Vec::new();
+        file.read_to_end(&mut contents)?;
+
+        let (dir, _, title) = split_log_file_name(&name);
+        if dir.is_empty() {
+            lines.push(format!("=== {} ===", title));
+        } else {
+            lines.push(format!("=== {} / {} ===", dir, title));
+        }
+        lines.extend(parse_text_log(&contents));
+    }
+
+    Ok(lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn create_test_archive(files: &[(&str, &str)]) -> Vec<u8> {
+        let mut buffer = Cursor::new(Vec::new());
+        {
+            let mut writer = zip::ZipWriter::new(&mut buffer);
+            let options = zip::write::SimpleFileOptions::default();
+            for (name, contents) in files {
+                writer.start_file(*name, options).unwrap();
+                writer.write_all(contents.as_bytes()).unwrap();
+            }
+            writer.finish().unwrap();
+        }
+        buffer.into_inner()
+    }
+
+    #[test]
+    fn test_strip_ansi() {
+        assert_eq!(strip_ansi("\u{1b}[36;1mcargo build\u{1b}[0m"), "cargo build");
+        assert_eq!(strip_ansi("plain text"), "plain text");
+        assert_eq!(strip_ansi("\u{1b}(Bdone"), "done");
+    }
+
+    #[test]
+    fn test_strip_timestamp() {
+        assert_eq!(strip_timestamp("2023-01-01T10:00:00.1234567Z Run cargo test"), "Run cargo test");
+        assert_eq!(strip_timestamp("2023-01-01T10:00:00Z"), "");
+        assert_eq!(strip_timestamp("no timestamp here"), "no timestamp here");
+    }
+
+    #[test]
+    fn test_clean_line() {
+        assert_eq!(
+            clean_line("\u{feff}2023-01-01T10:00:00.0000000Z \u{1b}[31merror: failed\u{1b}[0m\r"),
+            "error: failed"
+        );
+    }
+
+    #[test]
+    fn test_is_error_line() {
+        assert!(is_error_line("##[error]Process completed with exit code 1."));
+        assert!(is_error_line("error[E0308]: mismatched types"));
+        assert!(is_error_line("npm ERR! Error: Cannot find module"));
+        assert!(!is_error_line("Compiling nighthub v0.1.0"));
+        assert!(!is_error_line("test error::tests::test_app_error_source ... ok"));
+    }
+
+    #[test]
+    fn test_parse_text_log() {
+        let data = b"2023-01-01T10:00:00.0000000Z first\n2023-01-01T10:00:01.0000000Z second\n";
+        assert_eq!(parse_text_log(data), vec!["first", "second"]);
+    }
+
+    #[test]
+    fn test_parse_run_log_archive_orders_jobs_and_skips_steps() {
+        let data = create_test_archive(&[
+            ("10_deploy.txt", "2023-01-01T10:00:00.0000000Z deploying"),
+            ("2_test.txt", "2023-01-01T10:00:00.0000000Z ##[error]tests failed"),
+            ("test/1_Run tests.txt", "2023-01-01T10:00:00.0000000Z ##[error]tests failed"),
+            ("1_build.txt", "2023-01-01T10:00:00.0000000Z built"),
+        ]);
+
+        let lines = parse_run_log_archive(&data).unwrap();
+
+        assert_eq!(
+            lines,
+            vec![
+                "=== build ===",
+                "built",
+                "=== test ===",
+                "##[error]tests failed",
+                "=== deploy ===",
+                "deploying",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_run_log_archive_step_files_only() {
+        let data = create_test_archive(&[
+            ("build/10_Complete job.txt", "cleaning up"),
+            ("build/2_Set up job.txt", "setting up"),
+        ]);
+
+        let lines = parse_run_log_archive(&data).unwrap();
+
+        assert_eq!(
+            lines,
+            vec![
+                "=== build / Set up job ===",
+                "setting up",
+                "=== build / Complete job ===",
+                "cleaning up",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_run_log_archive_invalid_data() {
+        let result = parse_run_log_archive(b"not a zip file");
+        assert!(matches!(result, Err(AppError::ParseError(_))));
+    }
+}
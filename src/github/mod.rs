@@ -1,2 +1,7 @@
 pub mod client;
-pub mod models;
\ No newline at end of file
+pub mod dispatch;
+pub mod logs;
+pub mod models;
+pub mod pool;
+pub mod rate_limit;
+pub mod token_info;
\ No newline at end of file
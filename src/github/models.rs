@@ -25,6 +25,12 @@ pub struct Repository {
     pub full_name: String,
     pub html_url: String,
     pub default_branch: Option<String>,
+    /// GitHub Enterprise Server host, `None` for github.com.
+    #[serde(default)]
+    pub host: Option<String>,
+    /// Named credential the repository is accessed with, `None` for `github_token`.
+    #[serde(default)]
+    pub credential: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Clone)]
@@ -33,6 +39,9 @@ pub struct WorkflowRun {
     pub name: String,
     pub status: WorkflowStatus,
     pub conclusion: Option<WorkflowConclusion>,
+    /// 1 for the first attempt, incremented by each re-run of the same run.
+    #[serde(default = "first_attempt")]
+    pub run_attempt: u64,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub branch: String,
@@ -40,6 +49,251 @@ pub struct WorkflowRun {
     pub actor: String,
     pub html_url: String,
     pub logs_url: Option<String>,
+    /// Pull requests of the same repository the run was triggered for. GitHub leaves this
+    /// empty for pull requests from forks.
+    #[serde(default)]
+    pub pull_requests: Vec<PullRequestRef>,
+}
+
+fn first_attempt() -> u64 {
+    1
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, PartialEq)]
+pub struct PullRequestRef {
+    pub number: u64,
+    /// Title and author, joined from the repository's open pull requests; `None` when the pull
+    /// request is not among them.
+    #[serde(default)]
+    pub title: Option<String>,
+    #[serde(default)]
+    pub author: Option<String>,
+    pub head_branch: String,
+    pub base_branch: String,
+}
+
+/// An open pull request and the checks reported on its head commit.
+#[derive(Debug, Clone)]
+pub struct PullRequestStatus {
+    pub number: u64,
+    pub title: String,
+    pub author: String,
+    pub head_branch: String,
+    pub base_branch: String,
+    pub head_sha: String,
+    pub html_url: String,
+    pub checks: Vec<CheckRun>,
+}
+
+/// A check run, e.g. one job of a workflow run or a check reported by another app.
+#[derive(Debug, Clone)]
+pub struct CheckRun {
+    pub name: String,
+    pub status: WorkflowStatus,
+    pub conclusion: Option<WorkflowConclusion>,
+}
+
+impl CheckRun {
+    /// Whether the check does not block the pull request; skipped and neutral checks count as
+    /// passing, like on GitHub.
+    pub fn is_green(&self) -> bool {
+        self.status == WorkflowStatus::Completed
+            && matches!(self.conclusion, Some(WorkflowConclusion::Success | WorkflowConclusion::Skipped))
+    }
+
+    pub fn is_failed(&self) -> bool {
+        self.status == WorkflowStatus::Completed && !self.is_green()
+    }
+}
+
+impl PullRequestStatus {
+    /// Combined state of the checks, e.g. `3/4 checks green, lint failed`.
+    pub fn check_summary(&self) -> String {
+        if self.checks.is_empty() {
+            return "no checks".to_string();
+        }
+        let green = self.checks.iter().filter(|check| check.is_green()).count();
+        let mut summary = format!("{}/{} checks green", green, self.checks.len());
+        let failed: Vec<&str> = self
+            .checks
+            .iter()
+            .filter(|check| check.is_failed())
+            .map(|check| check.name.as_str())
+            .collect();
+        if !failed.is_empty() {
+            summary.push_str(&format!(", {} failed", failed.join(", ")));
+        }
+        let pending = self.checks.iter().filter(|check| check.status != WorkflowStatus::Completed).count();
+        if pending > 0 {
+            summary.push_str(&format!(", {} pending", pending));
+        }
+        summary
+    }
+
+    /// Status and conclusion standing for all checks: failed if any failed, running if any is
+    /// still pending, successful otherwise.
+    pub fn combined_state(&self) -> (WorkflowStatus, Option<WorkflowConclusion>) {
+        if self.checks.iter().any(CheckRun::is_failed) {
+            (WorkflowStatus::Completed, Some(WorkflowConclusion::Failure))
+        } else if self.checks.iter().any(|check| check.status != WorkflowStatus::Completed) {
+            (WorkflowStatus::InProgress, None)
+        } else {
+            (WorkflowStatus::Completed, Some(WorkflowConclusion::Success))
+        }
+    }
+}
+
+/// A page of the history view's runs.
+#[derive(Debug, Clone, Default)]
+pub struct RunHistoryPage {
+    pub runs: Vec<WorkflowRun>,
+    /// Whether GitHub links to a further page.
+    pub has_more: bool,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Job {
+    pub id: u64,
+    pub run_id: u64,
+    pub name: String,
+    pub status: WorkflowStatus,
+    pub conclusion: Option<WorkflowConclusion>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub html_url: Option<String>,
+    pub steps: Vec<Step>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Step {
+    pub number: u64,
+    pub name: String,
+    pub status: WorkflowStatus,
+    pub conclusion: Option<WorkflowConclusion>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone)]
+pub struct Workflow {
+    pub id: u64,
+    pub name: String,
+    pub path: String,
+    pub state: String,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputKind {
+    String,
+    Boolean,
+    Number,
+    Choice(Vec<String>),
+    Environment,
+}
+
+/// An input declared under `on.workflow_dispatch.inputs` in a workflow file.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkflowInput {
+    pub name: String,
+    pub description: Option<String>,
+    pub required: bool,
+    pub default: Option<String>,
+    pub kind: InputKind,
+}
+
+impl WorkflowInput {
+    /// Check a value entered in the dispatch form against the input's declared type.
+    pub fn validate(&self, value: &str) -> Result<(), String> {
+        if value.is_empty() {
+            return if self.required {
+                Err(format!("{} is required", self.name))
+            } else {
+                Ok(())
+            };
+        }
+        match &self.kind {
+            InputKind::Boolean if value != "true" && value != "false" => {
+                Err(format!("{} must be true or false", self.name))
+            }
+            InputKind::Number if value.parse::<f64>().is_err() => {
+                Err(format!("{} must be a number", self.name))
+            }
+            InputKind::Choice(options) if !options.iter().any(|option| option == value) => {
+                Err(format!("{} must be one of: {}", self.name, options.join(", ")))
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+/// Elapsed time between `started_at` and `completed_at`, measured against now while still running.
+fn elapsed(started_at: Option<DateTime<Utc>>, completed_at: Option<DateTime<Utc>>) -> Option<std::time::Duration> {
+    let started_at = started_at?;
+    let end = completed_at.unwrap_or_else(Utc::now);
+    (end - started_at).to_std().ok()
+}
+
+impl Job {
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        elapsed(self.started_at, self.completed_at)
+    }
+}
+
+impl Step {
+    pub fn duration(&self) -> Option<std::time::Duration> {
+        elapsed(self.started_at, self.completed_at)
+    }
+}
+
+/// Write actions that can be triggered on a workflow run from the UI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RunAction {
+    Rerun,
+    RerunFailed,
+    Cancel,
+}
+
+impl RunAction {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RunAction::Rerun => "Re-run all jobs",
+            RunAction::RerunFailed => "Re-run failed jobs",
+            RunAction::Cancel => "Cancel run",
+        }
+    }
+
+    /// Why the action cannot be applied to `run` in its current state, if it cannot.
+    pub fn unavailable_reason(&self, run: &WorkflowRun) -> Option<&'static str> {
+        let completed = run.status == WorkflowStatus::Completed;
+        match self {
+            RunAction::Rerun | RunAction::RerunFailed if !completed => Some("Run is still in progress"),
+            RunAction::RerunFailed
+                if !matches!(
+                    run.conclusion,
+                    Some(WorkflowConclusion::Failure | WorkflowConclusion::Cancelled | WorkflowConclusion::TimedOut)
+                ) =>
+            {
+                Some("Run has no failed jobs")
+            }
+            RunAction::Cancel if completed => Some("Run has already completed"),
+            _ => None,
+        }
+    }
+
+    /// Move `run` to the state GitHub will report once the action has been accepted.
+    pub fn apply_optimistic(&self, run: &mut WorkflowRun) {
+        match self {
+            RunAction::Rerun | RunAction::RerunFailed => {
+                run.status = WorkflowStatus::Queued;
+                run.conclusion = None;
+            }
+            RunAction::Cancel => {
+                run.status = WorkflowStatus::Completed;
+                run.conclusion = Some(WorkflowConclusion::Cancelled);
+            }
+        }
+        run.updated_at = Utc::now();
+    }
 }
 
 impl From<String> for WorkflowStatus {
@@ -142,6 +396,8 @@ mod tests {
             full_name: "test-owner/test-repo".to_string(),
             html_url: "https://github.com/test-owner/test-repo".to_string(),
             default_branch: Some("main".to_string()),
+            host: None,
+            credential: None,
         };
 
         assert_eq!(repo.id, 123);
@@ -160,6 +416,7 @@ mod tests {
             name: "CI".to_string(),
             status: WorkflowStatus::Completed,
             conclusion: Some(WorkflowConclusion::Success),
+            run_attempt: 1,
             created_at: now,
             updated_at: now,
             branch: "main".to_string(),
@@ -167,6 +424,7 @@ mod tests {
             actor: "testuser".to_string(),
             html_url: "https://github.com/test/repo/run/456".to_string(),
             logs_url: Some("https://github.com/test/repo/run/456/logs".to_string()),
+            pull_requests: Vec::new(),
         };
 
         assert_eq!(run.id, 456);
@@ -187,6 +445,7 @@ mod tests {
             name: "Build".to_string(),
             status: WorkflowStatus::InProgress,
             conclusion: None,
+            run_attempt: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             branch: "develop".to_string(),
@@ -194,6 +453,7 @@ mod tests {
             actor: "builder".to_string(),
             html_url: "https://github.com/test/repo/run/789".to_string(),
             logs_url: None,
+            pull_requests: Vec::new(),
         };
 
         assert_eq!(run.conclusion, None);
@@ -201,6 +461,179 @@ mod tests {
         assert_eq!(run.logs_url, None);
     }
 
+    #[test]
+    fn test_job_duration_completed() {
+        let started = Utc::now() - chrono::Duration::minutes(5);
+        let job = Job {
+            id: 1,
+            run_id: 456,
+            name: "build".to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Success),
+            started_at: Some(started),
+            completed_at: Some(started + chrono::Duration::seconds(90)),
+            html_url: None,
+            steps: vec![],
+        };
+
+        assert_eq!(job.duration(), Some(std::time::Duration::from_secs(90)));
+    }
+
+    #[test]
+    fn test_step_duration_not_started() {
+        let step = Step {
+            number: 1,
+            name: "Set up job".to_string(),
+            status: WorkflowStatus::Queued,
+            conclusion: None,
+            started_at: None,
+            completed_at: None,
+        };
+
+        assert_eq!(step.duration(), None);
+    }
+
+    #[test]
+    fn test_step_duration_in_progress() {
+        let step = Step {
+            number: 2,
+            name: "Run tests".to_string(),
+            status: WorkflowStatus::InProgress,
+            conclusion: None,
+            started_at: Some(Utc::now() - chrono::Duration::seconds(30)),
+            completed_at: None,
+        };
+
+        let duration = step.duration().unwrap();
+        assert!(duration >= std::time::Duration::from_secs(30));
+    }
+
+    fn create_pull_request(checks: &[(&str, WorkflowStatus, Option<WorkflowConclusion>)]) -> PullRequestStatus {
+        PullRequestStatus {
+            number: 412,
+            title: "Add billing export".to_string(),
+            author: "alice".to_string(),
+            head_branch: "billing-export".to_string(),
+            base_branch: "main".to_string(),
+            head_sha: "abc123".to_string(),
+            html_url: "https://github.com/test/repo/pull/412".to_string(),
+            checks: checks
+                .iter()
+                .map(|(name, status, conclusion)| CheckRun { name: name.to_string(), status: *status, conclusion: *conclusion })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_pull_request_check_summary() {
+        use WorkflowStatus::{Completed, InProgress};
+        let success = Some(WorkflowConclusion::Success);
+
+        let pull_request = create_pull_request(&[
+            ("build", Completed, success),
+            ("test", Completed, success),
+            ("lint", Completed, Some(WorkflowConclusion::Failure)),
+            ("deploy-preview", InProgress, None),
+        ]);
+        assert_eq!(pull_request.check_summary(), "2/4 checks green, lint failed, 1 pending");
+        assert_eq!(pull_request.combined_state(), (Completed, Some(WorkflowConclusion::Failure)));
+
+        let pull_request = create_pull_request(&[("build", Completed, success), ("test", InProgress, None)]);
+        assert_eq!(pull_request.check_summary(), "1/2 checks green, 1 pending");
+        assert_eq!(pull_request.combined_state(), (InProgress, None));
+
+        let pull_request = create_pull_request(&[]);
+        assert_eq!(pull_request.check_summary(), "no checks");
+        assert_eq!(pull_request.combined_state(), (Completed, success));
+    }
+
+    fn create_test_run(status: WorkflowStatus, conclusion: Option<WorkflowConclusion>) -> WorkflowRun {
+        WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status,
+            conclusion,
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now() - chrono::Duration::hours(1),
+            branch: "main".to_string(),
+            commit_sha: "abc123".to_string(),
+            actor: "testuser".to_string(),
+            html_url: "https://github.com/test/repo/run/1".to_string(),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_run_action_unavailable_reason() {
+        let failed = create_test_run(WorkflowStatus::Completed, Some(WorkflowConclusion::Failure));
+        let succeeded = create_test_run(WorkflowStatus::Completed, Some(WorkflowConclusion::Success));
+        let running = create_test_run(WorkflowStatus::InProgress, None);
+
+        assert_eq!(RunAction::Rerun.unavailable_reason(&failed), None);
+        assert_eq!(RunAction::RerunFailed.unavailable_reason(&failed), None);
+        assert_eq!(RunAction::Cancel.unavailable_reason(&running), None);
+
+        assert_eq!(RunAction::Rerun.unavailable_reason(&succeeded), None);
+        assert_eq!(RunAction::RerunFailed.unavailable_reason(&succeeded), Some("Run has no failed jobs"));
+        assert_eq!(RunAction::Rerun.unavailable_reason(&running), Some("Run is still in progress"));
+        assert_eq!(RunAction::Cancel.unavailable_reason(&failed), Some("Run has already completed"));
+    }
+
+    #[test]
+    fn test_run_action_apply_optimistic() {
+        let mut run = create_test_run(WorkflowStatus::Completed, Some(WorkflowConclusion::Failure));
+        let updated_at = run.updated_at;
+
+        RunAction::RerunFailed.apply_optimistic(&mut run);
+        assert_eq!(run.status, WorkflowStatus::Queued);
+        assert_eq!(run.conclusion, None);
+        assert!(run.updated_at > updated_at);
+
+        RunAction::Cancel.apply_optimistic(&mut run);
+        assert_eq!(run.status, WorkflowStatus::Completed);
+        assert_eq!(run.conclusion, Some(WorkflowConclusion::Cancelled));
+    }
+
+    fn create_test_input(kind: InputKind, required: bool) -> WorkflowInput {
+        WorkflowInput {
+            name: "target".to_string(),
+            description: None,
+            required,
+            default: None,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_workflow_input_validate_required() {
+        assert!(create_test_input(InputKind::String, true).validate("").is_err());
+        assert!(create_test_input(InputKind::String, false).validate("").is_ok());
+        assert!(create_test_input(InputKind::String, true).validate("anything").is_ok());
+    }
+
+    #[test]
+    fn test_workflow_input_validate_types() {
+        let boolean = create_test_input(InputKind::Boolean, true);
+        assert!(boolean.validate("true").is_ok());
+        assert!(boolean.validate("yes").is_err());
+
+        let number = create_test_input(InputKind::Number, true);
+        assert!(number.validate("1.5").is_ok());
+        assert!(number.validate("one").is_err());
+
+        let choice = create_test_input(
+            InputKind::Choice(vec!["staging".to_string(), "production".to_string()]),
+            true,
+        );
+        assert!(choice.validate("staging").is_ok());
+        assert_eq!(
+            choice.validate("dev"),
+            Err("target must be one of: staging, production".to_string())
+        );
+    }
+
     #[test]
     fn test_workflow_status_equality() {
         assert_eq!(WorkflowStatus::Queued, WorkflowStatus::Queued);
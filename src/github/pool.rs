@@ -0,0 +1,245 @@
+use crate::config::settings::{RepositoryConfig, Settings, GITHUB_HOST};
+use crate::error::AppError;
+use crate::github::client::GithubClient;
+use crate::github::models::Repository;
+use crate::utils::logging::{log_info, log_warn};
+use chrono::{DateTime, Utc};
+
+/// One `GithubClient` per host and credential used by the configured repositories, so
+/// github.com and GitHub Enterprise Server repositories, or repositories needing different
+/// accounts, can be monitored side by side. Each client keeps its own ETag cache and rate
+/// limit budget.
+#[derive(Clone)]
+pub struct ClientPool {
+    clients: Vec<GithubClient>,
+}
+
+fn missing_client(host: Option<&str>, credential: Option<&str>) -> AppError {
+    AppError::ConfigError(format!(
+        "No GitHub client configured for host {} with credential {}",
+        host.unwrap_or(GITHUB_HOST),
+        credential.unwrap_or("github_token")
+    ))
+}
+
+impl ClientPool {
+    /// Build the default github.com client and one for every other host and credential pair
+    /// used by `settings.repositories` and the discovery patterns.
+    pub fn new(settings: &Settings) -> Result<Self, AppError> {
+        let mut accounts: Vec<(Option<String>, Option<String>)> = vec![(None, None)];
+        let repo_accounts = settings.repositories().iter().map(|repo| (repo.host.clone(), repo.credential.clone()));
+        let pattern_accounts = settings.discovery.patterns.iter().map(|pattern| (pattern.host.clone(), None));
+        for account in repo_accounts.chain(pattern_accounts) {
+            if !accounts.contains(&account) {
+                accounts.push(account);
+            }
+        }
+
+        let clients = accounts
+            .into_iter()
+            .map(|(host, credential)| GithubClient::for_account(settings.clone(), host, credential))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(ClientPool { clients })
+    }
+
+    #[cfg(test)]
+    pub fn from_clients(clients: Vec<GithubClient>) -> Self {
+        ClientPool { clients }
+    }
+
+    pub fn clients(&self) -> &[GithubClient] {
+        &self.clients
+    }
+
+    pub fn get(&self, host: Option<&str>, credential: Option<&str>) -> Option<&GithubClient> {
+        self.clients.iter().find(|client| client.serves(host, credential))
+    }
+
+    pub fn for_repo(&self, repo: &Repository) -> Result<&GithubClient, AppError> {
+        let (host, credential) = (repo.host.as_deref(), repo.credential.as_deref());
+        self.get(host, credential).ok_or_else(|| missing_client(host, credential))
+    }
+
+    /// Repository details for `configs`, each fetched with the client it is configured for.
+    pub async fn fetch_repositories(&self, configs: &[RepositoryConfig]) -> Result<Vec<Repository>, AppError> {
+        let mut repositories = Vec::new();
+        for config in configs {
+            let (host, credential) = (config.host.as_deref(), config.credential.as_deref());
+            let client = self.get(host, credential).ok_or_else(|| missing_client(host, credential))?;
+            repositories.push(client.fetch_repository_info(&config.owner, &config.name).await?);
+        }
+        Ok(repositories)
+    }
+
+    /// Probe the token of every authenticated client and return warnings about missing
+    /// scopes, each prefixed with its account. A rejected token is an error; a probe that
+    /// fails for another reason, like a network error, is only logged.
+    pub async fn check_tokens(&self) -> Result<Vec<String>, AppError> {
+        let mut warnings = Vec::new();
+        for client in self.clients.iter().filter(|client| client.has_token()) {
+            let account = client.account_label().unwrap_or_else(|| GITHUB_HOST.to_string());
+            match client.check_token().await {
+                Ok(info) => {
+                    log_info(format!("Token for {}: {}", account, info.describe()));
+                    warnings.extend(info.warnings().into_iter().map(|warning| format!("{}: {}", account, warning)));
+                }
+                Err(AppError::ConfigError(message)) => {
+                    return Err(AppError::ConfigError(format!("{}: {}", account, message)));
+                }
+                Err(e) => log_warn(format!("Could not check the token for {}: {}", account, e)),
+            }
+        }
+        Ok(warnings)
+    }
+
+    /// The configured repositories followed by those the discovery patterns expand to, minus
+    /// the excluded ones. Listed repositories are kept even when an exclusion matches them.
+    pub async fn resolve_repositories(&self, settings: &Settings) -> Result<Vec<RepositoryConfig>, AppError> {
+        let discovery = &settings.discovery;
+        let mut repositories = settings.repositories().to_vec();
+        for pattern in &discovery.patterns {
+            let host = pattern.host.as_deref();
+            let client = self.get(host, None).ok_or_else(|| missing_client(host, None))?;
+            for repo in client.discover_repositories(pattern, &discovery.exclude, discovery.include_archived).await? {
+                let known = repositories.iter().any(|known| {
+                    known.host == repo.host
+                        && known.owner.eq_ignore_ascii_case(&repo.owner)
+                        && known.name.eq_ignore_ascii_case(&repo.name)
+                });
+                if !known {
+                    repositories.push(repo);
+                }
+            }
+        }
+        Ok(repositories)
+    }
+
+    /// Client with the tightest rate limit budget: a paused one first, then the one with the
+    /// smallest share of its budget left. Ties go to the default github.com client.
+    pub fn most_constrained(&self, now: DateTime<Utc>) -> &GithubClient {
+        let pressure = |client: &GithubClient| {
+            let rate_limits = client.rate_limits();
+            if rate_limits.paused_until(now).is_some() {
+                return 2.0;
+            }
+            rate_limits
+                .latest()
+                .filter(|rate_limit| rate_limit.reset_at > now)
+                .map_or(0.0, |rate_limit| 1.0 - rate_limit.remaining_ratio())
+        };
+
+        self.clients
+            .iter()
+            .reduce(|most, client| if pressure(client) > pressure(most) { client } else { most })
+            .expect("the pool always has a github.com client")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::settings::{CredentialConfig, HostConfig};
+    use crate::github::rate_limit::RateLimit;
+    use chrono::Duration;
+    use secrecy::SecretString;
+
+    fn create_test_settings() -> Settings {
+        let mut settings = Settings {
+            hosts: vec![HostConfig {
+                host: "github.example.com".to_string(),
+                api_url: None,
+            }],
+            ..Settings::default()
+        };
+        settings.credentials.insert("oss".to_string(), CredentialConfig {
+            token: Some(SecretString::new("ghp_oss".to_string())),
+            ..CredentialConfig::default()
+        });
+        settings.set_repositories(vec![
+            RepositoryConfig {
+                host: None,
+                credential: None,
+                owner: "acme".to_string(),
+                name: "website".to_string(),
+                branch: None,
+                workflows: None,
+                enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
+            },
+            RepositoryConfig {
+                host: Some("github.example.com".to_string()),
+                credential: None,
+                owner: "acme".to_string(),
+                name: "internal".to_string(),
+                branch: None,
+                workflows: None,
+                enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
+            },
+            RepositoryConfig {
+                host: None,
+                credential: Some("oss".to_string()),
+                owner: "upstream".to_string(),
+                name: "project".to_string(),
+                branch: None,
+                workflows: None,
+                enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
+            },
+        ]);
+        settings
+    }
+
+    fn create_test_repo(host: Option<&str>, credential: Option<&str>) -> Repository {
+        Repository {
+            id: 0,
+            name: "repo".to_string(),
+            owner: "owner".to_string(),
+            full_name: "owner/repo".to_string(),
+            html_url: String::new(),
+            default_branch: None,
+            host: host.map(str::to_string),
+            credential: credential.map(str::to_string),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_one_client_per_account() {
+        let pool = ClientPool::new(&create_test_settings()).unwrap();
+
+        assert_eq!(pool.clients().len(), 3);
+        assert_eq!(pool.for_repo(&create_test_repo(None, None)).unwrap().account_label(), None);
+        assert_eq!(
+            pool.for_repo(&create_test_repo(Some("github.example.com"), None)).unwrap().account_label(),
+            Some("github.example.com".to_string())
+        );
+        assert_eq!(
+            pool.for_repo(&create_test_repo(None, Some("oss"))).unwrap().account_label(),
+            Some("oss".to_string())
+        );
+        assert!(pool.for_repo(&create_test_repo(Some("other.example.com"), None)).is_err());
+        assert!(pool.for_repo(&create_test_repo(Some("github.example.com"), Some("oss"))).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_most_constrained() {
+        let pool = ClientPool::new(&create_test_settings()).unwrap();
+        let now = Utc::now();
+        let reset_at = now + Duration::minutes(30);
+        assert_eq!(pool.most_constrained(now).host(), None);
+
+        let enterprise = pool.get(Some("github.example.com"), None).unwrap();
+        enterprise.rate_limits().record(RateLimit { limit: 5000, remaining: 1000, reset_at });
+        assert_eq!(pool.most_constrained(now).host(), Some("github.example.com"));
+
+        let github = pool.get(None, None).unwrap();
+        github.rate_limits().pause_until(reset_at);
+        assert_eq!(pool.most_constrained(now).host(), None);
+    }
+}
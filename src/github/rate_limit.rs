@@ -0,0 +1,204 @@
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use http::{HeaderMap, StatusCode};
+use std::sync::{Arc, Mutex};
+
+/// Primary rate limit as reported by the `X-RateLimit-*` headers of the last response.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimit {
+    pub limit: u64,
+    pub remaining: u64,
+    pub reset_at: DateTime<Utc>,
+}
+
+fn header_number(headers: &HeaderMap, name: &str) -> Option<u64> {
+    headers.get(name)?.to_str().ok()?.trim().parse().ok()
+}
+
+impl RateLimit {
+    pub fn from_headers(headers: &HeaderMap) -> Option<Self> {
+        let reset = header_number(headers, "x-ratelimit-reset")?;
+        Some(RateLimit {
+            limit: header_number(headers, "x-ratelimit-limit")?,
+            remaining: header_number(headers, "x-ratelimit-remaining")?,
+            reset_at: Utc.timestamp_opt(i64::try_from(reset).ok()?, 0).single()?,
+        })
+    }
+
+    /// Share of the budget left, from 0.0 (exhausted) to 1.0.
+    pub fn remaining_ratio(&self) -> f64 {
+        if self.limit == 0 {
+            return 0.0;
+        }
+        (self.remaining as f64 / self.limit as f64).clamp(0.0, 1.0)
+    }
+}
+
+/// When a rejected request may be retried: `Retry-After` for secondary limits, the reset time
+/// once the primary budget is used up. `None` when the response was not a rate limit rejection.
+pub fn rate_limited_until(status: StatusCode, headers: &HeaderMap, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    if let Some(seconds) = header_number(headers, "retry-after") {
+        return Some(now + Duration::seconds(i64::try_from(seconds).unwrap_or(i64::MAX / 1000)));
+    }
+    RateLimit::from_headers(headers)
+        .filter(|rate_limit| rate_limit.remaining == 0)
+        .map(|rate_limit| rate_limit.reset_at)
+}
+
+#[derive(Debug, Default)]
+struct RateLimitState {
+    latest: Option<RateLimit>,
+    paused_until: Option<DateTime<Utc>>,
+}
+
+/// Rate limit budget seen across all requests, shared by every clone of a `GithubClient`.
+#[derive(Debug, Clone, Default)]
+pub struct RateLimitTracker {
+    state: Arc<Mutex<RateLimitState>>,
+}
+
+impl RateLimitTracker {
+    pub fn record(&self, rate_limit: RateLimit) {
+        self.state.lock().unwrap().latest = Some(rate_limit);
+    }
+
+    /// Stop polling until `until`, e.g. after hitting a secondary rate limit.
+    pub fn pause_until(&self, until: DateTime<Utc>) {
+        let mut state = self.state.lock().unwrap();
+        state.paused_until = Some(state.paused_until.map_or(until, |current| current.max(until)));
+    }
+
+    pub fn latest(&self) -> Option<RateLimit> {
+        self.state.lock().unwrap().latest
+    }
+
+    /// Time until which no requests should be sent, if the budget is exhausted or GitHub
+    /// asked us to back off.
+    pub fn paused_until(&self, now: DateTime<Utc>) -> Option<DateTime<Utc>> {
+        let state = self.state.lock().unwrap();
+        let exhausted_until = state
+            .latest
+            .filter(|rate_limit| rate_limit.remaining == 0)
+            .map(|rate_limit| rate_limit.reset_at);
+        [state.paused_until, exhausted_until]
+            .into_iter()
+            .flatten()
+            .filter(|until| *until > now)
+            .max()
+    }
+
+    /// Factor to stretch refresh intervals by as the remaining budget runs low.
+    pub fn interval_multiplier(&self, now: DateTime<Utc>) -> u32 {
+        let Some(rate_limit) = self.latest() else {
+            return 1;
+        };
+        // A budget that has already been reset is full again
+        if rate_limit.reset_at <= now {
+            return 1;
+        }
+        match rate_limit.remaining_ratio() {
+            ratio if ratio >= 0.5 => 1,
+            ratio if ratio >= 0.25 => 2,
+            ratio if ratio >= 0.1 => 4,
+            _ => 8,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_headers(pairs: &[(&'static str, &str)]) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        for (name, value) in pairs {
+            headers.insert(*name, value.parse().unwrap());
+        }
+        headers
+    }
+
+    fn create_rate_limit(remaining: u64, reset_at: DateTime<Utc>) -> RateLimit {
+        RateLimit { limit: 5000, remaining, reset_at }
+    }
+
+    #[test]
+    fn test_rate_limit_from_headers() {
+        let headers = create_headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "4321"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]);
+
+        let rate_limit = RateLimit::from_headers(&headers).unwrap();
+
+        assert_eq!(rate_limit.limit, 5000);
+        assert_eq!(rate_limit.remaining, 4321);
+        assert_eq!(rate_limit.reset_at, Utc.timestamp_opt(1_700_000_000, 0).unwrap());
+        assert!(RateLimit::from_headers(&create_headers(&[("x-ratelimit-limit", "5000")])).is_none());
+    }
+
+    #[test]
+    fn test_rate_limited_until() {
+        let now = Utc::now();
+        let secondary = create_headers(&[("retry-after", "60")]);
+        assert_eq!(rate_limited_until(StatusCode::FORBIDDEN, &secondary, now), Some(now + Duration::seconds(60)));
+        assert_eq!(rate_limited_until(StatusCode::OK, &secondary, now), None);
+
+        let exhausted = create_headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "0"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]);
+        assert_eq!(
+            rate_limited_until(StatusCode::TOO_MANY_REQUESTS, &exhausted, now),
+            Some(Utc.timestamp_opt(1_700_000_000, 0).unwrap())
+        );
+
+        // A plain permission error is not a rate limit
+        let forbidden = create_headers(&[
+            ("x-ratelimit-limit", "5000"),
+            ("x-ratelimit-remaining", "4000"),
+            ("x-ratelimit-reset", "1700000000"),
+        ]);
+        assert_eq!(rate_limited_until(StatusCode::FORBIDDEN, &forbidden, now), None);
+    }
+
+    #[test]
+    fn test_interval_multiplier() {
+        let now = Utc::now();
+        let reset_at = now + Duration::minutes(30);
+        let tracker = RateLimitTracker::default();
+        assert_eq!(tracker.interval_multiplier(now), 1);
+
+        tracker.record(create_rate_limit(3000, reset_at));
+        assert_eq!(tracker.interval_multiplier(now), 1);
+        tracker.record(create_rate_limit(2000, reset_at));
+        assert_eq!(tracker.interval_multiplier(now), 2);
+        tracker.record(create_rate_limit(1000, reset_at));
+        assert_eq!(tracker.interval_multiplier(now), 4);
+        tracker.record(create_rate_limit(100, reset_at));
+        assert_eq!(tracker.interval_multiplier(now), 8);
+
+        tracker.record(create_rate_limit(100, now - Duration::seconds(1)));
+        assert_eq!(tracker.interval_multiplier(now), 1);
+    }
+
+    #[test]
+    fn test_paused_until() {
+        let now = Utc::now();
+        let tracker = RateLimitTracker::default();
+        assert_eq!(tracker.paused_until(now), None);
+
+        tracker.record(create_rate_limit(0, now + Duration::minutes(10)));
+        assert_eq!(tracker.paused_until(now), Some(now + Duration::minutes(10)));
+
+        tracker.pause_until(now + Duration::minutes(20));
+        tracker.pause_until(now + Duration::minutes(5));
+        assert_eq!(tracker.paused_until(now), Some(now + Duration::minutes(20)));
+
+        // Nothing to wait for once both times have passed
+        assert_eq!(tracker.paused_until(now + Duration::minutes(30)), None);
+    }
+}
@@ -0,0 +1,94 @@
+use http::HeaderMap;
+
+/// What GitHub reports about a token when it is used to fetch `/user`.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TokenInfo {
+    /// Account the token belongs to; `None` for GitHub App installation tokens, which
+    /// cannot read `/user`.
+    pub login: Option<String>,
+    /// OAuth scopes of classic and gh CLI tokens. Fine-grained and installation tokens carry
+    /// permissions instead, which GitHub does not list.
+    pub scopes: Option<Vec<String>>,
+}
+
+impl TokenInfo {
+    pub fn scopes_from_headers(headers: &HeaderMap) -> Option<Vec<String>> {
+        let value = headers.get("x-oauth-scopes")?.to_str().ok()?;
+        Some(
+            value
+                .split(',')
+                .map(str::trim)
+                .filter(|scope| !scope.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    pub fn describe(&self) -> String {
+        let account = self.login.as_deref().unwrap_or("GitHub App installation");
+        match &self.scopes {
+            Some(scopes) if scopes.is_empty() => format!("{}, no scopes", account),
+            Some(scopes) => format!("{}, scopes: {}", account, scopes.join(", ")),
+            None => format!("{}, fine-grained permissions", account),
+        }
+    }
+
+    /// Problems worth telling the user about before they wonder why runs are missing.
+    pub fn warnings(&self) -> Vec<String> {
+        match &self.scopes {
+            Some(scopes) if !scopes.iter().any(|scope| scope == "repo") => vec![
+                "token lacks the `repo` scope; runs of private repositories cannot be read, re-run or cancelled"
+                    .to_string(),
+            ],
+            Some(_) => vec![],
+            None if self.login.is_some() => vec![
+                "fine-grained token permissions cannot be checked; it needs Actions read access (actions:read), and write access to re-run, cancel or dispatch"
+                    .to_string(),
+            ],
+            None => vec![],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn create_info(scopes: Option<&[&str]>) -> TokenInfo {
+        TokenInfo {
+            login: Some("octocat".to_string()),
+            scopes: scopes.map(|scopes| scopes.iter().map(|scope| scope.to_string()).collect()),
+        }
+    }
+
+    #[test]
+    fn test_scopes_from_headers() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(TokenInfo::scopes_from_headers(&headers), None);
+
+        headers.insert("x-oauth-scopes", "repo, read:org,workflow".parse().unwrap());
+        assert_eq!(
+            TokenInfo::scopes_from_headers(&headers),
+            Some(vec!["repo".to_string(), "read:org".to_string(), "workflow".to_string()])
+        );
+
+        headers.insert("x-oauth-scopes", "".parse().unwrap());
+        assert_eq!(TokenInfo::scopes_from_headers(&headers), Some(vec![]));
+    }
+
+    #[test]
+    fn test_warnings() {
+        assert!(create_info(Some(&["repo", "workflow"])).warnings().is_empty());
+        assert!(create_info(Some(&["public_repo"])).warnings()[0].contains("`repo` scope"));
+        assert!(create_info(None).warnings()[0].contains("actions:read"));
+        // Installation permissions come from the app's settings
+        assert!(TokenInfo::default().warnings().is_empty());
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(create_info(Some(&["repo", "workflow"])).describe(), "octocat, scopes: repo, workflow");
+        assert_eq!(create_info(Some(&[])).describe(), "octocat, no scopes");
+        assert_eq!(TokenInfo::default().describe(), "GitHub App installation, fine-grained permissions");
+    }
+}
@@ -1,6 +1,8 @@
+pub mod cli;
 pub mod config;
 pub mod error;
 pub mod github;
+pub mod notify;
 pub mod ui;
 pub mod utils;
 
@@ -1,10 +1,15 @@
 use nighthub::{
-    config::settings::Settings,
-    ui::app::AppState,
+    cli::status::{self, OutputFormat},
+    cli::watch::{self, WatchTarget},
+    config::settings::{CliOverrides, Settings},
+    ui::app::{AppState, PopupType},
+    ui::components::status_bar::StatusBarComponent,
     ui::components::workflow_list::WorkflowListComponent,
+    ui::layout::{calculate_layout, centered_rect},
     setup_logging,
+    utils::logging::log_warn,
 };
-use clap::Parser;
+use clap::{Parser, Subcommand};
 use std::error::Error;
 use std::time::Duration;
 use std::sync::atomic::{AtomicBool, Ordering};
@@ -25,18 +30,108 @@ use crossterm::{
 #[command(name = "nighthub")]
 #[command(about = "A terminal monitor for GitHub Actions")]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     #[arg(long)]
     fixed: bool,
+
+    /// Config file (TOML, YAML or JSON) layered over ~/.config/nighthub/config.toml
+    #[arg(long, value_name = "PATH", global = true)]
+    config: Option<std::path::PathBuf>,
+
+    /// Repositories to monitor, in the same format as REPOS; overrides REPOS and config files
+    #[arg(long, value_name = "LIST", global = true)]
+    repos: Option<String>,
+
+    /// Number of workflow runs shown per repository
+    #[arg(long, value_name = "N", global = true)]
+    runs_per_repo: Option<usize>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Refresh every repository once, print its runs and exit: 0 when nothing is failing,
+    /// 1 when a workflow is failing, 2 when a repository could not be refreshed
+    Status {
+        #[arg(long, value_enum, default_value_t = OutputFormat::Table)]
+        format: OutputFormat,
+    },
+    /// Follow the runs of a commit (the local checkout's HEAD by default), a run or a branch
+    /// until they finish: 0 when all succeeded, 1 when one failed, was cancelled or timed out,
+    /// 2 on errors and 3 when they did not finish in time
+    Watch {
+        /// Commit whose runs to follow, as a revision of the local checkout or a full SHA
+        #[arg(long, value_name = "REV", conflicts_with_all = ["run", "branch"])]
+        sha: Option<String>,
+
+        /// Workflow run to follow
+        #[arg(long, value_name = "ID", conflicts_with = "branch")]
+        run: Option<u64>,
+
+        /// Branch whose newest commit's runs to follow
+        #[arg(long, value_name = "BRANCH")]
+        branch: Option<String>,
+
+        /// Give up after this many seconds
+        #[arg(long, value_name = "SECONDS", default_value_t = 3600)]
+        timeout: u64,
+
+        /// Seconds between checks
+        #[arg(long, value_name = "SECONDS", default_value_t = 10)]
+        interval: u64,
+    },
 }
 
 #[tokio::main(flavor = "multi_thread", worker_threads = 2)]
 async fn main() -> Result<(), Box<dyn Error>> {
-    let _args = Args::parse();
+    let args = Args::parse();
     setup_logging();
 
-    let settings = Settings::new()?;
+    let settings = match Settings::load(&CliOverrides {
+        config_path: args.config,
+        repos: args.repos,
+        workflow_runs_per_repo: args.runs_per_repo,
+    }) {
+        Ok(settings) => settings,
+        // Exit code 1 is reserved for failing workflows in headless commands
+        Err(e) if args.command.is_some() => {
+            eprintln!("Error: {}", e);
+            std::process::exit(2);
+        }
+        Err(e) => return Err(e.into()),
+    };
+
+    // Headless commands print to stdout and exit without taking over the terminal
+    if let Some(command) = args.command {
+        let result = match command {
+            Command::Status { format } => status::run(settings, format).await,
+            Command::Watch { sha, run, branch, timeout, interval } => {
+                async {
+                    let target = WatchTarget::from_args(sha, run, branch)?;
+                    watch::run(settings, target, Duration::from_secs(timeout), Duration::from_secs(interval.max(1))).await
+                }
+                .await
+            }
+        };
+        let code = result.unwrap_or_else(|e| {
+            eprintln!("Error: {}", e);
+            2
+        });
+        std::process::exit(code);
+    }
+
+    let check_token = settings.monitoring.check_token;
     let mut app_state = AppState::new(settings).await?;
 
+    // Fail on a rejected token before the terminal is taken over, and point out missing scopes
+    if check_token {
+        for warning in app_state.github_clients.check_tokens().await? {
+            eprintln!("Warning: {}", warning);
+            log_warn(format!("Token check: {}", warning));
+        }
+    }
+
     enable_raw_mode()?;
     let backend = CrosstermBackend::new(std::io::stdout());
     let mut terminal = Terminal::new(backend)?;
@@ -44,6 +139,7 @@ async fn main() -> Result<(), Box<dyn Error>> {
     execute!(std::io::stdout(), Clear(crossterm::terminal::ClearType::All))?;
 
     let mut workflow_list = WorkflowListComponent::new();
+    let status_bar = StatusBarComponent::new();
 
     let should_exit = Arc::new(AtomicBool::new(false));
     let should_exit_clone = Arc::clone(&should_exit);
@@ -70,11 +166,21 @@ async fn main() -> Result<(), Box<dyn Error>> {
             break;
         }
 
-        // Auto refresh when timer reaches 0
+        // Pick up new and removed repositories from the discovery patterns
+        app_state.apply_discovery_results();
+        app_state.start_discovery();
+
+        // Pick up finished background refreshes, then start the ones that are due
+        app_state.apply_refresh_results();
+        app_state.poll_local_head();
         if app_state.seconds_until_refresh() == 0 {
-            let _ = app_state.refresh(false).await;
+            app_state.start_refresh(false);
         }
 
+        // Apply the popup requests that have finished, then start the ones the open popup waits for
+        app_state.apply_load_results();
+        app_state.start_loading();
+
         terminal.draw(|f| {
             // Create a list of repository names for UI component
             let repo_names: Vec<String> = app_state.repositories.iter().map(|r| r.full_name.clone()).collect();
@@ -95,66 +201,123 @@ async fn main() -> Result<(), Box<dyn Error>> {
                 app_state.selected_run
             )).ok();
 
+            // Rate limit gauge in the footer, unless disabled in the layout settings
+            let list_area = if app_state.settings.ui.layout.show_footer {
+                let (main_area, status_area) = calculate_layout(f.area());
+                // With several hosts or credentials, show the one closest to running out
+                let now = chrono::Utc::now();
+                let client = app_state.github_clients.most_constrained(now);
+                let rate_limits = client.rate_limits();
+                status_bar.render(
+                    f,
+                    status_area,
+                    rate_limits.latest(),
+                    rate_limits.paused_until(now),
+                    client.account_label().as_deref(),
+                    &app_state.theme,
+                );
+                main_area
+            } else {
+                f.area()
+            };
+
             // Render workflow list component with timer
-            workflow_list.render(f, f.area(), &app_state.workflow_runs, &repo_names, app_state.seconds_until_refresh(), &app_state.refreshing_repos);
-
-            // Render context menu if open
-            if let Some(popup_type) = app_state.popup {
-                match popup_type {
-                    nighthub::ui::app::PopupType::ContextMenu => {
-                        let area = f.area();
-                        let context_menu_area = ratatui::layout::Rect {
-                            x: area.width / 2 - 10,
-                            y: area.height / 2 - 2,
-                            width: 20.min(area.width),
-                            height: 4.min(area.height),
-                        };
-                        app_state.context_menu.render(f, context_menu_area);
-                    }
-                    _ => {}
+            workflow_list.render(f, list_area, &app_state.workflow_runs, &repo_names, app_state.seconds_until_refresh(), &app_state.refreshing_repos, app_state.local_checkout.head.as_ref(), &app_state.theme);
+
+            // Render popup if open
+            match app_state.popup {
+                Some(PopupType::ContextMenu) => {
+                    let height = app_state.context_menu.items.len() as u16 + 2;
+                    let context_menu_area = centered_rect(f.area(), 20, height);
+                    app_state.context_menu.render(f, context_menu_area, &app_state.theme);
+                }
+                Some(PopupType::Jobs) => {
+                    let area = f.area();
+                    let jobs_area = centered_rect(area, area.width.saturating_sub(10), area.height.saturating_sub(4));
+                    app_state.job_list.render(f, jobs_area, &app_state.theme);
+                }
+                Some(PopupType::History) => {
+                    let area = f.area();
+                    let history_area = centered_rect(area, area.width.saturating_sub(10), area.height.saturating_sub(4));
+                    app_state.run_history.render(f, history_area, &app_state.theme);
+                }
+                Some(PopupType::PullRequests) => {
+                    let area = f.area();
+                    let pull_requests_area = centered_rect(area, area.width.saturating_sub(10), area.height.saturating_sub(4));
+                    app_state.pull_request_list.render(f, pull_requests_area, &app_state.theme);
+                }
+                Some(PopupType::Logs) => {
+                    let area = f.area();
+                    let logs_area = centered_rect(area, area.width.saturating_sub(4), area.height.saturating_sub(2));
+                    app_state.log_viewer.render(f, logs_area, &app_state.theme);
+                }
+                Some(PopupType::Dispatch) => {
+                    let area = f.area();
+                    let dispatch_area = centered_rect(area, area.width.saturating_sub(10).min(80), area.height.saturating_sub(4));
+                    app_state.dispatch_form.render(f, dispatch_area, &app_state.theme);
                 }
+                Some(PopupType::Confirm) => {
+                    let confirm_area = centered_rect(f.area(), 50, 7);
+                    app_state.confirm_dialog.render(f, confirm_area, &app_state.theme);
+                }
+                _ => {}
             }
         })?;
 
         // Poll for events with timeout to keep UI responsive and update timer
-        if crossterm::event::poll(Duration::from_millis(100))? {
-            if let Event::Key(key) = event::read()? {
-                // Debug: log key events
-                std::fs::write("/tmp/keys.log", format!("Key: {:?}\n", key.code)).ok();
-                if key.kind == KeyEventKind::Press {
-                    match key.code {
-                        KeyCode::Char('c') if key.modifiers.contains(crossterm::event::KeyModifiers::CONTROL) => break,
-                        KeyCode::Char('q') => break,
-                        KeyCode::Char('j') | KeyCode::Down => {
-                            if app_state.popup.is_none() {
-                                app_state.next_run();
-                            }
-                        }
-                        KeyCode::Char('k') | KeyCode::Up => {
-                            if app_state.popup.is_none() {
-                                app_state.previous_run();
-                            }
-                        }
-                        KeyCode::Char('l') | KeyCode::Right => {
-                            if app_state.popup.is_none() {
-                                app_state.next_repo();
-                            }
+        if crossterm::event::poll(Duration::from_millis(100))?
+            && let Event::Key(key) = event::read()?
+        {
+            // Debug: log key events
+            std::fs::write("/tmp/keys.log", format!("Key: {:?}\n", key.code)).ok();
+            if key.kind == KeyEventKind::Press {
+                match key.code {
+                    KeyCode::Char('c') if key.modifiers.contains(crossterm::event::KeyModifiers::CONTROL) => break,
+                    KeyCode::Char(c) if app_state.is_text_input_active() => app_state.handle_char(c),
+                    KeyCode::Char('q') => break,
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        if app_state.popup.is_none() {
+                            app_state.next_run();
+                        } else {
+                            app_state.handle_key("down");
                         }
-                        KeyCode::Char('h') | KeyCode::Left => {
-                            if app_state.popup.is_none() {
-                                app_state.previous_repo();
-                            }
-                        }
-                        KeyCode::Char('f') => {
-                            if app_state.popup.is_none() {
-                                // Force immediate refresh of ALL repos (manual refresh)
-                                let _ = app_state.refresh(true).await;
-                            }
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        if app_state.popup.is_none() {
+                            app_state.previous_run();
+                        } else {
+                            app_state.handle_key("up");
                         }
-                        KeyCode::Enter => app_state.handle_key("enter"),
-                        KeyCode::Esc => app_state.handle_key("esc"),
-                        _ => {}
                     }
+                    KeyCode::Char('l') | KeyCode::Right if app_state.popup.is_none() => {
+                        app_state.next_repo();
+                    }
+                    KeyCode::Char('h') | KeyCode::Left if app_state.popup.is_none() => {
+                        app_state.previous_repo();
+                    }
+                    KeyCode::Char('f') if app_state.popup.is_none() => {
+                        // Force immediate refresh of ALL repos (manual refresh)
+                        app_state.start_refresh(true);
+                    }
+                    KeyCode::Char('w') if app_state.popup.is_none() => app_state.handle_key("w"),
+                    KeyCode::Char('H') if app_state.popup.is_none() => app_state.handle_key("H"),
+                    KeyCode::Char('p') if app_state.popup.is_none() => app_state.handle_key("p"),
+                    KeyCode::Char('m') if app_state.popup.is_none() => app_state.handle_key("m"),
+                    KeyCode::Right if app_state.popup.is_some() => app_state.handle_key("right"),
+                    KeyCode::Left if app_state.popup.is_some() => app_state.handle_key("left"),
+                    KeyCode::Tab => app_state.handle_key("tab"),
+                    KeyCode::BackTab => app_state.handle_key("backtab"),
+                    KeyCode::Backspace => app_state.handle_key("backspace"),
+                    KeyCode::PageDown if app_state.popup.is_some() => app_state.handle_key("pagedown"),
+                    KeyCode::PageUp if app_state.popup.is_some() => app_state.handle_key("pageup"),
+                    KeyCode::Home if app_state.popup.is_some() => app_state.handle_key("home"),
+                    KeyCode::End if app_state.popup.is_some() => app_state.handle_key("end"),
+                    KeyCode::Char(c @ ('g' | 'G' | 'e' | 'y' | 'n')) if app_state.popup.is_some() => {
+                        app_state.handle_key(&c.to_string());
+                    }
+                    KeyCode::Enter => app_state.handle_key("enter"),
+                    KeyCode::Esc => app_state.handle_key("esc"),
+                    _ => {}
                 }
             }
         }
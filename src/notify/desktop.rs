@@ -0,0 +1,47 @@
+use crate::error::AppError;
+use crate::notify::transitions::Transition;
+use crate::notify::Notifier;
+use std::io::Write;
+use std::process::{Command, Stdio};
+
+/// Freedesktop notifications, sent over D-Bus by `notify-send` from libnotify.
+pub struct DesktopNotifier;
+
+impl Notifier for DesktopNotifier {
+    fn name(&self) -> &'static str {
+        "desktop"
+    }
+
+    fn notify(&self, transition: &Transition) -> Result<(), AppError> {
+        let urgency = if transition.kind.is_failure() { "critical" } else { "normal" };
+        let mut child = Command::new("notify-send")
+            .arg("--app-name=nighthub")
+            .arg(format!("--urgency={}", urgency))
+            .arg(transition.title())
+            .arg(transition.body())
+            .stdin(Stdio::null())
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .spawn()?;
+        // Reap it in the background so a slow notification daemon never blocks the UI
+        std::thread::spawn(move || child.wait());
+        Ok(())
+    }
+}
+
+/// Rings the bell of the terminal nighthub runs in, which tmux and most terminals turn into
+/// an alert on the window.
+pub struct TerminalBell;
+
+impl Notifier for TerminalBell {
+    fn name(&self) -> &'static str {
+        "bell"
+    }
+
+    fn notify(&self, _transition: &Transition) -> Result<(), AppError> {
+        let mut stdout = std::io::stdout();
+        stdout.write_all(b"\x07")?;
+        stdout.flush()?;
+        Ok(())
+    }
+}
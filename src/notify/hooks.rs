@@ -0,0 +1,218 @@
+use crate::config::notifications::{HooksConfig, NotificationConfig};
+use crate::error::AppError;
+use crate::github::models::{WorkflowConclusion, WorkflowStatus};
+use crate::notify::transitions::Transition;
+use crate::utils::logging::{log_info, log_warn};
+use std::process::{Output, Stdio};
+use std::time::Duration;
+use tokio::process::Command;
+
+/// Runs the commands configured under `hooks` for run state changes.
+#[derive(Debug, Default)]
+pub struct ShellHooks {
+    config: HooksConfig,
+    /// Only its rules are used, to silence the hooks of some repositories or branches.
+    notifications: NotificationConfig,
+}
+
+/// The run of a transition as `NIGHTHUB_*` environment variables, with statuses and
+/// conclusions spelled like in the GitHub API.
+pub fn hook_env(transition: &Transition) -> Vec<(&'static str, String)> {
+    let run = &transition.run;
+    let status = match run.status {
+        WorkflowStatus::Queued => "queued",
+        WorkflowStatus::InProgress => "in_progress",
+        WorkflowStatus::Completed => "completed",
+    };
+    let conclusion = match run.conclusion {
+        Some(WorkflowConclusion::Success) => "success",
+        Some(WorkflowConclusion::Failure) => "failure",
+        Some(WorkflowConclusion::Cancelled) => "cancelled",
+        Some(WorkflowConclusion::Skipped) => "skipped",
+        Some(WorkflowConclusion::TimedOut) => "timed_out",
+        None => "",
+    };
+    let event = serde_json::to_value(transition.kind)
+        .ok()
+        .and_then(|event| event.as_str().map(str::to_string))
+        .unwrap_or_default();
+
+    vec![
+        ("NIGHTHUB_EVENT", event),
+        ("NIGHTHUB_REPO", transition.repo.clone()),
+        ("NIGHTHUB_RUN_ID", run.id.to_string()),
+        ("NIGHTHUB_RUN_NAME", run.name.clone()),
+        ("NIGHTHUB_BRANCH", run.branch.clone()),
+        ("NIGHTHUB_SHA", run.commit_sha.clone()),
+        ("NIGHTHUB_ACTOR", run.actor.clone()),
+        ("NIGHTHUB_URL", run.html_url.clone()),
+        ("NIGHTHUB_STATUS", status.to_string()),
+        ("NIGHTHUB_CONCLUSION", conclusion.to_string()),
+    ]
+}
+
+/// Run `command` through the shell for `transition` and collect its output. The command is
+/// killed once `timeout` has passed.
+pub async fn run_hook(command: &str, transition: &Transition, timeout: Duration) -> Result<Output, AppError> {
+    let (shell, flag) = if cfg!(windows) { ("cmd", "/C") } else { ("sh", "-c") };
+    let child = Command::new(shell)
+        .arg(flag)
+        .arg(command)
+        .envs(hook_env(transition))
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .kill_on_drop(true)
+        .spawn()?;
+
+    tokio::time::timeout(timeout, child.wait_with_output())
+        .await
+        .map_err(|_| {
+            let message = format!("Hook timed out after {} seconds: {}", timeout.as_secs(), command);
+            AppError::IoError(std::io::Error::new(std::io::ErrorKind::TimedOut, message))
+        })?
+        .map_err(AppError::from)
+}
+
+impl ShellHooks {
+    pub fn new(config: &HooksConfig, notifications: &NotificationConfig) -> Self {
+        ShellHooks { config: config.clone(), notifications: notifications.clone() }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.config.is_empty()
+    }
+
+    /// Commands to run for `transitions`, leaving out those the notification rules silence.
+    fn commands(&self, transitions: &[Transition]) -> Vec<(String, Transition)> {
+        transitions
+            .iter()
+            .filter(|transition| !self.notifications.silences(transition))
+            .filter_map(|transition| Some((self.config.command_for(transition.kind)?.to_string(), transition.clone())))
+            .collect()
+    }
+
+    /// Start the hooks for `transitions` in the background, one after the other so they see
+    /// the changes of a run in order. Their output and failures go to the log. The returned
+    /// task lets callers that are about to exit wait for the hooks.
+    pub fn run(&self, transitions: &[Transition]) -> Option<tokio::task::JoinHandle<()>> {
+        let hooks = self.commands(transitions);
+        if hooks.is_empty() {
+            return None;
+        }
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            log_warn("Hooks need a Tokio runtime, not running them".to_string());
+            return None;
+        };
+
+        let timeout = Duration::from_secs(self.config.timeout_seconds);
+        Some(runtime.spawn(async move {
+            for (command, transition) in hooks {
+                let label = format!("Hook for {} of {}", transition.title(), transition.repo);
+                match run_hook(&command, &transition, timeout).await {
+                    Ok(output) => {
+                        for line in String::from_utf8_lossy(&output.stdout).lines().chain(String::from_utf8_lossy(&output.stderr).lines()) {
+                            log_info(format!("{}: {}", label, line));
+                        }
+                        if !output.status.success() {
+                            log_warn(format!("{} exited with {}", label, output.status));
+                        }
+                    }
+                    Err(e) => log_warn(format!("{} failed: {}", label, e)),
+                }
+            }
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::notifications::NotificationRule;
+    use crate::github::models::WorkflowRun;
+    use crate::notify::transitions::TransitionKind;
+    use chrono::Utc;
+
+    fn create_test_transition() -> Transition {
+        Transition {
+            repo: "acme/website".to_string(),
+            kind: TransitionKind::Broken,
+            run: WorkflowRun {
+                id: 42,
+                name: "CI".to_string(),
+                status: WorkflowStatus::Completed,
+                conclusion: Some(WorkflowConclusion::TimedOut),
+                run_attempt: 1,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                branch: "main".to_string(),
+                commit_sha: "0123456789abcdef".to_string(),
+                actor: "alice".to_string(),
+                html_url: "https://github.com/acme/website/actions/runs/42".to_string(),
+                logs_url: None,
+                pull_requests: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_command_for_transition() {
+        let config = HooksConfig {
+            on_success: Some("deploy.sh".to_string()),
+            on_failure: Some("page.sh".to_string()),
+            ..HooksConfig::default()
+        };
+        assert_eq!(config.command_for(TransitionKind::Fixed), Some("deploy.sh"));
+        assert_eq!(config.command_for(TransitionKind::Broken), Some("page.sh"));
+        assert_eq!(config.command_for(TransitionKind::Started), None);
+        assert!(ShellHooks::new(&config, &NotificationConfig::default()).is_enabled());
+        assert!(!ShellHooks::default().is_enabled());
+    }
+
+    #[test]
+    fn test_rules_silence_hooks() {
+        let config = HooksConfig {
+            on_failure: Some("page.sh".to_string()),
+            ..HooksConfig::default()
+        };
+        // `events` does not apply to hooks, only the rules do
+        let notifications = NotificationConfig {
+            events: Vec::new(),
+            rules: vec![NotificationRule {
+                repo: Some("acme/sandbox".to_string()),
+                branch: None,
+                events: Vec::new(),
+            }],
+            ..NotificationConfig::default()
+        };
+        let hooks = ShellHooks::new(&config, &notifications);
+
+        let transition = create_test_transition();
+        assert_eq!(hooks.commands(std::slice::from_ref(&transition)).len(), 1);
+        let sandbox = Transition { repo: "acme/sandbox".to_string(), ..transition };
+        assert!(hooks.commands(&[sandbox]).is_empty());
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_hook_exports_run() {
+        let command = r#"echo "$NIGHTHUB_EVENT $NIGHTHUB_REPO #$NIGHTHUB_RUN_ID $NIGHTHUB_RUN_NAME $NIGHTHUB_BRANCH $NIGHTHUB_SHA $NIGHTHUB_ACTOR $NIGHTHUB_CONCLUSION $NIGHTHUB_URL"; exit 3"#;
+        let output = run_hook(command, &create_test_transition(), Duration::from_secs(5)).await.unwrap();
+
+        assert_eq!(
+            String::from_utf8_lossy(&output.stdout).trim(),
+            "broken acme/website #42 CI main 0123456789abcdef alice timed_out https://github.com/acme/website/actions/runs/42"
+        );
+        assert_eq!(output.status.code(), Some(3));
+    }
+
+    #[cfg(unix)]
+    #[tokio::test]
+    async fn test_run_hook_timeout() {
+        let started = std::time::Instant::now();
+        let result = run_hook("sleep 10", &create_test_transition(), Duration::from_millis(100)).await;
+
+        assert!(result.unwrap_err().to_string().contains("timed out"));
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+}
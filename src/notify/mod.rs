@@ -0,0 +1,142 @@
+pub mod desktop;
+pub mod hooks;
+pub mod transitions;
+pub mod webhook;
+
+use crate::config::notifications::NotificationConfig;
+use crate::error::AppError;
+use crate::notify::desktop::{DesktopNotifier, TerminalBell};
+use crate::notify::transitions::Transition;
+use crate::notify::webhook::WebhookNotifier;
+use crate::utils::logging::log_warn;
+
+/// Somewhere run state changes are announced.
+pub trait Notifier: Send + Sync {
+    /// Short name for log messages.
+    fn name(&self) -> &'static str;
+    fn notify(&self, transition: &Transition) -> Result<(), AppError>;
+
+    /// Notifications still being sent in the background, for callers about to exit.
+    fn pending(&self) -> Vec<tokio::task::JoinHandle<()>> {
+        Vec::new()
+    }
+}
+
+/// The notifiers enabled under `notifications`, and the rules deciding which transitions
+/// reach them.
+#[derive(Default)]
+pub struct Notifications {
+    config: NotificationConfig,
+    notifiers: Vec<Box<dyn Notifier>>,
+}
+
+impl Notifications {
+    pub fn new(config: &NotificationConfig) -> Result<Self, AppError> {
+        let mut notifiers: Vec<Box<dyn Notifier>> = Vec::new();
+        if config.desktop {
+            notifiers.push(Box::new(DesktopNotifier));
+        }
+        if config.bell {
+            notifiers.push(Box::new(TerminalBell));
+        }
+        for webhook in &config.webhooks {
+            notifiers.push(Box::new(WebhookNotifier::new(webhook)?));
+        }
+        Ok(Self::with_notifiers(config.clone(), notifiers))
+    }
+
+    pub fn with_notifiers(config: NotificationConfig, notifiers: Vec<Box<dyn Notifier>>) -> Self {
+        Notifications { config, notifiers }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        !self.notifiers.is_empty()
+    }
+
+    /// Hand every transition the rules let through to each notifier. Failures are only
+    /// logged, so a missing `notify-send` or an unreachable webhook never interrupts
+    /// monitoring.
+    pub fn send(&self, transitions: &[Transition]) {
+        for transition in transitions.iter().filter(|transition| self.config.wants(transition)) {
+            for notifier in &self.notifiers {
+                if let Err(e) = notifier.notify(transition) {
+                    log_warn(format!("Could not send {} notification for {}: {}", notifier.name(), transition.repo, e));
+                }
+            }
+        }
+    }
+
+    /// Wait for the notifications still being sent in the background, e.g. before exiting.
+    pub async fn flush(&self) {
+        for pending in self.notifiers.iter().flat_map(|notifier| notifier.pending()) {
+            let _ = pending.await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{WorkflowConclusion, WorkflowRun, WorkflowStatus};
+    use crate::notify::transitions::TransitionKind;
+    use chrono::Utc;
+    use std::sync::{Arc, Mutex};
+
+    struct RecordingNotifier(Arc<Mutex<Vec<String>>>);
+
+    impl Notifier for RecordingNotifier {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn notify(&self, transition: &Transition) -> Result<(), AppError> {
+            self.0.lock().unwrap().push(transition.title());
+            Ok(())
+        }
+    }
+
+    fn create_test_transition(name: &str, kind: TransitionKind) -> Transition {
+        Transition {
+            repo: "acme/website".to_string(),
+            kind,
+            run: WorkflowRun {
+                id: 1,
+                name: name.to_string(),
+                status: WorkflowStatus::Completed,
+                conclusion: Some(WorkflowConclusion::Success),
+                run_attempt: 1,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                branch: "main".to_string(),
+                commit_sha: "abc123".to_string(),
+                actor: "alice".to_string(),
+                html_url: "https://github.com/acme/website/actions/runs/1".to_string(),
+                logs_url: None,
+                pull_requests: Vec::new(),
+            },
+        }
+    }
+
+    #[test]
+    fn test_send_applies_rules() {
+        let sent = Arc::new(Mutex::new(Vec::new()));
+        let notifications = Notifications::with_notifiers(
+            NotificationConfig::default(),
+            vec![Box::new(RecordingNotifier(Arc::clone(&sent)))],
+        );
+        assert!(notifications.is_enabled());
+
+        notifications.send(&[
+            create_test_transition("CI", TransitionKind::Started),
+            create_test_transition("CI", TransitionKind::Fixed),
+            create_test_transition("Deploy", TransitionKind::Succeeded),
+        ]);
+        assert_eq!(*sent.lock().unwrap(), vec!["CI was fixed on main"]);
+    }
+
+    #[test]
+    fn test_disabled_by_default() {
+        assert!(!Notifications::new(&NotificationConfig::default()).unwrap().is_enabled());
+        assert!(!Notifications::default().is_enabled());
+    }
+}
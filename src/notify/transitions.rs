@@ -0,0 +1,293 @@
+use crate::github::models::{WorkflowConclusion, WorkflowRun, WorkflowStatus};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// State changes of a run that notifications can be sent for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransitionKind {
+    /// A run was queued, including re-runs.
+    Queued,
+    /// A run started.
+    Started,
+    /// A run succeeded or was skipped, and the workflow's previous run on the branch did not fail.
+    Succeeded,
+    /// A run failed or timed out, and the workflow's previous run on the branch did not succeed.
+    Failed,
+    Cancelled,
+    /// A run failed or timed out after the workflow's previous run on the branch succeeded, or
+    /// a re-run failed after the attempt it replaces succeeded.
+    Broken,
+    /// A run succeeded after the workflow's previous run on the branch failed, or a re-run
+    /// succeeded after the attempt it replaces failed.
+    Fixed,
+}
+
+impl TransitionKind {
+    fn verb(&self) -> &'static str {
+        match self {
+            TransitionKind::Queued => "queued",
+            TransitionKind::Started => "started",
+            TransitionKind::Succeeded => "succeeded",
+            TransitionKind::Failed => "failed",
+            TransitionKind::Cancelled => "was cancelled",
+            TransitionKind::Broken => "broke",
+            TransitionKind::Fixed => "was fixed",
+        }
+    }
+
+    /// Whether the transition is bad news, e.g. to raise the urgency of a notification.
+    pub fn is_failure(&self) -> bool {
+        matches!(self, TransitionKind::Failed | TransitionKind::Broken)
+    }
+}
+
+/// A run of `repo` that changed state between two refreshes.
+#[derive(Debug, Clone)]
+pub struct Transition {
+    /// Repository as shown in the UI.
+    pub repo: String,
+    pub kind: TransitionKind,
+    /// The run as of the newer refresh.
+    pub run: WorkflowRun,
+}
+
+impl Transition {
+    /// e.g. `CI failed on main`.
+    pub fn title(&self) -> String {
+        format!("{} {} on {}", self.run.name, self.kind.verb(), self.run.branch)
+    }
+
+    /// e.g. `acme/website: 0123456 by alice`.
+    pub fn body(&self) -> String {
+        let sha = &self.run.commit_sha[..self.run.commit_sha.len().min(7)];
+        format!("{}: {} by {}", self.repo, sha, self.run.actor)
+    }
+}
+
+/// How a completed `run` is reported, given the conclusion it is compared with: the attempt
+/// a re-run replaces, otherwise the workflow's previous completed run on its branch.
+fn completion_kind(run: &WorkflowRun, previous: Option<WorkflowConclusion>) -> Option<TransitionKind> {
+    let previous_failed = matches!(previous, Some(WorkflowConclusion::Failure | WorkflowConclusion::TimedOut));
+    match run.conclusion? {
+        WorkflowConclusion::Success if previous_failed => Some(TransitionKind::Fixed),
+        WorkflowConclusion::Success | WorkflowConclusion::Skipped => Some(TransitionKind::Succeeded),
+        WorkflowConclusion::Cancelled => Some(TransitionKind::Cancelled),
+        WorkflowConclusion::Failure | WorkflowConclusion::TimedOut => {
+            let previous_succeeded = previous == Some(WorkflowConclusion::Success);
+            Some(if previous_succeeded { TransitionKind::Broken } else { TransitionKind::Failed })
+        }
+    }
+}
+
+/// Detects the transitions between refreshes. It remembers the last completed attempt of each
+/// listed run, since a re-run clears the conclusion while it is queued and running.
+#[derive(Debug, Default)]
+pub struct TransitionDetector {
+    /// Attempt and conclusion of the last completed attempt per repository and run id.
+    attempts: HashMap<String, HashMap<u64, (u64, WorkflowConclusion)>>,
+}
+
+impl TransitionDetector {
+    /// Runs of `repo` that changed from the `previous` refresh to the `current` one, both
+    /// newest first, oldest change first. Runs that are new in `current` count as changed from
+    /// nothing; older runs that only scrolled into the listing are left out.
+    pub fn detect(&mut self, repo: &str, previous: &[WorkflowRun], current: &[WorkflowRun]) -> Vec<Transition> {
+        let mut attempts = self.attempts.remove(repo).unwrap_or_default();
+        for run in previous {
+            if let (WorkflowStatus::Completed, Some(conclusion)) = (run.status, run.conclusion) {
+                attempts.entry(run.id).or_insert((run.run_attempt, conclusion));
+            }
+        }
+
+        let oldest_known = previous.iter().map(|run| run.created_at).min();
+        let mut transitions = Vec::new();
+
+        for run in current.iter().rev() {
+            match previous.iter().find(|known| known.id == run.id) {
+                Some(known)
+                    if known.status == run.status
+                        && known.conclusion == run.conclusion
+                        && known.run_attempt == run.run_attempt =>
+                {
+                    continue
+                }
+                None if oldest_known.is_some_and(|oldest| run.created_at < oldest) => continue,
+                _ => {}
+            }
+
+            let kind = match run.status {
+                WorkflowStatus::Queued => Some(TransitionKind::Queued),
+                WorkflowStatus::InProgress => Some(TransitionKind::Started),
+                WorkflowStatus::Completed => {
+                    let replaced = attempts
+                        .get(&run.id)
+                        .filter(|(attempt, _)| *attempt < run.run_attempt)
+                        .map(|(_, conclusion)| *conclusion);
+                    // Otherwise the same workflow's newest earlier completed run on the branch,
+                    // from either refresh
+                    let earlier = || {
+                        current
+                            .iter()
+                            .chain(previous)
+                            .filter(|other| {
+                                other.id != run.id
+                                    && other.name == run.name
+                                    && other.branch == run.branch
+                                    && other.status == WorkflowStatus::Completed
+                                    && other.created_at < run.created_at
+                            })
+                            .max_by_key(|other| other.created_at)
+                            .and_then(|other| other.conclusion)
+                    };
+                    completion_kind(run, replaced.or_else(earlier))
+                }
+            };
+            if let Some(kind) = kind {
+                transitions.push(Transition {
+                    repo: repo.to_string(),
+                    kind,
+                    run: run.clone(),
+                });
+            }
+        }
+
+        // Only runs still listed are remembered
+        let attempts = current
+            .iter()
+            .filter_map(|run| match (run.status, run.conclusion) {
+                (WorkflowStatus::Completed, Some(conclusion)) => Some((run.id, (run.run_attempt, conclusion))),
+                _ => attempts.get(&run.id).map(|attempt| (run.id, *attempt)),
+            })
+            .collect();
+        self.attempts.insert(repo.to_string(), attempts);
+        transitions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, Utc};
+
+    fn create_test_run(id: u64, status: WorkflowStatus, conclusion: Option<WorkflowConclusion>) -> WorkflowRun {
+        WorkflowRun {
+            id,
+            name: "CI".to_string(),
+            status,
+            conclusion,
+            run_attempt: 1,
+            // Higher ids are newer
+            created_at: Utc::now() - Duration::hours(100 - id as i64),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: "0123456789abcdef".to_string(),
+            actor: "alice".to_string(),
+            html_url: format!("https://github.com/acme/website/actions/runs/{}", id),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn detect_transitions(repo: &str, previous: &[WorkflowRun], current: &[WorkflowRun]) -> Vec<Transition> {
+        TransitionDetector::default().detect(repo, previous, current)
+    }
+
+    fn kinds(transitions: &[Transition]) -> Vec<(u64, TransitionKind)> {
+        transitions.iter().map(|transition| (transition.run.id, transition.kind)).collect()
+    }
+
+    #[test]
+    fn test_status_changes() {
+        let success = Some(WorkflowConclusion::Success);
+        let previous = vec![
+            create_test_run(3, WorkflowStatus::Queued, None),
+            create_test_run(2, WorkflowStatus::InProgress, None),
+            create_test_run(1, WorkflowStatus::Completed, success),
+        ];
+        let current = vec![
+            create_test_run(4, WorkflowStatus::Queued, None),
+            create_test_run(3, WorkflowStatus::InProgress, None),
+            create_test_run(2, WorkflowStatus::Completed, Some(WorkflowConclusion::Cancelled)),
+            create_test_run(1, WorkflowStatus::Completed, success),
+        ];
+
+        let transitions = detect_transitions("acme/website", &previous, &current);
+        assert_eq!(kinds(&transitions), vec![
+            (2, TransitionKind::Cancelled),
+            (3, TransitionKind::Started),
+            (4, TransitionKind::Queued),
+        ]);
+        assert!(detect_transitions("acme/website", &current, &current).is_empty());
+    }
+
+    #[test]
+    fn test_broken_and_fixed() {
+        let success = Some(WorkflowConclusion::Success);
+        let failure = Some(WorkflowConclusion::Failure);
+
+        let previous = vec![create_test_run(2, WorkflowStatus::InProgress, None), create_test_run(1, WorkflowStatus::Completed, success)];
+        let current = vec![create_test_run(2, WorkflowStatus::Completed, failure), create_test_run(1, WorkflowStatus::Completed, success)];
+        let transitions = detect_transitions("acme/website", &previous, &current);
+        assert_eq!(kinds(&transitions), vec![(2, TransitionKind::Broken)]);
+        assert_eq!(transitions[0].title(), "CI broke on main");
+        assert_eq!(transitions[0].body(), "acme/website: 0123456 by alice");
+
+        // Still failing
+        let previous = current;
+        let current = vec![create_test_run(3, WorkflowStatus::Completed, failure), create_test_run(2, WorkflowStatus::Completed, failure)];
+        assert_eq!(kinds(&detect_transitions("acme/website", &previous, &current)), vec![(3, TransitionKind::Failed)]);
+
+        // The previous run only shows up in the older refresh
+        let previous = current;
+        let current = vec![create_test_run(4, WorkflowStatus::Completed, success)];
+        assert_eq!(kinds(&detect_transitions("acme/website", &previous, &current)), vec![(4, TransitionKind::Fixed)]);
+
+        // Other branches do not count
+        let mut other_branch = create_test_run(5, WorkflowStatus::Completed, failure);
+        other_branch.branch = "feature".to_string();
+        let current = vec![create_test_run(6, WorkflowStatus::Completed, success), other_branch];
+        let transitions = detect_transitions("acme/website", &[create_test_run(4, WorkflowStatus::Completed, success)], &current);
+        assert_eq!(kinds(&transitions), vec![(5, TransitionKind::Failed), (6, TransitionKind::Succeeded)]);
+    }
+
+    #[test]
+    fn test_older_runs_scrolling_in_are_ignored() {
+        let previous = vec![create_test_run(5, WorkflowStatus::Completed, Some(WorkflowConclusion::Success))];
+        let current = vec![
+            create_test_run(5, WorkflowStatus::Completed, Some(WorkflowConclusion::Success)),
+            create_test_run(4, WorkflowStatus::Completed, Some(WorkflowConclusion::Failure)),
+        ];
+        assert!(detect_transitions("acme/website", &previous, &current).is_empty());
+    }
+
+    #[test]
+    fn test_rerun_compares_with_the_replaced_attempt() {
+        let failure = Some(WorkflowConclusion::Failure);
+        let mut detector = TransitionDetector::default();
+        let mut rerun = create_test_run(2, WorkflowStatus::Queued, None);
+        rerun.run_attempt = 2;
+
+        // Run 2 failed after run 1 succeeded, then is re-run and passes
+        let first_attempt = vec![create_test_run(2, WorkflowStatus::Completed, failure), create_test_run(1, WorkflowStatus::Completed, Some(WorkflowConclusion::Success))];
+        let queued = vec![rerun.clone(), create_test_run(1, WorkflowStatus::Completed, Some(WorkflowConclusion::Success))];
+        assert_eq!(kinds(&detector.detect("acme/website", &first_attempt, &queued)), vec![(2, TransitionKind::Queued)]);
+
+        let mut passed = queued.clone();
+        passed[0].status = WorkflowStatus::Completed;
+        passed[0].conclusion = Some(WorkflowConclusion::Success);
+        assert_eq!(kinds(&detector.detect("acme/website", &queued, &passed)), vec![(2, TransitionKind::Fixed)]);
+    }
+
+    #[test]
+    fn test_rerun_between_refreshes() {
+        let previous = vec![create_test_run(1, WorkflowStatus::Completed, Some(WorkflowConclusion::Failure))];
+        let mut current = vec![create_test_run(1, WorkflowStatus::Completed, Some(WorkflowConclusion::Success))];
+        current[0].run_attempt = 2;
+        assert_eq!(kinds(&detect_transitions("acme/website", &previous, &current)), vec![(1, TransitionKind::Fixed)]);
+
+        // A re-run that fails again is reported again
+        current[0].conclusion = Some(WorkflowConclusion::Failure);
+        assert_eq!(kinds(&detect_transitions("acme/website", &previous, &current)), vec![(1, TransitionKind::Failed)]);
+    }
+}
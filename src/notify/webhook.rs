@@ -0,0 +1,398 @@
+use crate::config::notifications::{WebhookConfig, WebhookFormat};
+use crate::error::AppError;
+use crate::notify::transitions::{Transition, TransitionKind};
+use crate::notify::Notifier;
+use crate::utils::logging::log_warn;
+use chrono::{DateTime, Utc};
+use http::{header, Request, StatusCode, Uri};
+use http_body_util::Full;
+use hyper::body::Bytes;
+use hyper_rustls::{HttpsConnector, HttpsConnectorBuilder};
+use hyper_util::client::legacy::connect::HttpConnector;
+use hyper_util::client::legacy::Client;
+use hyper_util::rt::TokioExecutor;
+use serde_json::json;
+use std::collections::{HashSet, VecDeque};
+use std::io;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+/// How many sent transitions are remembered to leave out duplicates.
+const SENT_REMEMBERED: usize = 1000;
+
+/// Longest wait between two attempts, however many retries are configured.
+const MAX_RETRY_DELAY: Duration = Duration::from_secs(60);
+
+/// Identifies a transition across refreshes; a re-run moves `updated_at`, so its transitions
+/// are sent again.
+type SentKey = (String, u64, TransitionKind, DateTime<Utc>);
+
+#[derive(Default)]
+struct SentTransitions {
+    keys: HashSet<SentKey>,
+    order: VecDeque<SentKey>,
+}
+
+impl SentTransitions {
+    /// Remember `key`; `false` when it was already sent.
+    fn insert(&mut self, key: SentKey) -> bool {
+        if !self.keys.insert(key.clone()) {
+            return false;
+        }
+        self.order.push_back(key);
+        if self.order.len() > SENT_REMEMBERED
+            && let Some(oldest) = self.order.pop_front()
+        {
+            self.keys.remove(&oldest);
+        }
+        true
+    }
+
+    /// Forget `key` so that it can be sent again.
+    fn remove(&mut self, key: &SentKey) {
+        if self.keys.remove(key) {
+            self.order.retain(|sent| sent != key);
+        }
+    }
+}
+
+/// Escape the characters Slack reads as markup in mrkdwn text.
+fn slack_escape(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
+
+/// Posts transitions to a Slack, Microsoft Teams or other JSON webhook in the background,
+/// each transition once, retrying with a growing delay.
+#[derive(Clone)]
+pub struct WebhookNotifier {
+    uri: Uri,
+    format: WebhookFormat,
+    retries: u32,
+    retry_delay: Duration,
+    client: Client<HttpsConnector<HttpConnector>, Full<Bytes>>,
+    sent: Arc<Mutex<SentTransitions>>,
+    /// Deliveries started in the background that may still be running.
+    deliveries: Arc<Mutex<Vec<JoinHandle<()>>>>,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: &WebhookConfig) -> Result<Self, AppError> {
+        let uri: Uri = config
+            .url
+            .parse()
+            .ok()
+            .filter(|uri: &Uri| matches!(uri.scheme_str(), Some("http" | "https")) && uri.host().is_some())
+            .ok_or_else(|| AppError::ConfigError("Webhook URL must be an http or https URL".to_string()))?;
+        let connector = HttpsConnectorBuilder::new()
+            .with_native_roots()?
+            .https_or_http()
+            .enable_http1()
+            .build();
+
+        Ok(WebhookNotifier {
+            uri,
+            format: config.format,
+            retries: config.retries,
+            retry_delay: Duration::from_secs(2),
+            client: Client::builder(TokioExecutor::new()).build(connector),
+            sent: Arc::new(Mutex::new(SentTransitions::default())),
+            deliveries: Arc::new(Mutex::new(Vec::new())),
+        })
+    }
+
+    /// Endpoint for log messages, without the path and query that hold the credentials.
+    fn host(&self) -> &str {
+        self.uri.host().unwrap_or_default()
+    }
+
+    pub fn payload(&self, transition: &Transition) -> serde_json::Value {
+        let run = &transition.run;
+        let headline = format!("{} in {}", transition.title(), transition.repo);
+        match self.format {
+            WebhookFormat::Slack => {
+                let emoji = match transition.kind {
+                    kind if kind.is_failure() => ":x:",
+                    TransitionKind::Succeeded | TransitionKind::Fixed => ":white_check_mark:",
+                    _ => ":information_source:",
+                };
+                json!({
+                    "text": format!(
+                        "{} *{}*\n{} <{}|View run>",
+                        emoji,
+                        slack_escape(&headline),
+                        slack_escape(&transition.body()),
+                        run.html_url
+                    ),
+                })
+            }
+            WebhookFormat::Teams => {
+                let color = match transition.kind {
+                    kind if kind.is_failure() => "D93F0B",
+                    TransitionKind::Succeeded | TransitionKind::Fixed => "2EA043",
+                    _ => "6E7781",
+                };
+                json!({
+                    "@type": "MessageCard",
+                    "@context": "https://schema.org/extensions",
+                    "themeColor": color,
+                    "summary": headline,
+                    "title": headline,
+                    "text": transition.body(),
+                    "potentialAction": [{
+                        "@type": "OpenUri",
+                        "name": "View run",
+                        "targets": [{ "os": "default", "uri": run.html_url }],
+                    }],
+                })
+            }
+            WebhookFormat::Json => json!({
+                "event": transition.kind,
+                "repository": transition.repo,
+                "title": transition.title(),
+                "run": run,
+            }),
+        }
+    }
+
+    /// Post `transition` unless it was posted before. Returns whether it was posted.
+    pub async fn deliver(&self, transition: &Transition) -> Result<bool, AppError> {
+        let key = (transition.repo.clone(), transition.run.id, transition.kind, transition.run.updated_at);
+        if !self.sent.lock().unwrap().insert(key.clone()) {
+            return Ok(false);
+        }
+
+        let body = self.payload(transition).to_string();
+        let mut attempt = 0;
+        loop {
+            match self.post(&body).await {
+                Ok(()) => return Ok(true),
+                Err((e, retry)) if retry && attempt < self.retries => {
+                    attempt += 1;
+                    log_warn(format!("Webhook to {} failed ({}), retrying ({}/{})", self.host(), e, attempt, self.retries));
+                    tokio::time::sleep(self.backoff(attempt)).await;
+                }
+                Err((e, _)) => {
+                    // Not delivered, so a later refresh reporting it again may still send it
+                    self.sent.lock().unwrap().remove(&key);
+                    return Err(e);
+                }
+            }
+        }
+    }
+
+    /// Wait before retry number `attempt`, doubling from `retry_delay` up to `MAX_RETRY_DELAY`.
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.retry_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(MAX_RETRY_DELAY)
+    }
+
+    /// Send one request. Errors come with whether retrying may help.
+    async fn post(&self, body: &str) -> Result<(), (AppError, bool)> {
+        let request = Request::post(self.uri.clone())
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::USER_AGENT, "nighthub")
+            .body(Full::new(Bytes::from(body.to_string())))
+            .map_err(|e| (AppError::ConfigError(e.to_string()), false))?;
+
+        let response = tokio::time::timeout(Duration::from_secs(10), self.client.request(request))
+            .await
+            .map_err(|_| (AppError::IoError(io::Error::new(io::ErrorKind::TimedOut, "Webhook timeout after 10 seconds")), true))?
+            .map_err(|e| (AppError::IoError(io::Error::other(format!("Webhook request failed: {}", e))), true))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            let retry = status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS;
+            Err((AppError::IoError(io::Error::other(format!("Webhook answered {}", status))), retry))
+        }
+    }
+}
+
+impl Notifier for WebhookNotifier {
+    fn name(&self) -> &'static str {
+        "webhook"
+    }
+
+    fn notify(&self, transition: &Transition) -> Result<(), AppError> {
+        let runtime = tokio::runtime::Handle::try_current()
+            .map_err(|e| AppError::ConfigError(format!("Webhooks need a Tokio runtime: {}", e)))?;
+        let (notifier, transition) = (self.clone(), transition.clone());
+        let delivery = runtime.spawn(async move {
+            if let Err(e) = notifier.deliver(&transition).await {
+                log_warn(format!("Could not post {} of {} to {}: {}", transition.title(), transition.repo, notifier.host(), e));
+            }
+        });
+        let mut deliveries = self.deliveries.lock().unwrap();
+        deliveries.retain(|delivery| !delivery.is_finished());
+        deliveries.push(delivery);
+        Ok(())
+    }
+
+    fn pending(&self) -> Vec<JoinHandle<()>> {
+        std::mem::take(&mut *self.deliveries.lock().unwrap())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{WorkflowConclusion, WorkflowRun, WorkflowStatus};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Stand-in for a webhook endpoint answering with `statuses` in turn, then 200. Returns
+    /// its URL and the bodies it received.
+    async fn start_server(statuses: Vec<u16>) -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks/T000/B000", listener.local_addr().unwrap());
+        let bodies = Arc::new(Mutex::new(Vec::new()));
+        let received = Arc::clone(&bodies);
+        tokio::spawn(async move {
+            let mut statuses = statuses.into_iter();
+            loop {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+                // Read the headers, then as much body as Content-Length announces
+                let body = loop {
+                    let read = socket.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length: usize = head
+                            .lines()
+                            .find_map(|line| line.to_lowercase().strip_prefix("content-length:").map(|value| value.trim().parse().unwrap()))
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break body.to_string();
+                        }
+                    }
+                };
+                received.lock().unwrap().push(body);
+                let status = statuses.next().unwrap_or(200);
+                let response = format!("HTTP/1.1 {} Test\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status);
+                socket.write_all(response.as_bytes()).await.unwrap();
+            }
+        });
+        (url, bodies)
+    }
+
+    fn create_test_notifier(url: &str, format: WebhookFormat, retries: u32) -> WebhookNotifier {
+        let config = WebhookConfig { url: url.to_string(), format, retries };
+        WebhookNotifier {
+            retry_delay: Duration::from_millis(10),
+            ..WebhookNotifier::new(&config).unwrap()
+        }
+    }
+
+    fn create_test_transition(kind: TransitionKind) -> Transition {
+        Transition {
+            repo: "acme/website".to_string(),
+            kind,
+            run: WorkflowRun {
+                id: 42,
+                name: "CI".to_string(),
+                status: WorkflowStatus::Completed,
+                conclusion: Some(WorkflowConclusion::Failure),
+                run_attempt: 1,
+                created_at: Utc::now(),
+                updated_at: Utc::now(),
+                branch: "main".to_string(),
+                commit_sha: "0123456789abcdef".to_string(),
+                actor: "alice".to_string(),
+                html_url: "https://github.com/acme/website/actions/runs/42".to_string(),
+                logs_url: None,
+                pull_requests: Vec::new(),
+            },
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_then_sends_once() {
+        let (url, bodies) = start_server(vec![500, 503]).await;
+        let notifier = create_test_notifier(&url, WebhookFormat::Json, 3);
+        let transition = create_test_transition(TransitionKind::Broken);
+
+        assert!(notifier.deliver(&transition).await.unwrap());
+        assert!(!notifier.deliver(&transition).await.unwrap());
+
+        let bodies = bodies.lock().unwrap();
+        assert_eq!(bodies.len(), 3);
+        let payload: serde_json::Value = serde_json::from_str(&bodies[2]).unwrap();
+        assert_eq!(payload["event"], "broken");
+        assert_eq!(payload["repository"], "acme/website");
+        assert_eq!(payload["run"]["id"], 42);
+    }
+
+    #[tokio::test]
+    async fn test_flush_waits_for_background_deliveries() {
+        let (url, bodies) = start_server(vec![503]).await;
+        let notifications = crate::notify::Notifications::with_notifiers(
+            crate::config::notifications::NotificationConfig::default(),
+            vec![Box::new(create_test_notifier(&url, WebhookFormat::Json, 3))],
+        );
+
+        notifications.send(&[create_test_transition(TransitionKind::Broken)]);
+        notifications.flush().await;
+        assert_eq!(bodies.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_gives_up() {
+        let (url, bodies) = start_server(vec![500, 500, 500]).await;
+        let notifier = create_test_notifier(&url, WebhookFormat::Json, 1);
+        let transition = create_test_transition(TransitionKind::Failed);
+        assert!(notifier.deliver(&transition).await.is_err());
+        assert_eq!(bodies.lock().unwrap().len(), 2);
+
+        // A transition that was never delivered is not left out the next time
+        assert!(notifier.deliver(&transition).await.unwrap());
+        assert_eq!(bodies.lock().unwrap().len(), 4);
+
+        // Client errors are not retried
+        let (url, bodies) = start_server(vec![404]).await;
+        assert!(create_test_notifier(&url, WebhookFormat::Json, 3).deliver(&create_test_transition(TransitionKind::Failed)).await.is_err());
+        assert_eq!(bodies.lock().unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_payload_formats() {
+        let transition = create_test_transition(TransitionKind::Broken);
+
+        let slack = create_test_notifier("https://hooks.slack.com/services/T000/B000", WebhookFormat::Slack, 0).payload(&transition);
+        assert_eq!(
+            slack["text"],
+            ":x: *CI broke on main in acme/website*\nacme/website: 0123456 by alice <https://github.com/acme/website/actions/runs/42|View run>"
+        );
+
+        let mut transition = create_test_transition(TransitionKind::Broken);
+        transition.run.branch = "fix/<script>&co".to_string();
+        let slack = create_test_notifier("https://hooks.slack.com/services/T000/B000", WebhookFormat::Slack, 0).payload(&transition);
+        assert!(slack["text"].as_str().unwrap().contains("on fix/&lt;script&gt;&amp;co in"));
+
+        let teams = create_test_notifier("https://example.webhook.office.com/webhookb2/1", WebhookFormat::Teams, 0).payload(&transition);
+        assert_eq!(teams["@type"], "MessageCard");
+        assert_eq!(teams["themeColor"], "D93F0B");
+        assert_eq!(teams["potentialAction"][0]["targets"][0]["uri"], "https://github.com/acme/website/actions/runs/42");
+    }
+
+    #[test]
+    fn test_backoff_is_capped() {
+        let notifier = create_test_notifier("https://example.com/hook", WebhookFormat::Json, 3);
+        assert_eq!(notifier.backoff(1), Duration::from_millis(10));
+        assert_eq!(notifier.backoff(3), Duration::from_millis(40));
+        assert_eq!(notifier.backoff(40), MAX_RETRY_DELAY);
+        assert_eq!(notifier.backoff(u32::MAX), MAX_RETRY_DELAY);
+    }
+
+    #[test]
+    fn test_rejects_invalid_url() {
+        for url in ["hooks.slack.com/services/T000", "ftp://example.com/hook", "not a url"] {
+            let config = WebhookConfig { url: url.to_string(), format: WebhookFormat::Json, retries: 0 };
+            assert!(WebhookNotifier::new(&config).is_err(), "{}", url);
+        }
+    }
+}
@@ -1,8 +1,21 @@
-use crate::config::settings::Settings;
+use crate::config::settings::{RepositoryConfig, Settings};
 use crate::error::AppError;
-use crate::github::client::GithubClient;
-use crate::github::models::{Repository, WorkflowRun};
+use crate::github::pool::ClientPool;
+use crate::github::rate_limit::RateLimitTracker;
+use crate::github::models::{Repository, RunAction, WorkflowRun};
+use crate::notify::transitions::TransitionDetector;
+use crate::notify::hooks::ShellHooks;
+use crate::notify::Notifications;
+use crate::ui::components::confirm_dialog::ConfirmDialogComponent;
 use crate::ui::components::context_menu::ContextMenuComponent;
+use crate::ui::components::dispatch_form::{DispatchFormComponent, DispatchStage};
+use crate::ui::components::job_list::JobListComponent;
+use crate::ui::components::pull_request_list::PullRequestListComponent;
+use crate::ui::components::run_history::RunHistoryComponent;
+use crate::ui::components::log_viewer::{LogSource, LogViewerComponent};
+use crate::ui::refresh::{DiscoveryTask, LoadEvent, LoadRequest, Loader, RefreshEvent, Refresher};
+use crate::ui::theme::Theme;
+use crate::utils::local_git::LocalCheckout;
 use crate::utils::logging::{log_error, log_info, log_warn};
 use std::collections::{HashMap, HashSet};
 use std::sync::{Arc, RwLock};
@@ -15,6 +28,11 @@ use chrono::{DateTime, Utc};
 pub enum PopupType {
     ContextMenu,
     Logs,
+    Jobs,
+    History,
+    PullRequests,
+    Confirm,
+    Dispatch,
 }
 
 
@@ -26,15 +44,57 @@ pub struct AppState {
     pub selected_run: Option<usize>,
     pub popup: Option<PopupType>,
     pub context_menu: ContextMenuComponent,
+    pub job_list: JobListComponent,
+    pub run_history: RunHistoryComponent,
+    pub pull_request_list: PullRequestListComponent,
+    pub log_viewer: LogViewerComponent,
+    pub confirm_dialog: ConfirmDialogComponent,
+    pub dispatch_form: DispatchFormComponent,
+    pub theme: Theme,
     pub settings: Settings,
-    pub github_client: GithubClient,
+    pub github_clients: ClientPool,
     pub last_repo_refresh_times: HashMap<String, DateTime<Utc>>,
     pub refreshing_repos: Arc<RwLock<HashSet<String>>>,
+    pub refresher: Refresher,
+    /// Requests of the popups, run in the background like refreshes.
+    pub loader: Loader,
+    pub discovery: DiscoveryTask,
+    /// HEAD of the git checkout nighthub runs in, to highlight the runs for it.
+    pub local_checkout: LocalCheckout,
+    /// Announces runs changing state between refreshes.
+    pub notifications: Notifications,
+    /// Commands run when runs change state between refreshes.
+    pub hooks: ShellHooks,
+    /// Remembers the runs' last completed attempts, so re-runs are announced correctly.
+    pub transitions: TransitionDetector,
+    /// Runs as GitHub last reported them per repository. Transitions are detected against
+    /// these rather than `workflow_runs`, which also shows optimistic updates.
+    pub fetched_runs: HashMap<String, Vec<WorkflowRun>>,
 }
 
 impl AppState {
-    /// Calculate refresh interval based on activity (pure tiered logic)
+    /// Calculate refresh interval based on activity, within the repository's configured bounds
     fn calculate_refresh_interval(&self, repo_full_name: &str) -> Duration {
+        let mut interval = self.activity_refresh_interval(repo_full_name);
+        if let Some(config) = self.repo_config(repo_full_name) {
+            if let Some(max) = config.max_refresh_seconds {
+                interval = interval.min(Duration::from_secs(max));
+            }
+            if let Some(min) = config.min_refresh_seconds {
+                interval = interval.max(Duration::from_secs(min));
+            }
+        }
+
+        // Intervals are stretched as the rate limit budget of the repository's host runs low,
+        // even beyond the configured maximum
+        let multiplier = self
+            .rate_limits_for(repo_full_name)
+            .map_or(1, |rate_limits| rate_limits.interval_multiplier(Utc::now()));
+        interval * multiplier
+    }
+
+    fn activity_refresh_interval(&self, repo_full_name: &str) -> Duration {
+        let tiers = &self.settings.monitoring.refresh_tiers;
         // Tiered calculation based on activity
         if let Some(runs) = self.workflow_runs.get(repo_full_name) {
             if let Some(latest_run) = runs.first() {
@@ -42,42 +102,70 @@ impl AppState {
                 let time_since_activity = now - latest_run.updated_at;
                 
                 if time_since_activity.num_seconds() < 7200 {  // <2 hours
-                    Duration::from_secs(5)        // Very active: 5 seconds
+                    Duration::from_secs(tiers.active_seconds)     // Very active: 5 seconds by default
                 } else if time_since_activity.num_hours() < 24 {
-                    Duration::from_secs(60)       // Moderately active: 1 minute
+                    Duration::from_secs(tiers.recent_seconds)     // Moderately active: 1 minute
                 } else {
-                    Duration::from_secs(7200)     // Inactive: 2 hours
+                    Duration::from_secs(tiers.idle_seconds)       // Inactive: 2 hours
                 }
             } else {
                 // No workflow runs, treat as inactive
-                Duration::from_secs(7200)
+                Duration::from_secs(tiers.idle_seconds)
             }
         } else {
             // No workflow data, treat as inactive
-            Duration::from_secs(7200)
+            Duration::from_secs(tiers.idle_seconds)
         }
     }
 
+    /// Configuration of a monitored repository; `None` for discovered ones.
+    fn repo_config(&self, repo_full_name: &str) -> Option<&RepositoryConfig> {
+        let repo = self.repositories.iter().find(|repo| repo.full_name == repo_full_name)?;
+        self.settings.repositories().iter().find(|config| {
+            config.host == repo.host
+                && config.owner.eq_ignore_ascii_case(&repo.owner)
+                && config.name.eq_ignore_ascii_case(&repo.name)
+        })
+    }
+
 
 
 pub async fn new_without_refresh(settings: Settings) -> Result<Self, AppError> {
-    let github_client = GithubClient::new(settings.clone())?;
+    let theme = Theme::from_config(&settings.ui)?;
+    let github_clients = ClientPool::new(&settings)?;
+    let refresher = Refresher::new(settings.monitoring.max_concurrent_requests);
+    let notifications = Notifications::new(&settings.notifications)?;
+    let hooks = ShellHooks::new(&settings.hooks, &settings.notifications);
     
+    // Expand org, topic and glob patterns; the listed repositories are still monitored when
+    // that fails
+    let configs = match github_clients.resolve_repositories(&settings).await {
+        Ok(configs) => configs,
+        Err(e) => {
+            log_error(format!("Repository discovery failed: {}", e));
+            settings.repositories().to_vec()
+        }
+    };
+
     // Try to fetch repositories, but if it fails, create repos from REPOS env var
-    let repositories = match github_client.fetch_repositories().await {
+    let repositories = match github_clients.fetch_repositories(&configs).await {
         Ok(repos) => repos,
         Err(_) => {
             // Create repositories from REPOS env var when API fails (for testing refresh indicators)
             log_info("GitHub API failed, creating repositories from REPOS env var for demo".to_string());
             // Convert RepositoryConfig to Repository
-            settings.repositories.iter().map(|config| {
+            configs.iter().map(|config| {
+                let host = config.host.as_deref();
+                let full_name = format!("{}/{}", config.owner, config.name);
                 Repository {
                     id: 0,
                     name: config.name.clone(),
                     owner: config.owner.clone(),
-                    full_name: format!("{}/{}", config.owner, config.name),
-                    html_url: format!("https://github.com/{}/{}", config.owner, config.name),
+                    full_name: host.map_or(full_name.clone(), |host| format!("{}/{}", host, full_name)),
+                    html_url: format!("{}/{}", Settings::web_url(host), full_name),
                     default_branch: Some("main".to_string()),
+                    host: config.host.clone(),
+                    credential: config.credential.clone(),
                 }
             }).collect()
         }
@@ -90,17 +178,32 @@ pub async fn new_without_refresh(settings: Settings) -> Result<Self, AppError> {
         selected_run: None,
         popup: None,
         context_menu: ContextMenuComponent::new(),
+        job_list: JobListComponent::new(),
+        run_history: RunHistoryComponent::new(),
+        pull_request_list: PullRequestListComponent::new(),
+        log_viewer: LogViewerComponent::new(),
+        confirm_dialog: ConfirmDialogComponent::new(),
+        dispatch_form: DispatchFormComponent::new(),
+        theme,
         settings,
-        github_client,
+        github_clients,
         last_repo_refresh_times: HashMap::new(),
         refreshing_repos: Arc::new(RwLock::new(HashSet::new())),
+        refresher,
+        loader: Loader::default(),
+        discovery: DiscoveryTask::default(),
+        local_checkout: LocalCheckout::detect(),
+        notifications,
+        hooks,
+        transitions: TransitionDetector::default(),
+        fetched_runs: HashMap::new(),
     })
 }
 
 pub async fn new(settings: Settings) -> Result<Self, AppError> {
     let mut app_state = AppState::new_without_refresh(settings).await?;
-    // Initial refresh to populate workflow data and set proper timers
-    let _ = app_state.refresh(true).await;
+    // Initial refresh runs in the background; repositories show up as their results arrive
+    app_state.start_refresh(true);
     
     // Initialize selection if we have repositories
     if !app_state.repositories.is_empty() {
@@ -114,14 +217,41 @@ pub async fn new(settings: Settings) -> Result<Self, AppError> {
 
 
 pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
-    let now = Utc::now();
-    
-    // Collect repositories to refresh
-    let repos_to_refresh: Vec<_> = if force_all {
-        self.repositories.iter().cloned().collect()
-    } else {
-        self.repositories.iter()
+    if self.start_refresh(force_all) == 0 {
+        return Ok(());
+    }
+
+    // Wait for every refresh in flight, including ones started earlier
+    tokio::time::timeout(Duration::from_secs(60), async {
+        while self.refresher.in_flight_count() > 0 {
+            match self.refresher.next().await {
+                Some(event) => self.apply_refresh_event(event),
+                None => break,
+            }
+        }
+    })
+    .await
+    .map_err(|_| AppError::GithubError("Refresh operation timed out".to_string()))
+}
+
+    /// Spawn background refreshes for every repository that is due (or all of them with
+    /// `force_all`) and not already being refreshed. Returns how many were started.
+    pub fn start_refresh(&mut self, force_all: bool) -> usize {
+        let now = Utc::now();
+        let repos_to_refresh: Vec<Repository> = self.repositories.iter()
+            .filter(|repo| !self.refresher.is_in_flight(&repo.full_name))
+            // Hosts are rate limited separately, so a paused host leaves the others running
+            .filter(|repo| match self.repo_paused_until(&repo.full_name) {
+                Some(until) => {
+                    log_warn(format!("Rate limited, not refreshing {} until {}", repo.full_name, until));
+                    false
+                }
+                None => true,
+            })
             .filter(|repo| {
+                if force_all {
+                    return true;
+                }
                 if let Some(last_refresh_time) = self.last_repo_refresh_times.get(&repo.full_name) {
                     let refresh_interval = self.calculate_refresh_interval(&repo.full_name);
                     let time_since_refresh = now - *last_refresh_time;
@@ -134,104 +264,156 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
                 }
             })
             .cloned()
-            .collect()
-    };
-    
-    if repos_to_refresh.is_empty() {
-        return Ok(());
-    }
-    
-    log_info(format!("Refreshing {} repositories (force_all={})", repos_to_refresh.len(), force_all));
-    
-    // Add repositories to refreshing set
-    {
-        let mut refreshing = self.refreshing_repos.write().unwrap();
+            .collect();
+
+        if !repos_to_refresh.is_empty() {
+            log_info(format!("Refreshing {} repositories (force_all={})", repos_to_refresh.len(), force_all));
+        }
         for repo in &repos_to_refresh {
-            refreshing.insert(repo.full_name.clone());
+            match self.github_clients.for_repo(repo) {
+                Ok(client) => self.refresher.spawn(client, repo),
+                Err(e) => log_error(format!("Cannot refresh {}: {}", repo.full_name, e)),
+            }
         }
-        log_info(format!("Added {} repos to refreshing set", repos_to_refresh.len()));
+        repos_to_refresh.len()
     }
-    
-    // Use a semaphore to limit concurrent requests
-    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(
-        self.settings.monitoring.max_concurrent_requests
-    ));
-    
-    // Spawn parallel tasks for each repository with concurrency control
-    let mut tasks = Vec::new();
-    for repo in repos_to_refresh {
-        let github_client = self.github_client.clone();
-        let repo_name = repo.full_name.clone();
-        let owner = repo.owner.clone();
-        let name = repo.name.clone();
-        let semaphore = std::sync::Arc::clone(&semaphore);
-        let refreshing_repos = Arc::clone(&self.refreshing_repos);
-        
-        let task = tokio::spawn(async move {
-            // Acquire semaphore permit before making request
-            let _permit = semaphore.acquire().await
-                .map_err(|_| AppError::GithubError("Failed to acquire semaphore permit".to_string()))?;
-            
-            let result = github_client
-                .fetch_workflow_runs(&owner, &name)
-                .await;
-            
-            // Remove from refreshing set when done (regardless of success/failure)
-            {
-                let mut refreshing = refreshing_repos.write().unwrap();
-                refreshing.remove(&repo_name);
-            }
-            
-            let runs = result?;
-            Ok::<(String, Vec<WorkflowRun>), AppError>((repo_name, runs))
-        });
-        tasks.push(task);
+
+    /// Read the local checkout's HEAD again, and refresh its repository right away when HEAD
+    /// moved or was pushed so the runs for a push show up without waiting for the interval.
+    pub fn poll_local_head(&mut self) {
+        if !self.local_checkout.poll() {
+            return;
+        }
+        if let Some(head) = &self.local_checkout.head
+            && let Some(repo) = self.repositories.iter().find(|repo| head.is_repository(&repo.full_name))
+        {
+            self.last_repo_refresh_times.remove(&repo.full_name);
+        }
     }
-    
-    // Wait for all tasks to complete with timeout
-    let results = tokio::time::timeout(
-        Duration::from_secs(60), // Total timeout for all operations
-        futures::future::join_all(tasks)
-    ).await
-    .map_err(|_| AppError::GithubError("Refresh operation timed out".to_string()))?;
-    
-    // Process results
-    let mut success_count = 0;
-    let mut error_count = 0;
-    
-    for result in results {
-        match result {
-            Ok(Ok((repo_name, runs))) => {
-                self.workflow_runs.insert(repo_name.clone(), runs);
-                self.last_repo_refresh_times.insert(repo_name, now);
-                success_count += 1;
+
+    /// Expand the discovery patterns again in the background once their refresh interval has
+    /// passed. Returns whether a discovery was started.
+    pub fn start_discovery(&mut self) -> bool {
+        if !self.discovery.is_due(&self.settings, Utc::now()) {
+            return false;
+        }
+        self.discovery.spawn(&self.github_clients, &self.settings, self.repositories.clone());
+        true
+    }
+
+    /// Swap in the repositories found by the last discovery, once it has finished.
+    pub fn apply_discovery_results(&mut self) {
+        match self.discovery.try_result() {
+            Some(Ok(repositories)) => self.set_repositories(repositories),
+            Some(Err(e)) => log_warn(format!("Repository discovery failed, keeping the current repositories: {}", e)),
+            None => {}
+        }
+    }
+
+    /// Replace the monitored repositories, dropping the runs of removed ones and keeping the
+    /// selected repository selected when it is still there.
+    pub fn set_repositories(&mut self, repositories: Vec<Repository>) {
+        let selected = self.selected_repo
+            .and_then(|index| self.repositories.get(index))
+            .map(|repo| repo.full_name.clone());
+        let added = repositories.iter().filter(|repo| self.find_repository(&repo.full_name).is_none()).count();
+        let removed = self
+            .repositories
+            .iter()
+            .filter(|known| !repositories.iter().any(|repo| repo.full_name == known.full_name))
+            .count();
+        if added > 0 || removed > 0 {
+            log_info(format!("Discovery added {} and removed {} repositories", added, removed));
+        }
+
+        self.repositories = repositories;
+        let names: HashSet<&str> = self.repositories.iter().map(|repo| repo.full_name.as_str()).collect();
+        self.workflow_runs.retain(|full_name, _| names.contains(full_name.as_str()));
+        self.fetched_runs.retain(|full_name, _| names.contains(full_name.as_str()));
+        self.last_repo_refresh_times.retain(|full_name, _| names.contains(full_name.as_str()));
+
+        let index = selected.and_then(|full_name| self.repositories.iter().position(|repo| repo.full_name == full_name));
+        if index != self.selected_repo {
+            self.selected_repo = index.or_else(|| (!self.repositories.is_empty()).then_some(0));
+            self.selected_run = self.selected_repo.map(|_| 0);
+        }
+    }
+
+    /// Refresh a single repository right away, outside of the regular schedule. A refresh
+    /// already running for it is superseded since it may predate the change being waited on.
+    pub fn request_repo_refresh(&mut self, repo_full_name: &str) {
+        if let Some(repo) = self.find_repository(repo_full_name) {
+            match self.github_clients.for_repo(&repo) {
+                Ok(client) => self.refresher.spawn(client, &repo),
+                Err(e) => log_error(format!("Cannot refresh {}: {}", repo_full_name, e)),
             }
-            Ok(Err(e)) => {
-                log_error(format!("Failed to refresh repository: {}", e));
-                error_count += 1;
+        }
+    }
+
+    /// Apply every refresh result that has arrived since the last frame, without waiting.
+    pub fn apply_refresh_results(&mut self) -> usize {
+        let mut applied = 0;
+        while let Some(event) = self.refresher.try_next() {
+            self.apply_refresh_event(event);
+            applied += 1;
+        }
+        applied
+    }
+
+    pub fn apply_refresh_event(&mut self, event: RefreshEvent) {
+        if !self.refresher.accept(&event) {
+            return;
+        }
+
+        match event {
+            RefreshEvent::Started { repo, .. } => {
+                self.refreshing_repos.write().unwrap().insert(repo);
             }
-            Err(e) => {
-                log_error(format!("Task join error: {}", e));
-                error_count += 1;
+            RefreshEvent::Finished { repo, result, .. } => {
+                self.refreshing_repos.write().unwrap().remove(&repo);
+                match result {
+                    Ok(runs) => {
+                        // The first refresh of a repository only sets the baseline
+                        if (self.notifications.is_enabled() || self.hooks.is_enabled())
+                            && let Some(previous) = self.fetched_runs.get(&repo)
+                        {
+                            let transitions = self.transitions.detect(&repo, previous, &runs);
+                            self.notifications.send(&transitions);
+                            self.hooks.run(&transitions);
+                        }
+                        self.fetched_runs.insert(repo.clone(), runs.clone());
+                        self.workflow_runs.insert(repo.clone(), runs);
+                    }
+                    Err(e) => log_error(format!("Failed to refresh repository {}: {}", repo, e)),
+                }
+                // Failed repositories also wait a full interval before the next attempt
+                self.last_repo_refresh_times.insert(repo, Utc::now());
             }
         }
     }
-    
-    // Log summary if there were errors
-    if error_count > 0 {
-        log_warn(format!("Refresh completed: {} successful, {} failed", success_count, error_count));
-    } else {
-        log_info(format!("Refresh completed: {} successful", success_count));
+
+    /// Rate limit budget of the host `repo_full_name` lives on.
+    fn rate_limits_for(&self, repo_full_name: &str) -> Option<&RateLimitTracker> {
+        let repo = self.repositories.iter().find(|repo| repo.full_name == repo_full_name)?;
+        self.github_clients.for_repo(repo).ok().map(|client| client.rate_limits())
+    }
+
+    /// Time until which refreshing `repo_full_name` is paused because the rate limit budget
+    /// of its host is exhausted.
+    pub fn repo_paused_until(&self, repo_full_name: &str) -> Option<DateTime<Utc>> {
+        self.rate_limits_for(repo_full_name)?.paused_until(Utc::now())
     }
-    
-    Ok(())
-}
 
     pub fn seconds_until_refresh(&self) -> u64 {
         let now = Utc::now();
         let mut min_seconds_until_refresh = u64::MAX;
         
         for repo in &self.repositories {
+            if let Some(until) = self.repo_paused_until(&repo.full_name) {
+                let seconds_until = (until - now).num_seconds().max(1) as u64;
+                min_seconds_until_refresh = min_seconds_until_refresh.min(seconds_until);
+                continue;
+            }
             let refresh_interval = self.calculate_refresh_interval(&repo.full_name);
             
             if let Some(last_refresh_time) = self.last_repo_refresh_times.get(&repo.full_name) {
@@ -281,30 +463,30 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
     }
 
     pub fn next_run(&mut self) {
-        if let Some(repo_index) = self.selected_repo {
-            if repo_index < self.repositories.len() {
-                let repo_name = &self.repositories[repo_index].full_name;
-                if let Some(runs) = self.workflow_runs.get(repo_name) {
-                    let run_count = runs.len();
-                    if run_count > 0 {
-                        let current_index = self.selected_run.unwrap_or(0);
-                        self.selected_run = Some((current_index + 1) % run_count);
-                    }
+        if let Some(repo_index) = self.selected_repo
+            && repo_index < self.repositories.len()
+        {
+            let repo_name = &self.repositories[repo_index].full_name;
+            if let Some(runs) = self.workflow_runs.get(repo_name) {
+                let run_count = runs.len();
+                if run_count > 0 {
+                    let current_index = self.selected_run.unwrap_or(0);
+                    self.selected_run = Some((current_index + 1) % run_count);
                 }
             }
         }
     }
 
     pub fn previous_run(&mut self) {
-        if let Some(repo_index) = self.selected_repo {
-            if repo_index < self.repositories.len() {
-                let repo_name = &self.repositories[repo_index].full_name;
-                if let Some(runs) = self.workflow_runs.get(repo_name) {
-                    let run_count = runs.len();
-                    if run_count > 0 {
-                        let current_index = self.selected_run.unwrap_or(0);
-                        self.selected_run = Some((current_index + run_count - 1) % run_count);
-                    }
+        if let Some(repo_index) = self.selected_repo
+            && repo_index < self.repositories.len()
+        {
+            let repo_name = &self.repositories[repo_index].full_name;
+            if let Some(runs) = self.workflow_runs.get(repo_name) {
+                let run_count = runs.len();
+                if run_count > 0 {
+                    let current_index = self.selected_run.unwrap_or(0);
+                    self.selected_run = Some((current_index + run_count - 1) % run_count);
                 }
             }
         }
@@ -316,26 +498,530 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
 
     pub fn close_popup(&mut self) {
         self.popup = None;
+        self.run_history.close();
+    }
+
+    pub fn get_selected_run(&self) -> Option<(&Repository, &WorkflowRun)> {
+        if let (Some(repo_index), Some(run_index)) = (self.selected_repo, self.selected_run)
+            && repo_index < self.repositories.len()
+        {
+            let repo = &self.repositories[repo_index];
+            if let Some(runs) = self.workflow_runs.get(&repo.full_name)
+                && run_index < runs.len()
+            {
+                return Some((repo, &runs[run_index]));
+            }
+        }
+        None
     }
 
     pub fn get_selected_run_url(&self) -> Option<String> {
-        if let (Some(repo_index), Some(run_index)) = (self.selected_repo, self.selected_run) {
-            if repo_index < self.repositories.len() {
-                let repo_name = &self.repositories[repo_index].full_name;
-                if let Some(runs) = self.workflow_runs.get(repo_name) {
-                    if run_index < runs.len() {
-                        return Some(runs[run_index].html_url.clone());
+        self.get_selected_run().map(|(_, run)| run.html_url.clone())
+    }
+
+    pub fn open_jobs_view(&mut self) {
+        if let Some((repo, run)) = self.get_selected_run() {
+            let (full_name, run) = (repo.full_name.clone(), run.clone());
+            self.job_list.open(full_name, run);
+            self.popup = Some(PopupType::Jobs);
+        } else {
+            self.close_popup();
+        }
+    }
+
+    /// Open the history of the selected repository, up to its `history_runs`.
+    pub fn open_history_view(&mut self) {
+        let Some(repo) = self.selected_repo.and_then(|index| self.repositories.get(index)) else {
+            self.close_popup();
+            return;
+        };
+        let full_name = repo.full_name.clone();
+        let limit = self
+            .repo_config(&full_name)
+            .and_then(|config| config.history_runs)
+            .unwrap_or(self.settings.monitoring.history_runs);
+        self.run_history.open(full_name, limit);
+        self.popup = Some(PopupType::History);
+    }
+
+    /// Drill down into the jobs of the run selected in the history view.
+    pub fn open_history_jobs(&mut self) {
+        if let (Some(full_name), Some(run)) = (&self.run_history.repo_full_name, self.run_history.get_selected_run()) {
+            self.job_list.open(full_name.clone(), run.clone());
+            self.popup = Some(PopupType::Jobs);
+        }
+    }
+
+    /// Open the pull request view of the selected repository.
+    pub fn open_pull_requests_view(&mut self) {
+        let Some(repo) = self.selected_repo.and_then(|index| self.repositories.get(index)) else {
+            return;
+        };
+        self.pull_request_list.open(repo.full_name.clone());
+        self.popup = Some(PopupType::PullRequests);
+    }
+
+    pub fn open_run_logs(&mut self) {
+        if let Some((repo, run)) = self.get_selected_run() {
+            let (full_name, title, run_id) = (repo.full_name.clone(), run.name.clone(), run.id);
+            self.log_viewer.open(full_name, title, LogSource::Run(run_id));
+            self.popup = Some(PopupType::Logs);
+        } else {
+            self.close_popup();
+        }
+    }
+
+    pub fn open_job_logs(&mut self) {
+        if let (Some(full_name), Some(job)) = (&self.job_list.repo_full_name, self.job_list.get_selected_job()) {
+            let (full_name, title, job_id) = (full_name.clone(), job.name.clone(), job.id);
+            self.log_viewer.open(full_name, title, LogSource::Job(job_id));
+            self.popup = Some(PopupType::Logs);
+        }
+    }
+
+    /// Start downloading the logs requested by the log viewer, if they have not been loaded yet.
+    fn load_logs(&mut self) {
+        if !self.log_viewer.loading {
+            return;
+        }
+        let (Some(source), Some(repo)) = (
+            self.log_viewer.source,
+            self.log_viewer.repo_full_name.as_deref().and_then(|name| self.find_repository(name)),
+        ) else {
+            self.log_viewer.set_error("Selected run is no longer available".to_string());
+            return;
+        };
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.log_viewer.set_error(e.to_string());
+                return;
+            }
+        };
+
+        let request = LoadRequest::Logs { repo: repo.full_name.clone(), source };
+        self.loader.spawn(request, async move {
+            let result = match source {
+                LogSource::Run(run_id) => client.fetch_run_logs(&repo.owner, &repo.name, run_id).await,
+                LogSource::Job(job_id) => client.fetch_job_logs(&repo.owner, &repo.name, job_id).await,
+            };
+            LoadEvent::Logs { source, result }
+        });
+    }
+
+    /// Start fetching the jobs of the run opened in the jobs view, if they have not been loaded yet.
+    fn load_jobs(&mut self) {
+        if !self.job_list.loading {
+            return;
+        }
+        let (Some(run_id), Some(repo)) = (
+            self.job_list.run.as_ref().map(|run| run.id),
+            self.job_list.repo_full_name.as_deref().and_then(|name| self.find_repository(name)),
+        ) else {
+            self.job_list.set_error("Selected run is no longer available".to_string());
+            return;
+        };
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.job_list.set_error(e.to_string());
+                return;
+            }
+        };
+
+        let request = LoadRequest::Jobs { repo: repo.full_name.clone(), run_id };
+        self.loader.spawn(request, async move {
+            let result = client.fetch_workflow_jobs(&repo.owner, &repo.name, run_id).await;
+            LoadEvent::Jobs { run_id, result }
+        });
+    }
+
+    /// Start fetching the page of runs the history view is waiting for.
+    fn load_history_page(&mut self) {
+        let (true, Some(page)) = (self.run_history.loading, self.run_history.next_page) else {
+            return;
+        };
+        let Some(repo) = self.run_history.repo_full_name.as_deref().and_then(|name| self.find_repository(name)) else {
+            self.run_history.set_error("Repository is no longer available".to_string());
+            return;
+        };
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.run_history.set_error(e.to_string());
+                return;
+            }
+        };
+
+        let request = LoadRequest::HistoryPage { repo: repo.full_name.clone(), page };
+        self.loader.spawn(request, async move {
+            let result = client.fetch_run_history(&repo.owner, &repo.name, page).await;
+            LoadEvent::HistoryPage { repo: repo.full_name, page, result }
+        });
+    }
+
+    /// Start fetching the open pull requests and their checks once the pull request view is opened.
+    fn load_pull_requests(&mut self) {
+        if !self.pull_request_list.loading {
+            return;
+        }
+        let Some(repo) = self.pull_request_list.repo_full_name.as_deref().and_then(|name| self.find_repository(name)) else {
+            self.pull_request_list.set_error("Repository is no longer available".to_string());
+            return;
+        };
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.pull_request_list.set_error(e.to_string());
+                return;
+            }
+        };
+
+        let request = LoadRequest::PullRequests { repo: repo.full_name.clone() };
+        self.loader.spawn(request, async move {
+            let result = client.fetch_pull_requests(&repo.owner, &repo.name).await;
+            LoadEvent::PullRequests { repo: repo.full_name, result }
+        });
+    }
+
+    pub fn open_run_action(&mut self, action: RunAction) {
+        if let Some((repo, run)) = self.get_selected_run() {
+            let (repo_full_name, run) = (repo.full_name.clone(), run.clone());
+            self.confirm_dialog.open(action, repo_full_name, run);
+            self.popup = Some(PopupType::Confirm);
+        } else {
+            self.close_popup();
+        }
+    }
+
+    fn replace_run(&mut self, repo_full_name: &str, run: WorkflowRun) {
+        if let Some(existing) = self
+            .workflow_runs
+            .get_mut(repo_full_name)
+            .and_then(|runs| runs.iter_mut().find(|existing| existing.id == run.id))
+        {
+            *existing = run;
+        }
+    }
+
+    /// Accept the confirmation prompt and show the expected outcome in the list right away.
+    pub fn confirm_run_action(&mut self) {
+        if !self.confirm_dialog.confirm() {
+            return;
+        }
+        if let (Some(action), Some(run)) = (self.confirm_dialog.action, &self.confirm_dialog.run) {
+            let mut updated = run.clone();
+            action.apply_optimistic(&mut updated);
+            let repo_full_name = self.confirm_dialog.repo_full_name.clone();
+            self.replace_run(&repo_full_name, updated);
+        }
+    }
+
+    /// Start sending the confirmed action to GitHub; the repository it was applied to is
+    /// refreshed once GitHub accepts it.
+    fn run_confirmed_action(&mut self) {
+        if !self.confirm_dialog.confirmed {
+            return;
+        }
+        let (Some(action), Some(run_id)) = (self.confirm_dialog.action, self.confirm_dialog.run.as_ref().map(|run| run.id)) else {
+            return;
+        };
+        let repo_full_name = self.confirm_dialog.repo_full_name.clone();
+        let Some(repo) = self.find_repository(&repo_full_name) else {
+            self.finish_run_action(Err(AppError::ConfigError(format!("Unknown repository {}", repo_full_name))));
+            return;
+        };
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.finish_run_action(Err(e));
+                return;
+            }
+        };
+
+        let request = LoadRequest::RunAction { repo: repo_full_name.clone(), run_id, action };
+        self.loader.spawn(request, async move {
+            let result = client.run_action(&repo.owner, &repo.name, run_id, action).await;
+            LoadEvent::RunAction { repo: repo_full_name, run_id, action, result }
+        });
+    }
+
+    /// Close the prompt on success, or roll back the optimistic update and show the error.
+    fn finish_run_action(&mut self, result: Result<(), AppError>) -> bool {
+        let (Some(action), Some(run)) = (self.confirm_dialog.action, self.confirm_dialog.run.clone()) else {
+            return false;
+        };
+        let repo_full_name = self.confirm_dialog.repo_full_name.clone();
+
+        match result {
+            Ok(()) => {
+                log_info(format!("{} requested for {} run {}", action.label(), repo_full_name, run.id));
+                self.close_popup();
+                true
+            }
+            Err(e) => {
+                log_error(format!("{} failed for {} run {}: {}", action.label(), repo_full_name, run.id, e));
+                self.replace_run(&repo_full_name, run);
+                self.confirm_dialog.set_error(e.to_string());
+                false
+            }
+        }
+    }
+
+    /// Open the workflow_dispatch form for the selected repository.
+    pub fn open_dispatch_form(&mut self) {
+        let Some(repo) = self.selected_repo.and_then(|index| self.repositories.get(index)) else {
+            return;
+        };
+        let default_branch = repo.default_branch.clone().unwrap_or_else(|| "main".to_string());
+        self.dispatch_form.open(repo.full_name.clone(), default_branch);
+        self.popup = Some(PopupType::Dispatch);
+    }
+
+    fn find_repository(&self, full_name: &str) -> Option<Repository> {
+        self.repositories.iter().find(|repo| repo.full_name == full_name).cloned()
+    }
+
+    /// Start fetching what the dispatch form is waiting for: the workflow list or the
+    /// selected workflow's inputs.
+    fn load_dispatch_form(&mut self) {
+        if !self.dispatch_form.loading {
+            return;
+        }
+        let Some(repo) = self.find_repository(&self.dispatch_form.repo_full_name) else {
+            self.dispatch_form.set_error("Repository is no longer available".to_string());
+            return;
+        };
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.dispatch_form.set_error(e.to_string());
+                return;
+            }
+        };
+
+        match self.dispatch_form.stage {
+            DispatchStage::SelectWorkflow => {
+                let request = LoadRequest::Workflows { repo: repo.full_name.clone() };
+                self.loader.spawn(request, async move {
+                    let result = client.fetch_workflows(&repo.owner, &repo.name).await;
+                    LoadEvent::Workflows { repo: repo.full_name, result }
+                });
+            }
+            DispatchStage::EditInputs => {
+                let Some(path) = self.dispatch_form.get_selected_workflow().map(|workflow| workflow.path.clone()) else {
+                    return;
+                };
+                let git_ref = self.dispatch_form.default_branch.clone();
+                let request = LoadRequest::DispatchInputs { repo: repo.full_name.clone(), path: path.clone(), git_ref: git_ref.clone() };
+                self.loader.spawn(request, async move {
+                    let result = client.fetch_dispatch_inputs(&repo.owner, &repo.name, &path, &git_ref).await;
+                    LoadEvent::DispatchInputs { repo: repo.full_name, path, result }
+                });
+            }
+        }
+    }
+
+    /// Start sending the submitted dispatch form; the repository is refreshed to pick up the
+    /// new run once GitHub accepts it.
+    fn submit_dispatch_form(&mut self) {
+        if !self.dispatch_form.submitting {
+            return;
+        }
+        let (Some(repo), Some(workflow)) = (
+            self.find_repository(&self.dispatch_form.repo_full_name),
+            self.dispatch_form.get_selected_workflow().cloned(),
+        ) else {
+            self.dispatch_form.set_error("Workflow is no longer available".to_string());
+            return;
+        };
+        let git_ref = self.dispatch_form.git_ref().to_string();
+        let inputs = self.dispatch_form.input_values();
+        let client = match self.github_clients.for_repo(&repo) {
+            Ok(client) => client.clone(),
+            Err(e) => {
+                self.dispatch_form.set_error(e.to_string());
+                return;
+            }
+        };
+
+        let request = LoadRequest::Dispatch { repo: repo.full_name.clone(), workflow_id: workflow.id, git_ref: git_ref.clone() };
+        self.loader.spawn(request, async move {
+            let result = client.dispatch_workflow(&repo.owner, &repo.name, workflow.id, &git_ref, &inputs).await;
+            LoadEvent::Dispatched { repo: repo.full_name, workflow: workflow.name, git_ref, result }
+        });
+    }
+
+    /// Start the requests the open popup is waiting for. Each is sent once; its result is
+    /// applied by `apply_load_results` when it arrives.
+    pub fn start_loading(&mut self) {
+        match self.popup {
+            Some(PopupType::Jobs) => self.load_jobs(),
+            // Fetch the next page once the history is opened or scrolled past its end
+            Some(PopupType::History) => self.load_history_page(),
+            Some(PopupType::PullRequests) => self.load_pull_requests(),
+            Some(PopupType::Logs) => self.load_logs(),
+            Some(PopupType::Dispatch) => {
+                self.load_dispatch_form();
+                self.submit_dispatch_form();
+            }
+            Some(PopupType::Confirm) => self.run_confirmed_action(),
+            Some(PopupType::ContextMenu) | None => {}
+        }
+    }
+
+    /// Apply every popup request result that has arrived since the last frame, without waiting.
+    pub fn apply_load_results(&mut self) -> usize {
+        let mut applied = 0;
+        while let Some(event) = self.loader.try_next() {
+            self.apply_load_event(event);
+            applied += 1;
+        }
+        applied
+    }
+
+    /// Hand a result to the popup waiting for it. Results nothing waits for anymore, because
+    /// the popup was closed or moved on, are dropped; actions are still logged and refreshed.
+    pub fn apply_load_event(&mut self, event: LoadEvent) {
+        match event {
+            LoadEvent::Jobs { run_id, result } => {
+                if !self.job_list.loading || self.job_list.run.as_ref().map(|run| run.id) != Some(run_id) {
+                    return;
+                }
+                match result {
+                    Ok(jobs) => self.job_list.set_jobs(jobs),
+                    Err(e) => {
+                        log_error(format!("Failed to fetch jobs for run {}: {}", run_id, e));
+                        self.job_list.set_error(e.to_string());
+                    }
+                }
+            }
+            LoadEvent::Logs { source, result } => {
+                if !self.log_viewer.loading || self.log_viewer.source != Some(source) {
+                    return;
+                }
+                match result {
+                    Ok(lines) => self.log_viewer.set_lines(lines),
+                    Err(e) => {
+                        log_error(format!("Failed to fetch logs for {:?}: {}", source, e));
+                        self.log_viewer.set_error(e.to_string());
+                    }
+                }
+            }
+            LoadEvent::HistoryPage { repo, page, result } => {
+                if !self.run_history.loading
+                    || self.run_history.next_page != Some(page)
+                    || self.run_history.repo_full_name.as_deref() != Some(repo.as_str())
+                {
+                    return;
+                }
+                match result {
+                    Ok(history) => self.run_history.append_page(history),
+                    Err(e) => {
+                        log_error(format!("Failed to fetch run history of {}: {}", repo, e));
+                        self.run_history.set_error(e.to_string());
                     }
                 }
             }
+            LoadEvent::PullRequests { repo, result } => {
+                if !self.pull_request_list.loading || self.pull_request_list.repo_full_name.as_deref() != Some(repo.as_str()) {
+                    return;
+                }
+                match result {
+                    Ok(pull_requests) => self.pull_request_list.set_pull_requests(pull_requests),
+                    Err(e) => {
+                        log_error(format!("Failed to fetch pull requests of {}: {}", repo, e));
+                        self.pull_request_list.set_error(e.to_string());
+                    }
+                }
+            }
+            LoadEvent::Workflows { repo, result } => {
+                if !self.dispatch_form.loading
+                    || self.dispatch_form.stage != DispatchStage::SelectWorkflow
+                    || self.dispatch_form.repo_full_name != repo
+                {
+                    return;
+                }
+                match result {
+                    Ok(workflows) => self.dispatch_form.set_workflows(workflows),
+                    Err(e) => {
+                        log_error(format!("Failed to fetch workflows for {}: {}", repo, e));
+                        self.dispatch_form.set_error(e.to_string());
+                    }
+                }
+            }
+            LoadEvent::DispatchInputs { repo, path, result } => {
+                if !self.dispatch_form.loading
+                    || self.dispatch_form.stage != DispatchStage::EditInputs
+                    || self.dispatch_form.repo_full_name != repo
+                    || self.dispatch_form.get_selected_workflow().map(|workflow| workflow.path.as_str()) != Some(path.as_str())
+                {
+                    return;
+                }
+                match result {
+                    Ok(inputs) => self.dispatch_form.set_inputs(inputs),
+                    Err(e) => {
+                        log_error(format!("Failed to read inputs of {}: {}", path, e));
+                        self.dispatch_form.set_error(e.to_string());
+                    }
+                }
+            }
+            LoadEvent::Dispatched { repo, workflow, git_ref, result } => {
+                let waiting = self.popup == Some(PopupType::Dispatch)
+                    && self.dispatch_form.submitting
+                    && self.dispatch_form.repo_full_name == repo;
+                match result {
+                    Ok(()) => {
+                        log_info(format!("Dispatched {} on {} in {}", workflow, git_ref, repo));
+                        if waiting {
+                            self.close_popup();
+                        }
+                        self.request_repo_refresh(&repo);
+                    }
+                    Err(e) => {
+                        log_error(format!("Failed to dispatch {}: {}", workflow, e));
+                        if waiting {
+                            self.dispatch_form.set_error(e.to_string());
+                        }
+                    }
+                }
+            }
+            LoadEvent::RunAction { repo, run_id, action, result } => {
+                let waiting = self.popup == Some(PopupType::Confirm)
+                    && self.confirm_dialog.confirmed
+                    && self.confirm_dialog.action == Some(action)
+                    && self.confirm_dialog.run.as_ref().map(|run| run.id) == Some(run_id);
+                if waiting {
+                    if self.finish_run_action(result) {
+                        self.request_repo_refresh(&repo);
+                    }
+                    return;
+                }
+                // The prompt was dismissed meanwhile; the refresh replaces the optimistic update
+                match result {
+                    Ok(()) => log_info(format!("{} requested for {} run {}", action.label(), repo, run_id)),
+                    Err(e) => log_error(format!("{} failed for {} run {}: {}", action.label(), repo, run_id, e)),
+                }
+                self.request_repo_refresh(&repo);
+            }
+        }
+    }
+
+    /// Whether printable keys should be treated as text typed into a popup.
+    pub fn is_text_input_active(&self) -> bool {
+        self.popup == Some(PopupType::Dispatch) && self.dispatch_form.is_editing()
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        if self.is_text_input_active() {
+            self.dispatch_form.input_char(c);
         }
-        None
     }
 
     pub fn open_in_browser(&self) -> Result<(), AppError> {
         if let Some(url) = self.get_selected_run_url() {
             webbrowser::open(&url)
-                .map_err(|e| AppError::IoError(std::io::Error::new(std::io::ErrorKind::Other, e)))?;
+                .map_err(|e| AppError::IoError(std::io::Error::other(e)))?;
         }
         Ok(())
     }
@@ -352,6 +1038,16 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
                     self.next_repo();
                 } else if self.popup == Some(PopupType::ContextMenu) {
                     self.context_menu.next();
+                } else if self.popup == Some(PopupType::Jobs) {
+                    self.job_list.next();
+                } else if self.popup == Some(PopupType::History) {
+                    self.run_history.next();
+                } else if self.popup == Some(PopupType::PullRequests) {
+                    self.pull_request_list.next();
+                } else if self.popup == Some(PopupType::Logs) {
+                    self.log_viewer.scroll_down(1);
+                } else if self.popup == Some(PopupType::Dispatch) {
+                    self.dispatch_form.next();
                 }
             }
             "k" | "up" => {
@@ -359,23 +1055,89 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
                     self.previous_repo();
                 } else if self.popup == Some(PopupType::ContextMenu) {
                     self.context_menu.previous();
+                } else if self.popup == Some(PopupType::Jobs) {
+                    self.job_list.previous();
+                } else if self.popup == Some(PopupType::History) {
+                    self.run_history.previous();
+                } else if self.popup == Some(PopupType::PullRequests) {
+                    self.pull_request_list.previous();
+                } else if self.popup == Some(PopupType::Logs) {
+                    self.log_viewer.scroll_up(1);
+                } else if self.popup == Some(PopupType::Dispatch) {
+                    self.dispatch_form.previous();
                 }
             }
-            "l" | "right" => {
-                if self.popup.is_none() {
-                    self.next_run();
-                }
+            "tab" if self.popup == Some(PopupType::Dispatch) => {
+                self.dispatch_form.next();
             }
-            "h" | "left" => {
-                if self.popup.is_none() {
-                    self.previous_run();
-                }
+            "backtab" if self.popup == Some(PopupType::Dispatch) => {
+                self.dispatch_form.previous();
+            }
+            "right" if self.popup == Some(PopupType::Dispatch) => {
+                self.dispatch_form.cycle(true);
+            }
+            "left" if self.popup == Some(PopupType::Dispatch) => {
+                self.dispatch_form.cycle(false);
+            }
+            "backspace" if self.popup == Some(PopupType::Dispatch) => {
+                self.dispatch_form.backspace();
+            }
+            "w" if self.popup.is_none() => {
+                self.open_dispatch_form();
+            }
+            "H" if self.popup.is_none() => {
+                self.open_history_view();
+            }
+            "p" if self.popup.is_none() => {
+                self.open_pull_requests_view();
+            }
+            "m" if self.popup.is_none() => {
+                self.open_context_menu();
+            }
+            "pagedown" if self.popup == Some(PopupType::Logs) => {
+                self.log_viewer.page_down();
+            }
+            "pageup" if self.popup == Some(PopupType::Logs) => {
+                self.log_viewer.page_up();
+            }
+            "g" | "home" if self.popup == Some(PopupType::Logs) => {
+                self.log_viewer.jump_to_start();
+            }
+            "G" | "end" if self.popup == Some(PopupType::Logs) => {
+                self.log_viewer.jump_to_end();
+            }
+            "e" if self.popup == Some(PopupType::Logs) => {
+                self.log_viewer.jump_to_first_error();
+            }
+            "l" | "right" if self.popup.is_none() => {
+                self.next_run();
+            }
+            "h" | "left" if self.popup.is_none() => {
+                self.previous_run();
             }
             "enter" => {
                 if self.popup.is_none() {
-                    self.open_context_menu();
+                    self.open_jobs_view();
                 } else if self.popup == Some(PopupType::ContextMenu) {
                     match self.context_menu.get_selected_action() {
+                        "View Jobs" => {
+                            self.open_jobs_view();
+                        }
+                        "View Logs" => {
+                            self.open_run_logs();
+                        }
+                        "View History" => {
+                            self.open_history_view();
+                        }
+                        "Re-run all jobs" => {
+                            self.open_run_action(RunAction::Rerun);
+                        }
+                        "Re-run failed jobs" => {
+                            self.open_run_action(RunAction::RerunFailed);
+                        }
+                        "Cancel run" => {
+                            self.open_run_action(RunAction::Cancel);
+                        }
                         "Open in Browser" => {
                             let _ = self.open_in_browser();
                             self.close_popup();
@@ -385,11 +1147,48 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
                         }
                         _ => {}
                     }
+                } else if self.popup == Some(PopupType::Jobs) {
+                    self.open_job_logs();
+                } else if self.popup == Some(PopupType::History) {
+                    self.open_history_jobs();
+                } else if self.popup == Some(PopupType::PullRequests) {
+                    if let Some(pull_request) = self.pull_request_list.get_selected_pull_request() {
+                        let _ = webbrowser::open(&pull_request.html_url);
+                    }
+                } else if self.popup == Some(PopupType::Confirm) {
+                    self.confirm_run_action();
+                } else if self.popup == Some(PopupType::Dispatch) {
+                    match self.dispatch_form.stage {
+                        DispatchStage::SelectWorkflow => {
+                            self.dispatch_form.select_workflow();
+                        }
+                        DispatchStage::EditInputs => {
+                            self.dispatch_form.submit();
+                        }
+                    }
                 }
             }
-            "esc" => {
+            "y" if self.popup == Some(PopupType::Confirm) => {
+                self.confirm_run_action();
+            }
+            "n" if self.popup == Some(PopupType::Confirm) && !self.confirm_dialog.confirmed => {
                 self.close_popup();
             }
+            "esc" => {
+                // Job logs are opened from the jobs view, so return there
+                if self.popup == Some(PopupType::Logs)
+                    && matches!(self.log_viewer.source, Some(LogSource::Job(_)))
+                {
+                    self.popup = Some(PopupType::Jobs);
+                } else if self.popup == Some(PopupType::Jobs) && self.run_history.is_open() {
+                    // Jobs opened from the history view return there
+                    self.popup = Some(PopupType::History);
+                } else if self.popup == Some(PopupType::Dispatch) && self.dispatch_form.back() {
+                    // Returned from the input form to the workflow list
+                } else {
+                    self.close_popup();
+                }
+            }
             _ => {}
         }
     }
@@ -398,8 +1197,9 @@ pub async fn refresh(&mut self, force_all: bool) -> Result<(), AppError> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::config::settings::{Settings, RepositoryConfig, MonitoringConfig};
-    use crate::github::models::{Repository, WorkflowRun, WorkflowStatus, WorkflowConclusion};
+    use crate::config::settings::{Settings, RepositoryConfig, MonitoringConfig, HostConfig};
+    use crate::github::models::{Repository, RunHistoryPage, WorkflowRun, WorkflowStatus, WorkflowConclusion};
+    use crate::github::rate_limit::RateLimit;
     use chrono::Utc;
     use std::collections::HashMap;
     use std::time::Duration;
@@ -407,11 +1207,19 @@ mod tests {
 
     fn create_test_settings(repos: Vec<RepositoryConfig>) -> Settings {
         Settings {
-            github_token: "test_token".to_string(),
+            github_token: Some(secrecy::SecretString::new("test_token".to_string())),
+            token_file: None,
+            token_command: None,
             repositories: repos,
+            discovery: Default::default(),
+            hosts: vec![],
+            github_app: None,
+            credentials: Default::default(),
             monitoring: MonitoringConfig::default(),
             ui: crate::config::settings::UiConfig::default(),
             logging: crate::config::settings::LoggingConfig::default(),
+            notifications: Default::default(),
+            hooks: Default::default(),
         }
     }
 
@@ -424,6 +1232,8 @@ fn create_test_app_state() -> AppState {
                 full_name: "owner1/repo1".to_string(),
                 html_url: "https://github.com/owner1/repo1".to_string(),
                 default_branch: Some("main".to_string()),
+                host: None,
+                credential: None,
             },
             Repository {
                 id: 2,
@@ -432,23 +1242,35 @@ fn create_test_app_state() -> AppState {
                 full_name: "owner2/repo2".to_string(),
                 html_url: "https://github.com/owner2/repo2".to_string(),
                 default_branch: Some("main".to_string()),
+                host: None,
+                credential: None,
             },
         ];
 
         let settings = create_test_settings(vec![
             RepositoryConfig {
+                host: None,
+                credential: None,
                 owner: "owner1".to_string(),
                 name: "repo1".to_string(),
                 branch: None,
                 workflows: None,
                 enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
             },
             RepositoryConfig {
+                host: None,
+                credential: None,
                 owner: "owner2".to_string(),
                 name: "repo2".to_string(),
                 branch: None,
                 workflows: None,
                 enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
             },
         ]);
 
@@ -462,6 +1284,7 @@ fn create_test_app_state() -> AppState {
                 name: "CI".to_string(),
                 status: WorkflowStatus::Completed,
                 conclusion: Some(WorkflowConclusion::Success),
+                run_attempt: 1,
                 created_at: now,
                 updated_at: now,
                 branch: "main".to_string(),
@@ -469,6 +1292,7 @@ fn create_test_app_state() -> AppState {
                 actor: "user1".to_string(),
                 html_url: "https://github.com/owner1/repo1/run/1".to_string(),
                 logs_url: Some("https://github.com/owner1/repo1/run/1/logs".to_string()),
+                pull_requests: Vec::new(),
             }
         ]);
 
@@ -479,6 +1303,7 @@ fn create_test_app_state() -> AppState {
                 name: "CI".to_string(),
                 status: WorkflowStatus::Completed,
                 conclusion: Some(WorkflowConclusion::Success),
+                run_attempt: 1,
                 created_at: now - chrono::Duration::hours(25), // 25 hours ago
                 updated_at: now - chrono::Duration::hours(25),
                 branch: "main".to_string(),
@@ -486,6 +1311,7 @@ fn create_test_app_state() -> AppState {
                 actor: "user2".to_string(),
                 html_url: "https://github.com/owner2/repo2/run/2".to_string(),
                 logs_url: Some("https://github.com/owner2/repo2/run/2/logs".to_string()),
+                pull_requests: Vec::new(),
             }
         ]);
 
@@ -496,10 +1322,25 @@ fn create_test_app_state() -> AppState {
             selected_run: None,
             popup: None,
             context_menu: crate::ui::components::context_menu::ContextMenuComponent::new(),
+            job_list: crate::ui::components::job_list::JobListComponent::new(),
+            run_history: crate::ui::components::run_history::RunHistoryComponent::new(),
+            pull_request_list: crate::ui::components::pull_request_list::PullRequestListComponent::new(),
+            log_viewer: crate::ui::components::log_viewer::LogViewerComponent::new(),
+            confirm_dialog: crate::ui::components::confirm_dialog::ConfirmDialogComponent::new(),
+            dispatch_form: crate::ui::components::dispatch_form::DispatchFormComponent::new(),
+            theme: crate::ui::theme::Theme::default(),
             settings: settings.clone(),
-            github_client: crate::github::client::GithubClient::new(settings.clone()).unwrap(),
+            github_clients: ClientPool::new(&settings).unwrap(),
             last_repo_refresh_times: HashMap::new(),
             refreshing_repos: Arc::new(RwLock::new(HashSet::new())),
+            refresher: crate::ui::refresh::Refresher::new(5),
+            loader: Default::default(),
+            discovery: crate::ui::refresh::DiscoveryTask::default(),
+            local_checkout: crate::utils::local_git::LocalCheckout::default(),
+            notifications: crate::notify::Notifications::default(),
+            hooks: crate::notify::hooks::ShellHooks::default(),
+            transitions: Default::default(),
+            fetched_runs: HashMap::new(),
         }
     }
 
@@ -517,6 +1358,33 @@ fn create_test_app_state() -> AppState {
         assert_eq!(interval, Duration::from_secs(7200)); // No override, uses activity-based calculation
     }
 
+    #[tokio::test]
+    async fn test_calculate_refresh_interval_respects_repo_bounds() {
+        let mut app_state = create_test_app_state();
+        // owner1/repo1 is very active, owner2/repo2 inactive
+        app_state.settings.repositories[0].min_refresh_seconds = Some(30);
+        app_state.settings.repositories[1].max_refresh_seconds = Some(300);
+
+        assert_eq!(app_state.calculate_refresh_interval("owner1/repo1"), Duration::from_secs(30));
+        assert_eq!(app_state.calculate_refresh_interval("owner2/repo2"), Duration::from_secs(300));
+
+        // Due once the capped interval has passed, although the idle tier would wait 2 hours
+        app_state.last_repo_refresh_times.insert("owner1/repo1".to_string(), Utc::now());
+        app_state.last_repo_refresh_times.insert("owner2/repo2".to_string(), Utc::now() - chrono::Duration::seconds(301));
+        assert_eq!(app_state.seconds_until_refresh(), 0);
+        assert_eq!(app_state.start_refresh(false), 1);
+    }
+
+    #[tokio::test]
+    async fn test_calculate_refresh_interval_uses_configured_tiers() {
+        let mut app_state = create_test_app_state();
+        app_state.settings.monitoring.refresh_tiers.active_seconds = 20;
+        app_state.settings.monitoring.refresh_tiers.idle_seconds = 600;
+
+        assert_eq!(app_state.calculate_refresh_interval("owner1/repo1"), Duration::from_secs(20));
+        assert_eq!(app_state.calculate_refresh_interval("owner2/repo2"), Duration::from_secs(600));
+    }
+
     #[tokio::test]
     async fn test_calculate_refresh_interval_moderately_active() {
         let mut app_state = create_test_app_state();
@@ -675,10 +1543,25 @@ fn create_test_app_state() -> AppState {
             selected_run: None,
             popup: None,
             context_menu: crate::ui::components::context_menu::ContextMenuComponent::new(),
+            job_list: crate::ui::components::job_list::JobListComponent::new(),
+            run_history: crate::ui::components::run_history::RunHistoryComponent::new(),
+            pull_request_list: crate::ui::components::pull_request_list::PullRequestListComponent::new(),
+            log_viewer: crate::ui::components::log_viewer::LogViewerComponent::new(),
+            confirm_dialog: crate::ui::components::confirm_dialog::ConfirmDialogComponent::new(),
+            dispatch_form: crate::ui::components::dispatch_form::DispatchFormComponent::new(),
+            theme: crate::ui::theme::Theme::default(),
             settings: create_test_settings(vec![]),
-            github_client: crate::github::client::GithubClient::new(create_test_settings(vec![])).unwrap(),
+            github_clients: ClientPool::new(&create_test_settings(vec![])).unwrap(),
             last_repo_refresh_times: HashMap::new(),
             refreshing_repos: Arc::new(RwLock::new(HashSet::new())),
+            refresher: crate::ui::refresh::Refresher::new(5),
+            loader: Default::default(),
+            discovery: crate::ui::refresh::DiscoveryTask::default(),
+            local_checkout: crate::utils::local_git::LocalCheckout::default(),
+            notifications: crate::notify::Notifications::default(),
+            hooks: crate::notify::hooks::ShellHooks::default(),
+            transitions: Default::default(),
+            fetched_runs: HashMap::new(),
         };
         
         // Should not panic with no repositories
@@ -757,7 +1640,7 @@ fn create_test_app_state() -> AppState {
     async fn test_handle_key_popup() {
         let mut app_state = create_test_app_state();
         
-        app_state.handle_key("enter");
+        app_state.handle_key("m");
         assert_eq!(app_state.popup, Some(PopupType::ContextMenu));
         
         app_state.handle_key("esc");
@@ -777,6 +1660,359 @@ fn create_test_app_state() -> AppState {
         assert_ne!(initial_action_value, new_action_value);
     }
 
+    #[tokio::test]
+    async fn test_handle_key_view_jobs() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+
+        app_state.handle_key("enter");
+
+        assert_eq!(app_state.popup, Some(PopupType::Jobs));
+        assert!(app_state.job_list.loading);
+        assert_eq!(app_state.job_list.run.as_ref().unwrap().id, 1);
+
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, None);
+
+        // The context menu still offers the jobs view
+        app_state.handle_key("m");
+        assert_eq!(app_state.context_menu.get_selected_action(), "View Jobs");
+        app_state.handle_key("enter");
+        assert_eq!(app_state.popup, Some(PopupType::Jobs));
+    }
+
+    #[tokio::test]
+    async fn test_jobs_load_in_the_background() {
+        use crate::github::client::tests::MockGitHubApiClient;
+        use crate::github::client::GithubClient;
+
+        let mut app_state = create_test_app_state();
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client
+            .expect_get_workflow_jobs()
+            .withf(|route| route.starts_with("/repos/owner1/repo1/"))
+            .times(1)
+            .returning(|_| Ok(serde_json::from_str(r#"{"jobs": []}"#).unwrap()));
+        let client = GithubClient::new_with_client(app_state.settings.clone(), Box::new(mock_client));
+        app_state.github_clients = ClientPool::from_clients(vec![client]);
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+
+        app_state.handle_key("enter");
+        // The jobs come from the run's repository even when the selection moves on, e.g. after
+        // a rediscovery reorders the repositories
+        app_state.selected_repo = Some(1);
+        // Every frame asks again while the jobs are on their way; they are only requested once
+        app_state.start_loading();
+        app_state.start_loading();
+        assert!(app_state.job_list.loading);
+
+        // Results for a run the view no longer shows are dropped
+        app_state.apply_load_event(LoadEvent::Jobs { run_id: 99, result: Ok(vec![]) });
+        assert!(app_state.job_list.loading);
+
+        let event = app_state.loader.next().await.unwrap();
+        app_state.apply_load_event(event);
+        assert!(!app_state.job_list.loading);
+        assert!(app_state.job_list.error.is_none());
+        assert_eq!(app_state.popup, Some(PopupType::Jobs));
+    }
+
+    #[tokio::test]
+    async fn test_open_jobs_view_without_selection() {
+        let mut app_state = create_test_app_state();
+        app_state.open_context_menu();
+
+        app_state.open_jobs_view();
+
+        assert_eq!(app_state.popup, None);
+        assert!(!app_state.job_list.loading);
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_jobs_navigation() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_jobs_view();
+
+        let job = |id: u64| crate::github::models::Job {
+            id,
+            run_id: 1,
+            name: format!("job{}", id),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Success),
+            started_at: None,
+            completed_at: None,
+            html_url: None,
+            steps: vec![],
+        };
+        app_state.job_list.set_jobs(vec![job(1), job(2)]);
+
+        app_state.handle_key("j");
+        assert_eq!(app_state.job_list.selected_index, 1);
+        assert_eq!(app_state.selected_repo, Some(0)); // Main list is untouched
+
+        app_state.handle_key("k");
+        assert_eq!(app_state.job_list.selected_index, 0);
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_view_logs() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+
+        app_state.handle_key("m"); // Open context menu
+        app_state.handle_key("j");
+        assert_eq!(app_state.context_menu.get_selected_action(), "View Logs");
+        app_state.handle_key("enter");
+
+        assert_eq!(app_state.popup, Some(PopupType::Logs));
+        assert!(app_state.log_viewer.loading);
+        assert_eq!(app_state.log_viewer.source, Some(LogSource::Run(1)));
+
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, None);
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_job_logs_return_to_jobs() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_jobs_view();
+        app_state.job_list.set_jobs(vec![crate::github::models::Job {
+            id: 77,
+            run_id: 1,
+            name: "test".to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Failure),
+            started_at: None,
+            completed_at: None,
+            html_url: None,
+            steps: vec![],
+        }]);
+
+        app_state.handle_key("enter");
+        assert_eq!(app_state.popup, Some(PopupType::Logs));
+        assert_eq!(app_state.log_viewer.source, Some(LogSource::Job(77)));
+
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, Some(PopupType::Jobs));
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_log_navigation() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_run_logs();
+        let mut lines: Vec<String> = (0..100).map(|i| format!("line {}", i)).collect();
+        lines[60] = "##[error]boom".to_string();
+        app_state.log_viewer.set_lines(lines);
+
+        app_state.handle_key("j");
+        assert_eq!(app_state.log_viewer.scroll, 1);
+        app_state.handle_key("pagedown");
+        assert!(app_state.log_viewer.scroll > 1);
+        app_state.handle_key("G");
+        assert_eq!(app_state.log_viewer.scroll, 80);
+        app_state.handle_key("g");
+        assert_eq!(app_state.log_viewer.scroll, 0);
+        app_state.handle_key("e");
+        assert_eq!(app_state.log_viewer.scroll, 60);
+        assert_eq!(app_state.selected_repo, Some(0)); // Main list is untouched
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_history_view() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(1);
+        app_state.settings.repositories[1].history_runs = Some(50);
+
+        app_state.handle_key("H");
+        assert_eq!(app_state.popup, Some(PopupType::History));
+        assert_eq!(app_state.run_history.repo_full_name.as_deref(), Some("owner2/repo2"));
+        assert_eq!(app_state.run_history.limit, 50);
+        assert!(app_state.run_history.loading);
+
+        // Jobs of a run from the history return to it
+        let run = app_state.workflow_runs["owner1/repo1"][0].clone();
+        app_state.run_history.append_page(RunHistoryPage { runs: vec![run], has_more: false });
+        app_state.handle_key("enter");
+        assert_eq!(app_state.popup, Some(PopupType::Jobs));
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, Some(PopupType::History));
+
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, None);
+        assert!(!app_state.run_history.is_open());
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_pull_requests_view() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+
+        app_state.handle_key("p");
+        assert_eq!(app_state.popup, Some(PopupType::PullRequests));
+        assert_eq!(app_state.pull_request_list.repo_full_name.as_deref(), Some("owner1/repo1"));
+        assert!(app_state.pull_request_list.loading);
+
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, None);
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_rerun_confirmation() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+
+        app_state.handle_key("m"); // Open context menu
+        app_state.handle_key("j");
+        app_state.handle_key("j");
+        app_state.handle_key("j");
+        assert_eq!(app_state.context_menu.get_selected_action(), "Re-run all jobs");
+        app_state.handle_key("enter");
+
+        assert_eq!(app_state.popup, Some(PopupType::Confirm));
+        assert_eq!(app_state.confirm_dialog.action, Some(RunAction::Rerun));
+        assert_eq!(app_state.workflow_runs["owner1/repo1"][0].status, WorkflowStatus::Completed);
+
+        app_state.handle_key("y");
+
+        // The list shows the re-queued run before the request has been sent
+        assert!(app_state.confirm_dialog.confirmed);
+        let run = &app_state.workflow_runs["owner1/repo1"][0];
+        assert_eq!(run.status, WorkflowStatus::Queued);
+        assert_eq!(run.conclusion, None);
+    }
+
+    #[tokio::test]
+    async fn test_handle_key_decline_confirmation() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_run_action(RunAction::Rerun);
+
+        app_state.handle_key("n");
+
+        assert_eq!(app_state.popup, None);
+        assert!(!app_state.confirm_dialog.confirmed);
+        assert_eq!(app_state.workflow_runs["owner1/repo1"][0].status, WorkflowStatus::Completed);
+    }
+
+    #[tokio::test]
+    async fn test_unavailable_run_action_is_not_confirmed() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_run_action(RunAction::Cancel); // Run has already completed
+
+        app_state.handle_key("y");
+
+        assert_eq!(app_state.popup, Some(PopupType::Confirm));
+        assert!(!app_state.confirm_dialog.confirmed);
+        assert!(app_state.confirm_dialog.error.is_some());
+        assert_eq!(
+            app_state.workflow_runs["owner1/repo1"][0].conclusion,
+            Some(WorkflowConclusion::Success)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_failed_run_action_rolls_back() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_run_action(RunAction::Rerun);
+        app_state.confirm_run_action();
+        assert_eq!(app_state.workflow_runs["owner1/repo1"][0].status, WorkflowStatus::Queued);
+
+        let refresh = app_state.finish_run_action(Err(AppError::GithubError("Forbidden".to_string())));
+
+        assert!(!refresh);
+        assert_eq!(app_state.popup, Some(PopupType::Confirm));
+        assert!(app_state.confirm_dialog.error.as_ref().unwrap().contains("Forbidden"));
+        let run = &app_state.workflow_runs["owner1/repo1"][0];
+        assert_eq!(run.status, WorkflowStatus::Completed);
+        assert_eq!(run.conclusion, Some(WorkflowConclusion::Success));
+    }
+
+    #[tokio::test]
+    async fn test_successful_run_action_closes_popup() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_run_action(RunAction::Rerun);
+        app_state.confirm_run_action();
+
+        let refresh = app_state.finish_run_action(Ok(()));
+
+        assert!(refresh);
+        assert_eq!(app_state.popup, None);
+        assert_eq!(app_state.workflow_runs["owner1/repo1"][0].status, WorkflowStatus::Queued);
+    }
+
+    #[tokio::test]
+    async fn test_dispatch_form_flow() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(1);
+
+        app_state.handle_key("w");
+        assert_eq!(app_state.popup, Some(PopupType::Dispatch));
+        assert_eq!(app_state.dispatch_form.repo_full_name, "owner2/repo2");
+        assert!(app_state.dispatch_form.loading);
+        assert!(!app_state.is_text_input_active());
+
+        app_state.dispatch_form.set_workflows(vec![crate::github::models::Workflow {
+            id: 9,
+            name: "Release".to_string(),
+            path: ".github/workflows/release.yml".to_string(),
+            state: "active".to_string(),
+        }]);
+        app_state.handle_key("enter");
+        assert_eq!(app_state.dispatch_form.stage, DispatchStage::EditInputs);
+
+        app_state.dispatch_form.set_inputs(Some(vec![crate::github::models::WorkflowInput {
+            name: "version".to_string(),
+            description: None,
+            required: true,
+            default: None,
+            kind: crate::github::models::InputKind::String,
+        }]));
+        assert!(app_state.is_text_input_active());
+
+        app_state.handle_key("tab");
+        app_state.handle_char('j'); // Typed, not navigation
+        app_state.handle_char('q');
+        assert_eq!(app_state.dispatch_form.fields[1].value, "jq");
+        assert_eq!(app_state.selected_repo, Some(1));
+
+        app_state.handle_key("enter");
+        assert!(app_state.dispatch_form.submitting);
+
+        app_state.dispatch_form.set_error("Unexpected inputs".to_string());
+        app_state.handle_key("esc"); // Back to the workflow list
+        assert_eq!(app_state.popup, Some(PopupType::Dispatch));
+        assert_eq!(app_state.dispatch_form.stage, DispatchStage::SelectWorkflow);
+
+        app_state.handle_key("esc");
+        assert_eq!(app_state.popup, None);
+    }
+
+    #[tokio::test]
+    async fn test_open_dispatch_form_without_repo() {
+        let mut app_state = create_test_app_state();
+
+        app_state.handle_key("w");
+
+        assert_eq!(app_state.popup, None);
+    }
+
     #[tokio::test]
     async fn test_seconds_until_refresh_no_repos() {
         let app_state = AppState {
@@ -786,10 +2022,25 @@ fn create_test_app_state() -> AppState {
             selected_run: None,
             popup: None,
             context_menu: crate::ui::components::context_menu::ContextMenuComponent::new(),
+            job_list: crate::ui::components::job_list::JobListComponent::new(),
+            run_history: crate::ui::components::run_history::RunHistoryComponent::new(),
+            pull_request_list: crate::ui::components::pull_request_list::PullRequestListComponent::new(),
+            log_viewer: crate::ui::components::log_viewer::LogViewerComponent::new(),
+            confirm_dialog: crate::ui::components::confirm_dialog::ConfirmDialogComponent::new(),
+            dispatch_form: crate::ui::components::dispatch_form::DispatchFormComponent::new(),
+            theme: crate::ui::theme::Theme::default(),
             settings: create_test_settings(vec![]),
-            github_client: crate::github::client::GithubClient::new(create_test_settings(vec![])).unwrap(),
+            github_clients: ClientPool::new(&create_test_settings(vec![])).unwrap(),
             last_repo_refresh_times: HashMap::new(),
             refreshing_repos: Arc::new(RwLock::new(HashSet::new())),
+            refresher: crate::ui::refresh::Refresher::new(5),
+            loader: Default::default(),
+            discovery: crate::ui::refresh::DiscoveryTask::default(),
+            local_checkout: crate::utils::local_git::LocalCheckout::default(),
+            notifications: crate::notify::Notifications::default(),
+            hooks: crate::notify::hooks::ShellHooks::default(),
+            transitions: Default::default(),
+            fetched_runs: HashMap::new(),
         };
         
         let seconds = app_state.seconds_until_refresh();
@@ -846,6 +2097,171 @@ fn create_test_app_state() -> AppState {
         assert_eq!(refreshing.len(), 1);
     }
 
+    #[tokio::test]
+    async fn test_apply_refresh_events() {
+        let mut app_state = create_test_app_state();
+        let id = app_state.refresher.register("owner1/repo1");
+
+        app_state.apply_refresh_event(RefreshEvent::Started { repo: "owner1/repo1".to_string(), id });
+        assert!(app_state.refreshing_repos.read().unwrap().contains("owner1/repo1"));
+
+        app_state.apply_refresh_event(RefreshEvent::Finished {
+            repo: "owner1/repo1".to_string(),
+            id,
+            result: Ok(vec![]),
+        });
+        assert!(app_state.refreshing_repos.read().unwrap().is_empty());
+        assert!(app_state.workflow_runs["owner1/repo1"].is_empty());
+        assert!(app_state.last_repo_refresh_times.contains_key("owner1/repo1"));
+        assert!(!app_state.refresher.is_in_flight("owner1/repo1"));
+    }
+
+    #[tokio::test]
+    async fn test_set_repositories_keeps_selection() {
+        let mut app_state = create_test_app_state();
+        app_state.selected_repo = Some(1);
+        app_state.selected_run = Some(0);
+
+        let mut new_repo = app_state.repositories[0].clone();
+        new_repo.name = "repo3".to_string();
+        new_repo.full_name = "owner1/repo3".to_string();
+        let repositories = vec![new_repo, app_state.repositories[1].clone()];
+        app_state.set_repositories(repositories);
+
+        // owner1/repo1 was removed, owner2/repo2 is still selected
+        assert_eq!(app_state.repositories.len(), 2);
+        assert_eq!(app_state.selected_repo, Some(1));
+        assert!(!app_state.workflow_runs.contains_key("owner1/repo1"));
+        assert!(app_state.workflow_runs.contains_key("owner2/repo2"));
+
+        // Duplicates in the new list are not mistaken for removals
+        let duplicated = vec![app_state.repositories[1].clone(), app_state.repositories[1].clone(), app_state.repositories[0].clone()];
+        app_state.set_repositories(duplicated);
+        assert_eq!(app_state.repositories.len(), 3);
+
+        app_state.set_repositories(vec![]);
+        assert_eq!(app_state.selected_repo, None);
+        assert_eq!(app_state.selected_run, None);
+    }
+
+    #[tokio::test]
+    async fn test_apply_refresh_event_failure_and_stale_results() {
+        let mut app_state = create_test_app_state();
+        let runs_before = app_state.workflow_runs["owner2/repo2"].len();
+        let stale_id = app_state.refresher.register("owner2/repo2");
+        let id = app_state.refresher.register("owner2/repo2");
+
+        // Superseded by a newer refresh, so it must not overwrite anything
+        app_state.apply_refresh_event(RefreshEvent::Finished {
+            repo: "owner2/repo2".to_string(),
+            id: stale_id,
+            result: Ok(vec![]),
+        });
+        assert_eq!(app_state.workflow_runs["owner2/repo2"].len(), runs_before);
+        assert!(app_state.refresher.is_in_flight("owner2/repo2"));
+
+        // Failures keep the previous runs but still wait for the next interval
+        app_state.apply_refresh_event(RefreshEvent::Finished {
+            repo: "owner2/repo2".to_string(),
+            id,
+            result: Err(AppError::GithubError("Bad gateway".to_string())),
+        });
+        assert_eq!(app_state.workflow_runs["owner2/repo2"].len(), runs_before);
+        assert!(app_state.last_repo_refresh_times.contains_key("owner2/repo2"));
+        assert!(!app_state.refresher.is_in_flight("owner2/repo2"));
+    }
+
+    struct RecordingNotifier(std::sync::Arc<std::sync::Mutex<Vec<crate::notify::transitions::TransitionKind>>>);
+
+    impl crate::notify::Notifier for RecordingNotifier {
+        fn name(&self) -> &'static str {
+            "recording"
+        }
+
+        fn notify(&self, transition: &crate::notify::transitions::Transition) -> Result<(), AppError> {
+            self.0.lock().unwrap().push(transition.kind);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_transitions_ignore_optimistic_updates() {
+        use crate::notify::transitions::TransitionKind;
+
+        let mut app_state = create_test_app_state();
+        let sent = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let config = crate::config::notifications::NotificationConfig {
+            events: vec![TransitionKind::Started, TransitionKind::Cancelled],
+            ..Default::default()
+        };
+        app_state.notifications = crate::notify::Notifications::with_notifiers(config, vec![Box::new(RecordingNotifier(std::sync::Arc::clone(&sent)))]);
+
+        let mut running = app_state.workflow_runs["owner1/repo1"][0].clone();
+        running.status = WorkflowStatus::InProgress;
+        running.conclusion = None;
+        let refresh = |app_state: &mut AppState, run: &WorkflowRun| {
+            let id = app_state.refresher.register("owner1/repo1");
+            app_state.apply_refresh_event(RefreshEvent::Finished { repo: "owner1/repo1".to_string(), id, result: Ok(vec![run.clone()]) });
+        };
+        refresh(&mut app_state, &running);
+
+        app_state.selected_repo = Some(0);
+        app_state.selected_run = Some(0);
+        app_state.open_run_action(RunAction::Cancel);
+        app_state.confirm_run_action();
+        assert_eq!(app_state.workflow_runs["owner1/repo1"][0].conclusion, Some(WorkflowConclusion::Cancelled));
+
+        // GitHub has not caught up yet: the run is still in progress, which is not a new start
+        refresh(&mut app_state, &running);
+        assert!(sent.lock().unwrap().is_empty());
+
+        let mut cancelled = running.clone();
+        cancelled.status = WorkflowStatus::Completed;
+        cancelled.conclusion = Some(WorkflowConclusion::Cancelled);
+        refresh(&mut app_state, &cancelled);
+        assert_eq!(*sent.lock().unwrap(), vec![TransitionKind::Cancelled]);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_interval_stretched_when_budget_low() {
+        let app_state = create_test_app_state();
+        let reset_at = Utc::now() + chrono::Duration::minutes(30);
+        let base = app_state.calculate_refresh_interval("owner1/repo1");
+
+        app_state.github_clients.get(None, None).unwrap().rate_limits().record(RateLimit { limit: 5000, remaining: 200, reset_at });
+
+        assert_eq!(app_state.calculate_refresh_interval("owner1/repo1"), base * 8);
+    }
+
+    #[tokio::test]
+    async fn test_refresh_paused_when_rate_limited() {
+        let mut app_state = create_test_app_state();
+        let reset_at = Utc::now() + chrono::Duration::minutes(5);
+        app_state.github_clients.get(None, None).unwrap().rate_limits().record(RateLimit { limit: 5000, remaining: 0, reset_at });
+
+        assert_eq!(app_state.repo_paused_until("owner1/repo1"), Some(reset_at));
+        assert!(app_state.seconds_until_refresh() > 240);
+        assert_eq!(app_state.start_refresh(true), 0);
+        assert_eq!(app_state.refresher.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limits_are_per_host() {
+        let mut app_state = create_test_app_state();
+        app_state.settings.hosts = vec![HostConfig { host: "github.example.com".to_string(), api_url: None }];
+        app_state.settings.repositories[1].host = Some("github.example.com".to_string());
+        app_state.github_clients = ClientPool::new(&app_state.settings).unwrap();
+        app_state.repositories[1].host = Some("github.example.com".to_string());
+
+        let reset_at = Utc::now() + chrono::Duration::minutes(5);
+        app_state.github_clients.get(None, None).unwrap().rate_limits().record(RateLimit { limit: 5000, remaining: 0, reset_at });
+
+        assert_eq!(app_state.repo_paused_until("owner1/repo1"), Some(reset_at));
+        assert_eq!(app_state.repo_paused_until("owner2/repo2"), None);
+        // The enterprise repository has never been refreshed and is not held up by github.com
+        assert_eq!(app_state.seconds_until_refresh(), 0);
+    }
+
     #[tokio::test]
     async fn test_calculate_refresh_interval_very_active_new() {
         let app_state = create_test_app_state();
@@ -0,0 +1,196 @@
+use crate::github::models::{RunAction, WorkflowRun};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::{Alignment, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+pub struct ConfirmDialogComponent {
+    pub action: Option<RunAction>,
+    pub repo_full_name: String,
+    /// The run as it was before the action, used to roll back the optimistic update.
+    pub run: Option<WorkflowRun>,
+    /// Set once the user has confirmed and the request is waiting to be sent.
+    pub confirmed: bool,
+    pub error: Option<String>,
+}
+
+impl Default for ConfirmDialogComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ConfirmDialogComponent {
+    pub fn new() -> Self {
+        ConfirmDialogComponent {
+            action: None,
+            repo_full_name: String::new(),
+            run: None,
+            confirmed: false,
+            error: None,
+        }
+    }
+
+    pub fn open(&mut self, action: RunAction, repo_full_name: String, run: WorkflowRun) {
+        self.error = action.unavailable_reason(&run).map(str::to_string);
+        self.action = Some(action);
+        self.repo_full_name = repo_full_name;
+        self.run = Some(run);
+        self.confirmed = false;
+    }
+
+    /// Accept the prompt; returns false when there is nothing that can be confirmed.
+    pub fn confirm(&mut self) -> bool {
+        if self.action.is_none() || self.error.is_some() || self.confirmed {
+            return false;
+        }
+        self.confirmed = true;
+        true
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.confirmed = false;
+        self.error = Some(error);
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let (Some(action), Some(run)) = (self.action, &self.run) else {
+            return;
+        };
+
+        let mut lines = vec![
+            Line::from(format!("{} for {}?", action.label(), self.repo_full_name)),
+            Line::from(Span::styled(
+                format!("{} #{} on {}", run.name, run.id, run.branch),
+                Style::default().fg(theme.muted),
+            )),
+            Line::from(""),
+        ];
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(theme.error))));
+            lines.push(Line::from(Span::styled("[Esc] Close", Style::default().fg(theme.muted))));
+        } else if self.confirmed {
+            lines.push(Line::from(Span::styled("Sending request...", Style::default().fg(theme.warning))));
+        } else {
+            lines.push(Line::from(vec![
+                Span::styled("[y] Yes", Style::default().fg(theme.success)),
+                Span::raw("   "),
+                Span::styled("[n] No", Style::default().fg(theme.error)),
+            ]));
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true })
+            .block(Block::default().borders(Borders::ALL).title("Confirm"));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{WorkflowConclusion, WorkflowStatus};
+    use chrono::Utc;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_test_run(status: WorkflowStatus, conclusion: Option<WorkflowConclusion>) -> WorkflowRun {
+        WorkflowRun {
+            id: 42,
+            name: "CI".to_string(),
+            status,
+            conclusion,
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: "abc123".to_string(),
+            actor: "testuser".to_string(),
+            html_url: "https://github.com/test/repo/run/42".to_string(),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_confirm_available_action() {
+        let mut dialog = ConfirmDialogComponent::new();
+        dialog.open(
+            RunAction::RerunFailed,
+            "test/repo".to_string(),
+            create_test_run(WorkflowStatus::Completed, Some(WorkflowConclusion::Failure)),
+        );
+
+        assert!(dialog.error.is_none());
+        assert!(dialog.confirm());
+        assert!(dialog.confirmed);
+        assert!(!dialog.confirm()); // Already waiting for the request
+    }
+
+    #[test]
+    fn test_confirm_unavailable_action() {
+        let mut dialog = ConfirmDialogComponent::new();
+        dialog.open(
+            RunAction::Cancel,
+            "test/repo".to_string(),
+            create_test_run(WorkflowStatus::Completed, Some(WorkflowConclusion::Success)),
+        );
+
+        assert_eq!(dialog.error, Some("Run has already completed".to_string()));
+        assert!(!dialog.confirm());
+        assert!(!dialog.confirmed);
+    }
+
+    #[test]
+    fn test_set_error_clears_confirmation() {
+        let mut dialog = ConfirmDialogComponent::new();
+        dialog.open(
+            RunAction::Cancel,
+            "test/repo".to_string(),
+            create_test_run(WorkflowStatus::InProgress, None),
+        );
+        dialog.confirm();
+
+        dialog.set_error("Forbidden".to_string());
+
+        assert!(!dialog.confirmed);
+        assert_eq!(dialog.error, Some("Forbidden".to_string()));
+    }
+
+    #[test]
+    fn test_render_prompt() {
+        let mut dialog = ConfirmDialogComponent::new();
+        dialog.open(
+            RunAction::Rerun,
+            "test/repo".to_string(),
+            create_test_run(WorkflowStatus::Completed, Some(WorkflowConclusion::Success)),
+        );
+
+        let backend = TestBackend::new(50, 7);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                dialog.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Re-run all jobs for test/repo?"));
+        assert!(content.contains("CI #42 on main"));
+        assert!(content.contains("[y] Yes"));
+    }
+}
@@ -1,6 +1,7 @@
+use crate::ui::theme::Theme;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::Span,
     widgets::{Block, Borders, List, ListItem},
     Frame,
@@ -11,19 +12,30 @@ pub struct ContextMenuComponent {
     pub items: Vec<String>,
 }
 
+impl Default for ContextMenuComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ContextMenuComponent {
     pub fn new() -> Self {
         ContextMenuComponent {
             selected_index: 0,
             items: vec![
+                "View Jobs".to_string(),
                 "View Logs".to_string(),
+                "View History".to_string(),
+                "Re-run all jobs".to_string(),
+                "Re-run failed jobs".to_string(),
+                "Cancel run".to_string(),
                 "Open in Browser".to_string(),
                 "Close Menu".to_string(),
             ],
         }
     }
 
-    pub fn render(&self, frame: &mut Frame, area: Rect) {
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
         let items: Vec<ListItem> = self
             .items
             .iter()
@@ -32,9 +44,9 @@ impl ContextMenuComponent {
                 let line = Span::styled(
                     item.clone(),
                     if index == self.selected_index {
-                        Style::default().fg(Color::Black).bg(Color::White)
+                        theme.selected()
                     } else {
-                        Style::default().fg(Color::White)
+                        Style::default().fg(theme.text)
                     },
                 );
                 ListItem::new(vec![line.into()])
@@ -43,7 +55,7 @@ impl ContextMenuComponent {
 
         let list = List::new(items)
             .block(Block::default().borders(Borders::ALL).title("Actions"))
-            .highlight_style(Style::default().bg(Color::DarkGray));
+            .highlight_style(Style::default().bg(theme.selected_bg));
 
         frame.render_widget(list, area);
     }
@@ -70,10 +82,15 @@ mod tests {
     fn test_context_menu_new() {
         let menu = ContextMenuComponent::new();
         assert_eq!(menu.selected_index, 0);
-        assert_eq!(menu.items.len(), 3);
-        assert_eq!(menu.items[0], "View Logs");
-        assert_eq!(menu.items[1], "Open in Browser");
-        assert_eq!(menu.items[2], "Close Menu");
+        assert_eq!(menu.items.len(), 8);
+        assert_eq!(menu.items[0], "View Jobs");
+        assert_eq!(menu.items[1], "View Logs");
+        assert_eq!(menu.items[2], "View History");
+        assert_eq!(menu.items[3], "Re-run all jobs");
+        assert_eq!(menu.items[4], "Re-run failed jobs");
+        assert_eq!(menu.items[5], "Cancel run");
+        assert_eq!(menu.items[6], "Open in Browser");
+        assert_eq!(menu.items[7], "Close Menu");
     }
 
     #[test]
@@ -86,6 +103,11 @@ mod tests {
         menu.next();
         assert_eq!(menu.selected_index, 2);
         
+        for _ in 0..5 {
+            menu.next();
+        }
+        assert_eq!(menu.selected_index, 7);
+        
         menu.next();
         assert_eq!(menu.selected_index, 0); // Should wrap around
     }
@@ -95,12 +117,17 @@ mod tests {
         let mut menu = ContextMenuComponent::new();
         
         menu.previous();
-        assert_eq!(menu.selected_index, 2); // Should wrap to end
+        assert_eq!(menu.selected_index, 7); // Should wrap to end
         
         menu.previous();
-        assert_eq!(menu.selected_index, 1);
+        assert_eq!(menu.selected_index, 6);
         
         menu.previous();
+        assert_eq!(menu.selected_index, 5);
+        
+        for _ in 0..5 {
+            menu.previous();
+        }
         assert_eq!(menu.selected_index, 0);
     }
 
@@ -108,8 +135,23 @@ mod tests {
     fn test_context_menu_get_selected_action() {
         let mut menu = ContextMenuComponent::new();
         
+        assert_eq!(menu.get_selected_action(), "View Jobs");
+        
+        menu.next();
         assert_eq!(menu.get_selected_action(), "View Logs");
         
+        menu.next();
+        assert_eq!(menu.get_selected_action(), "View History");
+        
+        menu.next();
+        assert_eq!(menu.get_selected_action(), "Re-run all jobs");
+        
+        menu.next();
+        assert_eq!(menu.get_selected_action(), "Re-run failed jobs");
+        
+        menu.next();
+        assert_eq!(menu.get_selected_action(), "Cancel run");
+        
         menu.next();
         assert_eq!(menu.get_selected_action(), "Open in Browser");
         
@@ -140,7 +182,12 @@ mod tests {
         
         // Verify all expected items are present
         let expected_items = vec![
+            "View Jobs",
             "View Logs",
+            "View History",
+            "Re-run all jobs",
+            "Re-run failed jobs",
+            "Cancel run",
             "Open in Browser", 
             "Close Menu"
         ];
@@ -152,7 +199,7 @@ mod tests {
     fn test_context_menu_initial_selection() {
         let menu = ContextMenuComponent::new();
         assert_eq!(menu.selected_index, 0);
-        assert_eq!(menu.get_selected_action(), "View Logs");
+        assert_eq!(menu.get_selected_action(), "View Jobs");
     }
 
     #[test]
@@ -0,0 +1,519 @@
+use crate::github::models::{InputKind, Workflow, WorkflowInput};
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
+    Frame,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DispatchStage {
+    SelectWorkflow,
+    EditInputs,
+}
+
+pub struct FormField {
+    pub input: WorkflowInput,
+    pub value: String,
+    pub error: Option<String>,
+}
+
+impl FormField {
+    fn new(input: WorkflowInput) -> Self {
+        let value = match (&input.default, &input.kind) {
+            (Some(default), _) => default.clone(),
+            (None, InputKind::Boolean) => "false".to_string(),
+            (None, InputKind::Choice(options)) => options.first().cloned().unwrap_or_default(),
+            (None, _) => String::new(),
+        };
+        FormField { input, value, error: None }
+    }
+
+    /// Booleans and choices are changed by cycling through their values instead of typing.
+    fn is_toggle(&self) -> bool {
+        matches!(self.input.kind, InputKind::Boolean | InputKind::Choice(_))
+    }
+
+    fn cycle(&mut self, forward: bool) {
+        let options = match &self.input.kind {
+            InputKind::Boolean => vec!["false".to_string(), "true".to_string()],
+            InputKind::Choice(options) if !options.is_empty() => options.clone(),
+            _ => return,
+        };
+        let len = options.len();
+        let next = match options.iter().position(|option| *option == self.value) {
+            Some(index) if forward => (index + 1) % len,
+            Some(index) => (index + len - 1) % len,
+            None => 0,
+        };
+        self.value = options[next].clone();
+        self.error = None;
+    }
+}
+
+pub struct DispatchFormComponent {
+    pub repo_full_name: String,
+    pub default_branch: String,
+    pub workflows: Vec<Workflow>,
+    pub selected_workflow: usize,
+    pub stage: DispatchStage,
+    /// The git ref to run on, followed by the workflow's declared inputs.
+    pub fields: Vec<FormField>,
+    pub selected_field: usize,
+    pub loading: bool,
+    pub submitting: bool,
+    pub error: Option<String>,
+}
+
+impl Default for DispatchFormComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DispatchFormComponent {
+    pub fn new() -> Self {
+        DispatchFormComponent {
+            repo_full_name: String::new(),
+            default_branch: String::new(),
+            workflows: Vec::new(),
+            selected_workflow: 0,
+            stage: DispatchStage::SelectWorkflow,
+            fields: Vec::new(),
+            selected_field: 0,
+            loading: false,
+            submitting: false,
+            error: None,
+        }
+    }
+
+    /// Reset the form for `repo_full_name` and mark it as waiting for its workflows.
+    pub fn open(&mut self, repo_full_name: String, default_branch: String) {
+        *self = DispatchFormComponent {
+            repo_full_name,
+            default_branch,
+            loading: true,
+            ..DispatchFormComponent::new()
+        };
+    }
+
+    pub fn set_workflows(&mut self, workflows: Vec<Workflow>) {
+        self.workflows = workflows;
+        self.selected_workflow = 0;
+        self.loading = false;
+        self.error = None;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading = false;
+        self.submitting = false;
+        self.error = Some(error);
+    }
+
+    pub fn get_selected_workflow(&self) -> Option<&Workflow> {
+        self.workflows.get(self.selected_workflow)
+    }
+
+    /// Move to the input form of the selected workflow, whose inputs still need to be fetched.
+    pub fn select_workflow(&mut self) -> bool {
+        if self.loading || self.get_selected_workflow().is_none() {
+            return false;
+        }
+        self.stage = DispatchStage::EditInputs;
+        self.fields.clear();
+        self.selected_field = 0;
+        self.loading = true;
+        self.error = None;
+        true
+    }
+
+    /// Fill the form with the inputs read from the workflow file; `None` means the workflow
+    /// cannot be dispatched, which sends the user back to the workflow list.
+    pub fn set_inputs(&mut self, inputs: Option<Vec<WorkflowInput>>) {
+        self.loading = false;
+        let Some(inputs) = inputs else {
+            let name = self.get_selected_workflow().map(|workflow| workflow.name.clone()).unwrap_or_default();
+            self.stage = DispatchStage::SelectWorkflow;
+            self.error = Some(format!("{} has no workflow_dispatch trigger", name));
+            return;
+        };
+
+        let ref_field = FormField::new(WorkflowInput {
+            name: "ref".to_string(),
+            description: Some("Branch or tag to run on".to_string()),
+            required: true,
+            default: Some(self.default_branch.clone()),
+            kind: InputKind::String,
+        });
+        self.fields = std::iter::once(ref_field).chain(inputs.into_iter().map(FormField::new)).collect();
+        self.selected_field = 0;
+        self.error = None;
+    }
+
+    /// Go back one step; returns false when already on the workflow list.
+    pub fn back(&mut self) -> bool {
+        if self.stage == DispatchStage::EditInputs && !self.submitting {
+            self.stage = DispatchStage::SelectWorkflow;
+            self.fields.clear();
+            self.loading = false;
+            self.error = None;
+            return true;
+        }
+        false
+    }
+
+    /// Whether typed characters should go into the selected field.
+    pub fn is_editing(&self) -> bool {
+        self.stage == DispatchStage::EditInputs && !self.loading && !self.submitting && !self.fields.is_empty()
+    }
+
+    pub fn next(&mut self) {
+        match self.stage {
+            DispatchStage::SelectWorkflow if !self.workflows.is_empty() => {
+                self.selected_workflow = (self.selected_workflow + 1) % self.workflows.len();
+                self.error = None;
+            }
+            DispatchStage::EditInputs if !self.fields.is_empty() => {
+                self.selected_field = (self.selected_field + 1) % self.fields.len();
+            }
+            _ => {}
+        }
+    }
+
+    pub fn previous(&mut self) {
+        match self.stage {
+            DispatchStage::SelectWorkflow if !self.workflows.is_empty() => {
+                let len = self.workflows.len();
+                self.selected_workflow = (self.selected_workflow + len - 1) % len;
+                self.error = None;
+            }
+            DispatchStage::EditInputs if !self.fields.is_empty() => {
+                let len = self.fields.len();
+                self.selected_field = (self.selected_field + len - 1) % len;
+            }
+            _ => {}
+        }
+    }
+
+    pub fn input_char(&mut self, c: char) {
+        if !self.is_editing() {
+            return;
+        }
+        let field = &mut self.fields[self.selected_field];
+        if field.is_toggle() {
+            if c == ' ' {
+                field.cycle(true);
+            }
+        } else {
+            field.value.push(c);
+            field.error = None;
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        if !self.is_editing() {
+            return;
+        }
+        let field = &mut self.fields[self.selected_field];
+        if !field.is_toggle() {
+            field.value.pop();
+            field.error = None;
+        }
+    }
+
+    pub fn cycle(&mut self, forward: bool) {
+        if self.is_editing() {
+            self.fields[self.selected_field].cycle(forward);
+        }
+    }
+
+    /// Validate every field and mark the form as ready to send; returns false on errors.
+    pub fn submit(&mut self) -> bool {
+        if !self.is_editing() {
+            return false;
+        }
+        let mut valid = true;
+        for field in &mut self.fields {
+            field.error = field.input.validate(field.value.trim()).err();
+            valid &= field.error.is_none();
+        }
+        if valid {
+            self.submitting = true;
+            self.error = None;
+        } else if let Some(index) = self.fields.iter().position(|field| field.error.is_some()) {
+            self.selected_field = index;
+        }
+        valid
+    }
+
+    pub fn git_ref(&self) -> &str {
+        self.fields.first().map(|field| field.value.trim()).unwrap_or_default()
+    }
+
+    /// Input values to send, leaving out optional inputs that were left empty.
+    pub fn input_values(&self) -> Vec<(String, String)> {
+        self.fields
+            .iter()
+            .skip(1)
+            .filter(|field| !field.value.trim().is_empty())
+            .map(|field| (field.input.name.clone(), field.value.trim().to_string()))
+            .collect()
+    }
+
+    fn render_workflows(&self, lines: &mut Vec<Line>, theme: &Theme) {
+        if self.loading {
+            lines.push(Line::from(Span::styled("Loading workflows...", Style::default().fg(theme.warning))));
+        } else if self.workflows.is_empty() && self.error.is_none() {
+            lines.push(Line::from(Span::styled("No active workflows", Style::default().fg(theme.muted))));
+        }
+
+        for (index, workflow) in self.workflows.iter().enumerate() {
+            let style = if index == self.selected_workflow {
+                theme.selected().add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme.muted)
+            };
+            lines.push(Line::from(Span::styled(format!("{} ({})", workflow.name, workflow.path), style)));
+        }
+    }
+
+    fn render_fields(&self, lines: &mut Vec<Line>, theme: &Theme) {
+        if self.loading {
+            lines.push(Line::from(Span::styled("Loading inputs...", Style::default().fg(theme.warning))));
+            return;
+        }
+
+        for (index, field) in self.fields.iter().enumerate() {
+            let is_selected = index == self.selected_field;
+            let mut label = vec![Span::styled(
+                format!("{}{}", field.input.name, if field.input.required { " *" } else { "" }),
+                Style::default().fg(theme.text).add_modifier(Modifier::BOLD),
+            )];
+            if let Some(description) = &field.input.description {
+                label.push(Span::styled(format!("  {}", description), Style::default().fg(theme.muted)));
+            }
+            lines.push(Line::from(label));
+
+            let value = match (field.is_toggle(), is_selected) {
+                (true, true) => format!("< {} >", field.value),
+                (false, true) => format!("{}_", field.value),
+                _ => field.value.clone(),
+            };
+            let value_style = if is_selected {
+                theme.selected()
+            } else {
+                Style::default().fg(theme.muted)
+            };
+            lines.push(Line::from(vec![Span::raw("  "), Span::styled(value, value_style)]));
+
+            if let Some(error) = &field.error {
+                lines.push(Line::from(Span::styled(format!("  {}", error), Style::default().fg(theme.error))));
+            }
+        }
+
+        if self.submitting {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled("Dispatching workflow...", Style::default().fg(theme.warning))));
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut lines = vec![];
+
+        let (title, help) = match self.stage {
+            DispatchStage::SelectWorkflow => {
+                self.render_workflows(&mut lines, theme);
+                (format!("Run Workflow - {}", self.repo_full_name), "j/k select  Enter choose  Esc close")
+            }
+            DispatchStage::EditInputs => {
+                self.render_fields(&mut lines, theme);
+                let name = self.get_selected_workflow().map(|workflow| workflow.name.as_str()).unwrap_or_default();
+                (format!("Run Workflow - {}", name), "Tab/↑↓ field  ←→/Space change  Enter run  Esc back")
+            }
+        };
+
+        if let Some(error) = &self.error {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(error.clone(), Style::default().fg(theme.error))));
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .wrap(Wrap { trim: false })
+            .block(Block::default().borders(Borders::ALL).title(title).title_bottom(help));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_test_workflow(id: u64, name: &str) -> Workflow {
+        Workflow {
+            id,
+            name: name.to_string(),
+            path: format!(".github/workflows/{}.yml", name.to_lowercase()),
+            state: "active".to_string(),
+        }
+    }
+
+    fn create_test_inputs() -> Vec<WorkflowInput> {
+        vec![
+            WorkflowInput {
+                name: "environment".to_string(),
+                description: Some("Target environment".to_string()),
+                required: true,
+                default: None,
+                kind: InputKind::Choice(vec!["staging".to_string(), "production".to_string()]),
+            },
+            WorkflowInput {
+                name: "version".to_string(),
+                description: None,
+                required: true,
+                default: None,
+                kind: InputKind::String,
+            },
+            WorkflowInput {
+                name: "notes".to_string(),
+                description: None,
+                required: false,
+                default: None,
+                kind: InputKind::String,
+            },
+        ]
+    }
+
+    fn create_test_form() -> DispatchFormComponent {
+        let mut form = DispatchFormComponent::new();
+        form.open("test/repo".to_string(), "main".to_string());
+        form.set_workflows(vec![create_test_workflow(1, "CI"), create_test_workflow(2, "Deploy")]);
+        form.next();
+        form.select_workflow();
+        form.set_inputs(Some(create_test_inputs()));
+        form
+    }
+
+    #[test]
+    fn test_open_and_select_workflow() {
+        let mut form = DispatchFormComponent::new();
+        form.open("test/repo".to_string(), "main".to_string());
+        assert!(form.loading);
+        assert!(!form.select_workflow()); // Workflows are still loading
+
+        form.set_workflows(vec![create_test_workflow(1, "CI"), create_test_workflow(2, "Deploy")]);
+        form.next();
+        assert_eq!(form.get_selected_workflow().unwrap().name, "Deploy");
+
+        assert!(form.select_workflow());
+        assert_eq!(form.stage, DispatchStage::EditInputs);
+        assert!(form.loading);
+        assert!(!form.is_editing());
+    }
+
+    #[test]
+    fn test_set_inputs_prepends_ref() {
+        let form = create_test_form();
+
+        assert!(form.is_editing());
+        assert_eq!(form.fields.len(), 4);
+        assert_eq!(form.fields[0].input.name, "ref");
+        assert_eq!(form.git_ref(), "main");
+        assert_eq!(form.fields[1].value, "staging"); // First choice by default
+    }
+
+    #[test]
+    fn test_workflow_without_dispatch_trigger() {
+        let mut form = DispatchFormComponent::new();
+        form.open("test/repo".to_string(), "main".to_string());
+        form.set_workflows(vec![create_test_workflow(1, "CI")]);
+        form.select_workflow();
+
+        form.set_inputs(None);
+
+        assert_eq!(form.stage, DispatchStage::SelectWorkflow);
+        assert_eq!(form.error, Some("CI has no workflow_dispatch trigger".to_string()));
+    }
+
+    #[test]
+    fn test_editing_text_and_choice_fields() {
+        let mut form = create_test_form();
+
+        form.next(); // environment
+        form.input_char('x'); // Ignored for choices
+        form.cycle(true);
+        assert_eq!(form.fields[1].value, "production");
+        form.input_char(' ');
+        assert_eq!(form.fields[1].value, "staging");
+
+        form.next(); // version
+        for c in "1.2.x".chars() {
+            form.input_char(c);
+        }
+        form.backspace();
+        form.input_char('3');
+        assert_eq!(form.fields[2].value, "1.2.3");
+    }
+
+    #[test]
+    fn test_submit_validates_fields() {
+        let mut form = create_test_form();
+
+        assert!(!form.submit());
+        assert!(!form.submitting);
+        assert_eq!(form.selected_field, 2); // Jumps to the missing version
+        assert_eq!(form.fields[2].error, Some("version is required".to_string()));
+
+        form.input_char('1');
+        assert!(form.submit());
+        assert!(form.submitting);
+        assert!(!form.is_editing());
+        assert_eq!(
+            form.input_values(),
+            vec![
+                ("environment".to_string(), "staging".to_string()),
+                ("version".to_string(), "1".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_back_returns_to_workflows() {
+        let mut form = create_test_form();
+
+        assert!(form.back());
+        assert_eq!(form.stage, DispatchStage::SelectWorkflow);
+        assert!(form.fields.is_empty());
+        assert!(!form.back());
+    }
+
+    #[test]
+    fn test_render_form() {
+        let form = create_test_form();
+
+        let backend = TestBackend::new(70, 14);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                form.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Run Workflow - Deploy"));
+        assert!(content.contains("environment *"));
+        assert!(content.contains("Target environment"));
+        assert!(content.contains("main_"));
+    }
+}
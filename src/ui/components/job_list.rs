@@ -0,0 +1,339 @@
+use crate::github::models::{Job, WorkflowConclusion, WorkflowRun};
+use crate::ui::theme::Theme;
+use crate::utils::time::duration_to_human_readable;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+pub struct JobListComponent {
+    /// Repository the run belongs to, where its jobs are fetched from.
+    pub repo_full_name: Option<String>,
+    pub run: Option<WorkflowRun>,
+    pub jobs: Vec<Job>,
+    pub selected_index: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl Default for JobListComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn format_duration(duration: Option<std::time::Duration>) -> String {
+    duration
+        .map(duration_to_human_readable)
+        .unwrap_or_else(|| "-".to_string())
+}
+
+impl JobListComponent {
+    pub fn new() -> Self {
+        JobListComponent {
+            repo_full_name: None,
+            run: None,
+            jobs: Vec::new(),
+            selected_index: 0,
+            loading: false,
+            error: None,
+        }
+    }
+
+    /// Reset the view for `run` of `repo_full_name` and mark it as waiting for its jobs to be
+    /// fetched.
+    pub fn open(&mut self, repo_full_name: String, run: WorkflowRun) {
+        self.repo_full_name = Some(repo_full_name);
+        self.run = Some(run);
+        self.jobs.clear();
+        self.selected_index = 0;
+        self.loading = true;
+        self.error = None;
+    }
+
+    pub fn set_jobs(&mut self, jobs: Vec<Job>) {
+        self.jobs = jobs;
+        self.selected_index = 0;
+        self.loading = false;
+        self.error = None;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.jobs.clear();
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    pub fn next(&mut self) {
+        if !self.jobs.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.jobs.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let len = self.jobs.len();
+        if len > 0 {
+            self.selected_index = (self.selected_index + len - 1) % len;
+        }
+    }
+
+    pub fn get_selected_job(&self) -> Option<&Job> {
+        self.jobs.get(self.selected_index)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut lines = vec![];
+
+        if self.loading {
+            lines.push(Line::from(Span::styled("Loading jobs...", Style::default().fg(theme.warning))));
+        } else if let Some(error) = &self.error {
+            lines.push(Line::from(Span::styled(
+                format!("Failed to load jobs: {}", error),
+                Style::default().fg(theme.error),
+            )));
+        } else if self.jobs.is_empty() {
+            lines.push(Line::from(Span::styled("No jobs for this run", Style::default().fg(theme.muted))));
+        }
+
+        for (index, job) in self.jobs.iter().enumerate() {
+            let is_selected = index == self.selected_index;
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} {} ({})",
+                    theme.icons.state(&job.status, &job.conclusion),
+                    job.name,
+                    format_duration(job.duration())
+                ),
+                if is_selected {
+                    theme.selected()
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.muted)
+                },
+            )));
+
+            // Steps are only expanded for the selected job to keep the popup compact
+            if is_selected {
+                for step in &job.steps {
+                    let step_style = if step.conclusion == Some(WorkflowConclusion::Failure) {
+                        Style::default().fg(theme.error)
+                    } else {
+                        Style::default().fg(theme.muted)
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled("    ", Style::default()),
+                        Span::styled(
+                            format!(
+                                "{} {}. {} ({})",
+                                theme.icons.state(&step.status, &step.conclusion),
+                                step.number,
+                                step.name,
+                                format_duration(step.duration())
+                            ),
+                            step_style,
+                        ),
+                    ]));
+                }
+            }
+        }
+
+        let title = match &self.run {
+            Some(run) => {
+                let pull_requests: Vec<String> = run
+                    .pull_requests
+                    .iter()
+                    .map(|pr| match (&pr.title, &pr.author) {
+                        (Some(title), Some(author)) => format!("PR #{} {} by {}", pr.number, title, author),
+                        _ => format!("PR #{}", pr.number),
+                    })
+                    .collect();
+                if pull_requests.is_empty() {
+                    format!("Jobs - {} ({})", run.name, run.branch)
+                } else {
+                    format!("Jobs - {} ({}, {})", run.name, run.branch, pull_requests.join(", "))
+                }
+            }
+            None => "Jobs".to_string(),
+        };
+
+        // Keep the selected job and its steps in view, or at least the job when they do not fit
+        let visible = area.height.saturating_sub(2) as usize;
+        let selected_end = self.selected_index + 1 + self.get_selected_job().map_or(0, |job| job.steps.len());
+        let scroll = selected_end
+            .saturating_sub(visible)
+            .min(self.selected_index)
+            .min(lines.len().saturating_sub(visible));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .scroll((scroll as u16, 0));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{Step, WorkflowStatus};
+    use chrono::Utc;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_test_run() -> WorkflowRun {
+        WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Failure),
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: "abc123".to_string(),
+            actor: "testuser".to_string(),
+            html_url: "https://github.com/test/repo/run/1".to_string(),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn create_test_job(id: u64, name: &str, conclusion: Option<WorkflowConclusion>) -> Job {
+        Job {
+            id,
+            run_id: 1,
+            name: name.to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion,
+            started_at: Some(Utc::now()),
+            completed_at: Some(Utc::now()),
+            html_url: None,
+            steps: vec![Step {
+                number: 1,
+                name: "Run tests".to_string(),
+                status: WorkflowStatus::Completed,
+                conclusion,
+                started_at: Some(Utc::now()),
+                completed_at: Some(Utc::now()),
+            }],
+        }
+    }
+
+    #[test]
+    fn test_open_marks_loading() {
+        let mut component = JobListComponent::new();
+        component.open("owner/repo".to_string(), create_test_run());
+
+        assert!(component.loading);
+        assert!(component.jobs.is_empty());
+        assert_eq!(component.run.as_ref().unwrap().id, 1);
+    }
+
+    #[test]
+    fn test_set_jobs_and_navigation() {
+        let mut component = JobListComponent::new();
+        component.open("owner/repo".to_string(), create_test_run());
+        component.set_jobs(vec![
+            create_test_job(1, "build", Some(WorkflowConclusion::Success)),
+            create_test_job(2, "test", Some(WorkflowConclusion::Failure)),
+        ]);
+
+        assert!(!component.loading);
+        assert_eq!(component.get_selected_job().unwrap().name, "build");
+
+        component.next();
+        assert_eq!(component.get_selected_job().unwrap().name, "test");
+
+        component.next();
+        assert_eq!(component.selected_index, 0); // Should wrap around
+
+        component.previous();
+        assert_eq!(component.selected_index, 1); // Should wrap to end
+    }
+
+    #[test]
+    fn test_navigation_without_jobs() {
+        let mut component = JobListComponent::new();
+
+        component.next();
+        component.previous();
+
+        assert_eq!(component.selected_index, 0);
+        assert!(component.get_selected_job().is_none());
+    }
+
+    #[test]
+    fn test_set_error() {
+        let mut component = JobListComponent::new();
+        component.open("owner/repo".to_string(), create_test_run());
+        component.set_error("Not Found".to_string());
+
+        assert!(!component.loading);
+        assert_eq!(component.error, Some("Not Found".to_string()));
+    }
+
+    #[test]
+    fn test_render_shows_selected_job_steps() {
+        let mut component = JobListComponent::new();
+        component.open("owner/repo".to_string(), create_test_run());
+        component.set_jobs(vec![
+            create_test_job(1, "build", Some(WorkflowConclusion::Success)),
+            create_test_job(2, "test", Some(WorkflowConclusion::Failure)),
+        ]);
+
+        let backend = TestBackend::new(60, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                component.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Jobs - CI (main)"));
+        assert!(content.contains("build"));
+        assert!(content.contains("test"));
+        // Only the selected job has its steps expanded
+        assert_eq!(content.matches("Run tests").count(), 1);
+    }
+
+    #[test]
+    fn test_render_scrolls_to_selected_job() {
+        let mut component = JobListComponent::new();
+        component.open("owner/repo".to_string(), create_test_run());
+        component.set_jobs((1..=20).map(|id| create_test_job(id, &format!("matrix-{}", id), Some(WorkflowConclusion::Success))).collect());
+        for _ in 0..17 {
+            component.next();
+        }
+
+        let backend = TestBackend::new(60, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                component.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("matrix-18"));
+        assert!(content.contains("Run tests"));
+        assert!(!content.contains("matrix-1 "));
+    }
+}
@@ -0,0 +1,273 @@
+use crate::github::logs::is_error_line;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+use std::cell::Cell;
+
+/// Default page size used before the viewer has been rendered once.
+const DEFAULT_PAGE_SIZE: usize = 20;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LogSource {
+    Run(u64),
+    Job(u64),
+}
+
+pub struct LogViewerComponent {
+    pub title: String,
+    /// Repository the logs are downloaded from.
+    pub repo_full_name: Option<String>,
+    pub source: Option<LogSource>,
+    pub lines: Vec<String>,
+    pub scroll: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+    /// Number of log lines that fit in the popup, updated on every render.
+    page_size: Cell<usize>,
+}
+
+impl Default for LogViewerComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl LogViewerComponent {
+    pub fn new() -> Self {
+        LogViewerComponent {
+            title: String::new(),
+            repo_full_name: None,
+            source: None,
+            lines: Vec::new(),
+            scroll: 0,
+            loading: false,
+            error: None,
+            page_size: Cell::new(DEFAULT_PAGE_SIZE),
+        }
+    }
+
+    /// Reset the viewer and mark it as waiting for the logs of `source` in `repo_full_name` to
+    /// be fetched.
+    pub fn open(&mut self, repo_full_name: String, title: String, source: LogSource) {
+        self.repo_full_name = Some(repo_full_name);
+        self.title = title;
+        self.source = Some(source);
+        self.lines.clear();
+        self.scroll = 0;
+        self.loading = true;
+        self.error = None;
+    }
+
+    pub fn set_lines(&mut self, lines: Vec<String>) {
+        self.lines = lines;
+        self.scroll = 0;
+        self.loading = false;
+        self.error = None;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.lines.clear();
+        self.scroll = 0;
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.lines.len().saturating_sub(self.page_size.get())
+    }
+
+    pub fn scroll_down(&mut self, amount: usize) {
+        self.scroll = (self.scroll + amount).min(self.max_scroll());
+    }
+
+    pub fn scroll_up(&mut self, amount: usize) {
+        self.scroll = self.scroll.saturating_sub(amount);
+    }
+
+    pub fn page_down(&mut self) {
+        self.scroll_down(self.page_size.get());
+    }
+
+    pub fn page_up(&mut self) {
+        self.scroll_up(self.page_size.get());
+    }
+
+    pub fn jump_to_start(&mut self) {
+        self.scroll = 0;
+    }
+
+    pub fn jump_to_end(&mut self) {
+        self.scroll = self.max_scroll();
+    }
+
+    /// Scroll so the first error line is at the top; returns false when the log has no errors.
+    pub fn jump_to_first_error(&mut self) -> bool {
+        match self.lines.iter().position(|line| is_error_line(line)) {
+            Some(index) => {
+                self.scroll = index.min(self.max_scroll());
+                true
+            }
+            None => false,
+        }
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        // Two rows for the borders
+        let page_size = (area.height as usize).saturating_sub(2).max(1);
+        self.page_size.set(page_size);
+
+        let lines: Vec<Line> = if self.loading {
+            vec![Line::from(Span::styled("Downloading logs...", Style::default().fg(theme.warning)))]
+        } else if let Some(error) = &self.error {
+            vec![Line::from(Span::styled(
+                format!("Failed to load logs: {}", error),
+                Style::default().fg(theme.error),
+            ))]
+        } else if self.lines.is_empty() {
+            vec![Line::from(Span::styled("Log is empty", Style::default().fg(theme.muted)))]
+        } else {
+            self.lines
+                .iter()
+                .skip(self.scroll)
+                .take(page_size)
+                .map(|line| {
+                    let style = if is_error_line(line) {
+                        Style::default().fg(theme.error)
+                    } else if line.starts_with("=== ") {
+                        Style::default().fg(theme.info)
+                    } else {
+                        Style::default().fg(theme.muted)
+                    };
+                    Line::from(Span::styled(line.clone(), style))
+                })
+                .collect()
+        };
+
+        let position = if self.lines.is_empty() {
+            String::new()
+        } else {
+            format!(
+                " [{}-{}/{}]",
+                self.scroll + 1,
+                (self.scroll + page_size).min(self.lines.len()),
+                self.lines.len()
+            )
+        };
+
+        let paragraph = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!("Logs - {}{}", self.title, position))
+                .title_bottom("PgUp/PgDn page  g/G start/end  e first error  Esc close"),
+        );
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_test_viewer(line_count: usize) -> LogViewerComponent {
+        let mut viewer = LogViewerComponent::new();
+        viewer.open("owner/repo".to_string(), "CI".to_string(), LogSource::Run(1));
+        viewer.set_lines((0..line_count).map(|i| format!("line {}", i)).collect());
+        viewer
+    }
+
+    #[test]
+    fn test_open_marks_loading() {
+        let mut viewer = LogViewerComponent::new();
+        viewer.open("owner/repo".to_string(), "build".to_string(), LogSource::Job(7));
+
+        assert!(viewer.loading);
+        assert_eq!(viewer.source, Some(LogSource::Job(7)));
+        assert_eq!(viewer.title, "build");
+    }
+
+    #[test]
+    fn test_scrolling_is_clamped() {
+        let mut viewer = create_test_viewer(50);
+
+        viewer.scroll_up(5);
+        assert_eq!(viewer.scroll, 0);
+
+        viewer.scroll_down(100);
+        assert_eq!(viewer.scroll, 50 - DEFAULT_PAGE_SIZE);
+    }
+
+    #[test]
+    fn test_paging() {
+        let mut viewer = create_test_viewer(50);
+
+        viewer.page_down();
+        assert_eq!(viewer.scroll, DEFAULT_PAGE_SIZE);
+
+        viewer.page_up();
+        assert_eq!(viewer.scroll, 0);
+    }
+
+    #[test]
+    fn test_jump_to_start_and_end() {
+        let mut viewer = create_test_viewer(50);
+
+        viewer.jump_to_end();
+        assert_eq!(viewer.scroll, 30);
+
+        viewer.jump_to_start();
+        assert_eq!(viewer.scroll, 0);
+    }
+
+    #[test]
+    fn test_short_log_does_not_scroll() {
+        let mut viewer = create_test_viewer(5);
+
+        viewer.page_down();
+        viewer.jump_to_end();
+        assert_eq!(viewer.scroll, 0);
+    }
+
+    #[test]
+    fn test_jump_to_first_error() {
+        let mut viewer = create_test_viewer(50);
+        viewer.lines[12] = "##[error]Process completed with exit code 1.".to_string();
+        viewer.lines[40] = "error: second failure".to_string();
+
+        assert!(viewer.jump_to_first_error());
+        assert_eq!(viewer.scroll, 12);
+    }
+
+    #[test]
+    fn test_jump_to_first_error_without_errors() {
+        let mut viewer = create_test_viewer(50);
+        viewer.scroll_down(3);
+
+        assert!(!viewer.jump_to_first_error());
+        assert_eq!(viewer.scroll, 3);
+    }
+
+    #[test]
+    fn test_render_updates_page_size() {
+        let mut viewer = create_test_viewer(50);
+
+        let backend = TestBackend::new(60, 12);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                viewer.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        viewer.page_down();
+        assert_eq!(viewer.scroll, 10); // 12 rows minus borders
+    }
+}
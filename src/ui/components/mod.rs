@@ -1,2 +1,9 @@
 pub mod workflow_list;
-pub mod context_menu;
\ No newline at end of file
+pub mod context_menu;
+pub mod job_list;
+pub mod run_history;
+pub mod pull_request_list;
+pub mod log_viewer;
+pub mod confirm_dialog;
+pub mod dispatch_form;
+pub mod status_bar;
\ No newline at end of file
@@ -0,0 +1,255 @@
+use crate::github::models::PullRequestStatus;
+use crate::ui::theme::Theme;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Open pull requests of one repository with the combined state of their checks.
+pub struct PullRequestListComponent {
+    pub repo_full_name: Option<String>,
+    pub pull_requests: Vec<PullRequestStatus>,
+    pub selected_index: usize,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl Default for PullRequestListComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl PullRequestListComponent {
+    pub fn new() -> Self {
+        PullRequestListComponent {
+            repo_full_name: None,
+            pull_requests: Vec::new(),
+            selected_index: 0,
+            loading: false,
+            error: None,
+        }
+    }
+
+    /// Reset the view for `repo_full_name` and mark it as waiting for its pull requests.
+    pub fn open(&mut self, repo_full_name: String) {
+        self.repo_full_name = Some(repo_full_name);
+        self.pull_requests.clear();
+        self.selected_index = 0;
+        self.loading = true;
+        self.error = None;
+    }
+
+    pub fn set_pull_requests(&mut self, pull_requests: Vec<PullRequestStatus>) {
+        self.pull_requests = pull_requests;
+        self.selected_index = 0;
+        self.loading = false;
+        self.error = None;
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.pull_requests.clear();
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    pub fn next(&mut self) {
+        if !self.pull_requests.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.pull_requests.len();
+        }
+    }
+
+    pub fn previous(&mut self) {
+        let len = self.pull_requests.len();
+        if len > 0 {
+            self.selected_index = (self.selected_index + len - 1) % len;
+        }
+    }
+
+    pub fn get_selected_pull_request(&self) -> Option<&PullRequestStatus> {
+        self.pull_requests.get(self.selected_index)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut lines = vec![];
+
+        if self.loading {
+            lines.push(Line::from(Span::styled("Loading pull requests...", Style::default().fg(theme.warning))));
+        } else if let Some(error) = &self.error {
+            lines.push(Line::from(Span::styled(
+                format!("Failed to load pull requests: {}", error),
+                Style::default().fg(theme.error),
+            )));
+        } else if self.pull_requests.is_empty() {
+            lines.push(Line::from(Span::styled("No open pull requests", Style::default().fg(theme.muted))));
+        }
+
+        for (index, pull_request) in self.pull_requests.iter().enumerate() {
+            let is_selected = index == self.selected_index;
+            let (status, conclusion) = pull_request.combined_state();
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} PR #{}: {} ({}, {} -> {}): {}",
+                    theme.icons.state(&status, &conclusion),
+                    pull_request.number,
+                    pull_request.title,
+                    pull_request.author,
+                    pull_request.head_branch,
+                    pull_request.base_branch,
+                    pull_request.check_summary()
+                ),
+                if is_selected {
+                    theme.selected().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.muted)
+                },
+            )));
+
+            // Checks are only expanded for the selected pull request to keep the popup compact
+            if is_selected {
+                for check in &pull_request.checks {
+                    let check_style = if check.is_failed() {
+                        Style::default().fg(theme.error)
+                    } else {
+                        Style::default().fg(theme.muted)
+                    };
+                    lines.push(Line::from(vec![
+                        Span::styled("    ", Style::default()),
+                        Span::styled(
+                            format!("{} {}", theme.icons.state(&check.status, &check.conclusion), check.name),
+                            check_style,
+                        ),
+                    ]));
+                }
+            }
+        }
+
+        let title = match &self.repo_full_name {
+            Some(name) => format!("Pull Requests - {}", name),
+            None => "Pull Requests".to_string(),
+        };
+
+        // Keep the selected pull request and its checks in view, or at least the pull request
+        // when they do not fit
+        let visible = area.height.saturating_sub(2) as usize;
+        let selected_end = self.selected_index + 1 + self.get_selected_pull_request().map_or(0, |pull_request| pull_request.checks.len());
+        let scroll = selected_end
+            .saturating_sub(visible)
+            .min(self.selected_index)
+            .min(lines.len().saturating_sub(visible));
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .scroll((scroll as u16, 0));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{CheckRun, WorkflowConclusion, WorkflowStatus};
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_test_pull_request(number: u64, failing_check: Option<&str>) -> PullRequestStatus {
+        let mut checks = vec![CheckRun {
+            name: "build".to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Success),
+        }];
+        checks.extend(failing_check.map(|name| CheckRun {
+            name: name.to_string(),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Failure),
+        }));
+        PullRequestStatus {
+            number,
+            title: format!("Change {}", number),
+            author: "alice".to_string(),
+            head_branch: format!("change-{}", number),
+            base_branch: "main".to_string(),
+            head_sha: "abc123".to_string(),
+            html_url: format!("https://github.com/test/repo/pull/{}", number),
+            checks,
+        }
+    }
+
+    #[test]
+    fn test_open_and_navigation() {
+        let mut component = PullRequestListComponent::new();
+        component.open("owner/repo".to_string());
+        assert!(component.loading);
+
+        component.set_pull_requests(vec![create_test_pull_request(1, None), create_test_pull_request(2, None)]);
+        assert!(!component.loading);
+        assert_eq!(component.get_selected_pull_request().unwrap().number, 1);
+
+        component.next();
+        assert_eq!(component.get_selected_pull_request().unwrap().number, 2);
+        component.next();
+        assert_eq!(component.selected_index, 0); // Should wrap around
+        component.previous();
+        assert_eq!(component.selected_index, 1); // Should wrap to end
+    }
+
+    #[test]
+    fn test_set_error() {
+        let mut component = PullRequestListComponent::new();
+        component.open("owner/repo".to_string());
+        component.set_error("Not Found".to_string());
+
+        assert!(!component.loading);
+        assert_eq!(component.error, Some("Not Found".to_string()));
+    }
+
+    #[test]
+    fn test_render_shows_summary_and_selected_checks() {
+        let mut component = PullRequestListComponent::new();
+        component.open("owner/repo".to_string());
+        component.set_pull_requests(vec![create_test_pull_request(412, Some("lint")), create_test_pull_request(413, None)]);
+
+        let backend = TestBackend::new(100, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                component.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(content.contains("PR #412: Change 412 (alice, change-412 -> main): 1/2 checks green, lint failed"));
+        assert!(content.contains("PR #413"));
+        // Only the selected pull request lists its checks
+        assert_eq!(content.matches("build").count(), 1);
+    }
+
+    #[test]
+    fn test_render_scrolls_to_selected_pull_request() {
+        let mut component = PullRequestListComponent::new();
+        component.open("owner/repo".to_string());
+        component.set_pull_requests((1..=30).map(|number| create_test_pull_request(number, Some("lint"))).collect());
+        for _ in 0..28 {
+            component.next();
+        }
+
+        let backend = TestBackend::new(100, 10);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                component.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(content.contains("PR #29: Change 29"));
+        assert!(content.contains("lint"));
+        assert!(!content.contains("PR #1:"));
+    }
+}
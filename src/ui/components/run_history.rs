@@ -0,0 +1,275 @@
+use crate::github::models::{RunHistoryPage, WorkflowRun};
+use crate::ui::theme::Theme;
+use crate::utils::time::format_relative_time;
+use ratatui::{
+    layout::Rect,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+/// Past runs of one repository, fetched a page at a time as the list is scrolled past its end.
+pub struct RunHistoryComponent {
+    pub repo_full_name: Option<String>,
+    pub runs: Vec<WorkflowRun>,
+    pub selected_index: usize,
+    /// Page to fetch next, `None` once the last page or `limit` has been reached.
+    pub next_page: Option<u32>,
+    /// Whether `next_page` should be fetched.
+    pub loading: bool,
+    pub error: Option<String>,
+    /// How many runs are loaded at most.
+    pub limit: usize,
+}
+
+impl Default for RunHistoryComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RunHistoryComponent {
+    pub fn new() -> Self {
+        RunHistoryComponent {
+            repo_full_name: None,
+            runs: Vec::new(),
+            selected_index: 0,
+            next_page: None,
+            loading: false,
+            error: None,
+            limit: 0,
+        }
+    }
+
+    /// Reset the view for `repo_full_name` and mark its first page as waiting to be fetched.
+    pub fn open(&mut self, repo_full_name: String, limit: usize) {
+        self.repo_full_name = Some(repo_full_name);
+        self.runs.clear();
+        self.selected_index = 0;
+        self.next_page = (limit > 0).then_some(1);
+        self.loading = limit > 0;
+        self.error = None;
+        self.limit = limit;
+    }
+
+    pub fn close(&mut self) {
+        *self = Self::new();
+    }
+
+    pub fn is_open(&self) -> bool {
+        self.repo_full_name.is_some()
+    }
+
+    pub fn append_page(&mut self, page: RunHistoryPage) {
+        let fetched = self.next_page.unwrap_or(1);
+        // Runs started since the previous page shift older ones onto the next page
+        let new_runs: Vec<WorkflowRun> = page
+            .runs
+            .into_iter()
+            .filter(|run| !self.runs.iter().any(|known| known.id == run.id))
+            .collect();
+        self.runs.extend(new_runs);
+        self.runs.truncate(self.limit);
+
+        self.next_page = (page.has_more && self.runs.len() < self.limit).then_some(fetched + 1);
+        self.loading = false;
+        self.error = None;
+        // Move onto the first new run when the page was loaded by scrolling past the end
+        if self.selected_index + 1 < self.runs.len() && fetched > 1 {
+            self.selected_index += 1;
+        }
+    }
+
+    pub fn set_error(&mut self, error: String) {
+        self.loading = false;
+        self.error = Some(error);
+    }
+
+    /// Select the next run, or request the next page when the last loaded run is selected.
+    pub fn next(&mut self) {
+        if self.selected_index + 1 < self.runs.len() {
+            self.selected_index += 1;
+        } else if self.next_page.is_some() && self.error.is_none() {
+            self.loading = true;
+        }
+    }
+
+    pub fn previous(&mut self) {
+        self.selected_index = self.selected_index.saturating_sub(1);
+    }
+
+    pub fn get_selected_run(&self) -> Option<&WorkflowRun> {
+        self.runs.get(self.selected_index)
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let mut lines: Vec<Line> = self
+            .runs
+            .iter()
+            .enumerate()
+            .map(|(index, run)| {
+                let style = if index == self.selected_index {
+                    theme.selected().add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(theme.muted)
+                };
+                Line::from(Span::styled(
+                    format!(
+                        "{} {} ({}) by {}, {}",
+                        theme.icons.state(&run.status, &run.conclusion),
+                        run.name,
+                        run.branch,
+                        run.actor,
+                        format_relative_time(run.created_at)
+                    ),
+                    style,
+                ))
+            })
+            .collect();
+
+        if self.loading {
+            lines.push(Line::from(Span::styled("Loading runs...", Style::default().fg(theme.warning))));
+        } else if let Some(error) = &self.error {
+            lines.push(Line::from(Span::styled(
+                format!("Failed to load runs: {}", error),
+                Style::default().fg(theme.error),
+            )));
+        } else if self.runs.is_empty() {
+            lines.push(Line::from(Span::styled("No runs for this repository", Style::default().fg(theme.muted))));
+        } else if self.next_page.is_some() {
+            lines.push(Line::from(Span::styled("Scroll down to load more", Style::default().fg(theme.muted))));
+        }
+
+        // Keep the selected run, or the loading line below it, in view
+        let visible = area.height.saturating_sub(2) as usize;
+        let scroll = (self.selected_index + 2).saturating_sub(visible).min(lines.len().saturating_sub(visible));
+
+        let title = match &self.repo_full_name {
+            Some(name) => format!("History - {} ({} runs)", name, self.runs.len()),
+            None => "History".to_string(),
+        };
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .scroll((scroll as u16, 0));
+
+        frame.render_widget(Clear, area);
+        frame.render_widget(paragraph, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::{WorkflowConclusion, WorkflowStatus};
+    use chrono::Utc;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    fn create_test_run(id: u64) -> WorkflowRun {
+        WorkflowRun {
+            id,
+            name: format!("CI {}", id),
+            status: WorkflowStatus::Completed,
+            conclusion: Some(WorkflowConclusion::Success),
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: "abc123".to_string(),
+            actor: "testuser".to_string(),
+            html_url: format!("https://github.com/test/repo/actions/runs/{}", id),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    fn create_page(ids: std::ops::RangeInclusive<u64>, has_more: bool) -> RunHistoryPage {
+        RunHistoryPage {
+            runs: ids.map(create_test_run).collect(),
+            has_more,
+        }
+    }
+
+    #[test]
+    fn test_open_marks_loading() {
+        let mut component = RunHistoryComponent::new();
+        component.open("owner/repo".to_string(), 100);
+
+        assert!(component.is_open());
+        assert!(component.loading);
+        assert_eq!(component.next_page, Some(1));
+
+        component.close();
+        assert!(!component.is_open());
+    }
+
+    #[test]
+    fn test_scrolling_past_the_end_loads_the_next_page() {
+        let mut component = RunHistoryComponent::new();
+        component.open("owner/repo".to_string(), 100);
+        component.append_page(create_page(1..=2, true));
+        assert!(!component.loading);
+        assert_eq!(component.next_page, Some(2));
+
+        component.next();
+        assert_eq!(component.get_selected_run().unwrap().id, 2);
+        assert!(!component.loading);
+
+        component.next();
+        assert!(component.loading);
+
+        // Run 2 moved onto the second page as a new run was started
+        component.append_page(create_page(2..=4, false));
+        assert_eq!(component.runs.iter().map(|run| run.id).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(component.get_selected_run().unwrap().id, 3);
+        assert_eq!(component.next_page, None);
+
+        component.selected_index = 3;
+        component.next();
+        assert!(!component.loading);
+    }
+
+    #[test]
+    fn test_limit_stops_loading() {
+        let mut component = RunHistoryComponent::new();
+        component.open("owner/repo".to_string(), 3);
+        component.append_page(create_page(1..=5, true));
+
+        assert_eq!(component.runs.len(), 3);
+        assert_eq!(component.next_page, None);
+    }
+
+    #[test]
+    fn test_error_stops_loading() {
+        let mut component = RunHistoryComponent::new();
+        component.open("owner/repo".to_string(), 100);
+        component.set_error("Not Found".to_string());
+
+        assert!(!component.loading);
+        component.next();
+        assert!(!component.loading);
+    }
+
+    #[test]
+    fn test_render_keeps_selected_run_visible() {
+        let mut component = RunHistoryComponent::new();
+        component.open("owner/repo".to_string(), 100);
+        component.append_page(create_page(1..=20, true));
+        component.selected_index = 15;
+
+        let backend = TestBackend::new(60, 8);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                component.render(f, area, &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect();
+        assert!(content.contains("History - owner/repo (20 runs)"));
+        assert!(content.contains("CI 16"));
+        assert!(!content.contains("CI 1 "));
+    }
+}
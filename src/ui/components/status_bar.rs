@@ -0,0 +1,119 @@
+use crate::github::rate_limit::RateLimit;
+use crate::ui::theme::Theme;
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    layout::Rect,
+    style::Style,
+    symbols,
+    widgets::{Block, Borders, LineGauge},
+    Frame,
+};
+
+/// Footer with the remaining GitHub API budget and when it resets.
+pub struct StatusBarComponent;
+
+impl Default for StatusBarComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+fn format_local_time(time: DateTime<Utc>) -> String {
+    time.with_timezone(&Local).format("%H:%M").to_string()
+}
+
+impl StatusBarComponent {
+    pub fn new() -> Self {
+        StatusBarComponent
+    }
+
+    pub fn label(rate_limit: Option<RateLimit>, paused_until: Option<DateTime<Utc>>) -> String {
+        match (rate_limit, paused_until) {
+            (_, Some(until)) => format!("Rate limited, refreshing paused until {}", format_local_time(until)),
+            (Some(rate_limit), None) => format!(
+                "API {}/{} left, resets {}",
+                rate_limit.remaining,
+                rate_limit.limit,
+                format_local_time(rate_limit.reset_at)
+            ),
+            (None, None) => "API budget not known yet".to_string(),
+        }
+    }
+
+    pub fn render(
+        &self,
+        frame: &mut Frame,
+        area: Rect,
+        rate_limit: Option<RateLimit>,
+        paused_until: Option<DateTime<Utc>>,
+        account: Option<&str>,
+        theme: &Theme,
+    ) {
+        let ratio = if paused_until.is_some() {
+            0.0
+        } else {
+            rate_limit.map_or(1.0, |rate_limit| rate_limit.remaining_ratio())
+        };
+        let color = match ratio {
+            ratio if ratio >= 0.5 => theme.success,
+            ratio if ratio >= 0.1 => theme.warning,
+            _ => theme.error,
+        };
+
+        let title = match account {
+            Some(account) => format!("Rate Limit - {}", account),
+            None => "Rate Limit".to_string(),
+        };
+        let gauge = LineGauge::default()
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .filled_style(Style::default().fg(color))
+            .unfilled_style(Style::default().fg(theme.muted))
+            .line_set(symbols::line::THICK)
+            .label(Self::label(rate_limit, paused_until))
+            .ratio(ratio);
+
+        frame.render_widget(gauge, area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::{backend::TestBackend, Terminal};
+
+    #[test]
+    fn test_label() {
+        let reset_at = Utc::now();
+        let rate_limit = RateLimit { limit: 5000, remaining: 4321, reset_at };
+
+        assert_eq!(
+            StatusBarComponent::label(Some(rate_limit), None),
+            format!("API 4321/5000 left, resets {}", format_local_time(reset_at))
+        );
+        assert!(StatusBarComponent::label(Some(rate_limit), Some(reset_at)).starts_with("Rate limited"));
+        assert_eq!(StatusBarComponent::label(None, None), "API budget not known yet");
+    }
+
+    #[test]
+    fn test_render() {
+        let rate_limit = RateLimit { limit: 5000, remaining: 250, reset_at: Utc::now() };
+        let backend = TestBackend::new(80, 3);
+        let mut terminal = Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| {
+                let area = f.area();
+                StatusBarComponent::new().render(f, area, Some(rate_limit), None, Some("github.example.com"), &Theme::default());
+            })
+            .unwrap();
+
+        let content: String = terminal
+            .backend()
+            .buffer()
+            .content()
+            .iter()
+            .map(|cell| cell.symbol())
+            .collect();
+        assert!(content.contains("Rate Limit - github.example.com"));
+        assert!(content.contains("API 250/5000 left"));
+    }
+}
@@ -1,8 +1,9 @@
 use crate::github::models::WorkflowRun;
-use crate::utils::icons::{get_status_icon, get_conclusion_icon};
+use crate::ui::theme::Theme;
+use crate::utils::local_git::LocalHead;
 use ratatui::{
     layout::Rect,
-    style::{Color, Style},
+    style::Style,
     text::{Line, Span},
     widgets::{Block, Borders, Paragraph},
     Frame,
@@ -15,6 +16,12 @@ pub struct WorkflowListComponent {
     pub selected_run_index: usize,
 }
 
+impl Default for WorkflowListComponent {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl WorkflowListComponent {
     pub fn new() -> Self {
         WorkflowListComponent {
@@ -23,6 +30,7 @@ impl WorkflowListComponent {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn render(
         &self,
         f: &mut Frame,
@@ -31,6 +39,8 @@ impl WorkflowListComponent {
         repo_names: &[String],
         seconds_until_refresh: u64,
         refreshing_repos: &Arc<RwLock<HashSet<String>>>,
+        local_head: Option<&LocalHead>,
+        theme: &Theme,
     ) {
         let mut lines = vec![];
 
@@ -42,13 +52,12 @@ impl WorkflowListComponent {
         
         // Add timer information
         let timer_text = if is_any_refreshing {
-            // Simple spinner frames with refresh emoji
-            let spinner_frames = ["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
-            let frame_index = (std::time::SystemTime::now()
+            // Spinner frames advance every 100ms
+            let tick = (std::time::SystemTime::now()
                 .duration_since(std::time::UNIX_EPOCH)
                 .unwrap_or_default()
-                .as_millis() / 100) as usize % spinner_frames.len();
-            format!("🔄 {} Refreshing {} repos...", spinner_frames[frame_index], refreshing_set.len())
+                .as_millis() / 100) as usize;
+            format!("{} {} Refreshing {} repos...", theme.icons.running, theme.icons.spinner_frame(tick), refreshing_set.len())
         } else if seconds_until_refresh < 60 {
             format!("Refresh in {}s", seconds_until_refresh)
         } else {
@@ -56,32 +65,34 @@ impl WorkflowListComponent {
         };
         
         lines.push(Line::from(vec![
-            Span::styled(timer_text, Style::default().fg(Color::Yellow))
+            Span::styled(timer_text, Style::default().fg(theme.warning))
         ]));
 
         // Add workflow runs for each repository
         for (repo_idx, repo_name) in repo_names.iter().enumerate() {
             if let Some(runs) = workflow_runs.get(repo_name) {
                 let is_refreshing = refreshing_set.contains(repo_name);
-                let refresh_indicator = if is_refreshing { "🔄 " } else { "" };
+                let refresh_indicator = if is_refreshing { format!("{} ", theme.icons.running) } else { String::new() };
                 
                 lines.push(Line::from(vec![
                     Span::styled(
                         format!("{}{}: {}", refresh_indicator, repo_name, runs.len()),
                         Style::default().fg(if repo_idx == self.selected_repo_index {
-                            Color::Green
+                            theme.success
                         } else {
-                            Color::Gray
+                            theme.muted
                         })
                     )
                 ]));
 
+                let local_head = local_head.filter(|head| head.is_repository(repo_name));
                 for (run_idx, run) in runs.iter().enumerate() {
                     let is_selected = repo_idx == self.selected_repo_index && run_idx == self.selected_run_index;
-                    let status_icon = get_status_icon(&run.status);
-                    let conclusion_icon = get_conclusion_icon(&run.conclusion);
+                    let status_icon = theme.icons.status(&run.status);
+                    let conclusion_icon = theme.icons.conclusion(&run.conclusion);
+                    let is_head = local_head.is_some_and(|head| head.sha == run.commit_sha);
                     
-                    lines.push(Line::from(vec![
+                    let mut spans = vec![
                         Span::styled("  ", Style::default()),
                         Span::styled(
                             format!("{} {} {} - {} ({})", 
@@ -92,14 +103,25 @@ impl WorkflowListComponent {
                                 crate::utils::time::format_relative_time(run.updated_at)
                             ),
                             if is_selected {
-                                Style::default()
-                                    .fg(Color::White)
-                                    .bg(Color::DarkGray)
+                                theme.selected()
                                     .add_modifier(ratatui::style::Modifier::ITALIC)
+                            } else if is_head {
+                                Style::default().fg(theme.info).add_modifier(ratatui::style::Modifier::BOLD)
                             } else {
-                                Style::default().fg(Color::Gray)
+                                Style::default().fg(theme.muted)
                             }
                         )
+                    ];
+                    if is_head {
+                        spans.push(Span::styled(" <- HEAD", Style::default().fg(theme.info)));
+                    }
+                    lines.push(Line::from(spans));
+                }
+
+                if let Some(status) = local_head.and_then(|head| head.status_line(runs)) {
+                    lines.push(Line::from(vec![
+                        Span::styled("  ", Style::default()),
+                        Span::styled(status, Style::default().fg(theme.warning)),
                     ]));
                 }
             }
@@ -219,12 +241,11 @@ impl WorkflowListComponent {
     }
 
     pub fn get_selected_run(&self, workflow_runs: &HashMap<String, Vec<WorkflowRun>>, repo_names: &[String]) -> Option<WorkflowRun> {
-        if let Some(repo_name) = repo_names.get(self.selected_repo_index) {
-            if let Some(runs) = workflow_runs.get(repo_name) {
-                if self.selected_run_index < runs.len() {
-                    return Some(runs[self.selected_run_index].clone());
-                }
-            }
+        if let Some(repo_name) = repo_names.get(self.selected_repo_index)
+            && let Some(runs) = workflow_runs.get(repo_name)
+            && self.selected_run_index < runs.len()
+        {
+            return Some(runs[self.selected_run_index].clone());
         }
         None
     }
@@ -252,6 +273,7 @@ mod tests {
                 name: "Test Workflow".to_string(),
                 status: WorkflowStatus::Completed,
                 conclusion: Some(WorkflowConclusion::Success),
+                run_attempt: 1,
                 created_at: Utc::now(),
                 updated_at: Utc::now(),
                 branch: "main".to_string(),
@@ -259,6 +281,7 @@ mod tests {
                 actor: "testuser".to_string(),
                 html_url: "https://github.com/test/repo/run/123".to_string(),
                 logs_url: Some("https://github.com/test/repo/logs/123".to_string()),
+                pull_requests: Vec::new(),
             }
         ]);
         runs
@@ -312,19 +335,19 @@ mod tests {
         
         // Test with different timer values
         let area = frame.area();
-        component.render(&mut frame, area, &runs, &repo_names, 0, &refreshing_repos);
+        component.render(&mut frame, area, &runs, &repo_names, 0, &refreshing_repos, None, &Theme::default());
         
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         let mut frame = terminal.get_frame();
         let area = frame.area();
-        component.render(&mut frame, area, &runs, &repo_names, 30, &refreshing_repos);
+        component.render(&mut frame, area, &runs, &repo_names, 30, &refreshing_repos, None, &Theme::default());
         
         let backend = TestBackend::new(80, 24);
         let mut terminal = Terminal::new(backend).unwrap();
         let mut frame = terminal.get_frame();
         let area = frame.area();
-        component.render(&mut frame, area, &runs, &repo_names, 120, &refreshing_repos);
+        component.render(&mut frame, area, &runs, &repo_names, 120, &refreshing_repos, None, &Theme::default());
         
         // Test refreshing state
         let mut refreshing = refreshing_repos.write().unwrap();
@@ -335,6 +358,45 @@ mod tests {
         let mut terminal = Terminal::new(backend).unwrap();
         let mut frame = terminal.get_frame();
         let area = frame.area();
-        component.render(&mut frame, area, &runs, &repo_names, 0, &refreshing_repos);
+        component.render(&mut frame, area, &runs, &repo_names, 0, &refreshing_repos, None, &Theme::default());
+    }
+
+    #[test]
+    fn test_render_local_head() {
+        use crate::utils::local_git::PushState;
+
+        let mut component = create_test_workflow_list();
+        component.selected_repo_index = 1; // Keep the run unselected
+        let runs = create_test_workflow_runs();
+        let repo_names = vec!["test/repo".to_string()];
+        let refreshing_repos = Arc::new(RwLock::new(HashSet::new()));
+        let mut head = LocalHead {
+            host: None,
+            owner: "test".to_string(),
+            name: "repo".to_string(),
+            branch: Some("main".to_string()),
+            sha: "abc123".to_string(),
+            push_state: PushState::Pushed,
+        };
+
+        let render = |head: &LocalHead| {
+            let mut terminal = Terminal::new(TestBackend::new(80, 10)).unwrap();
+            terminal
+                .draw(|f| {
+                    let area = f.area();
+                    component.render(f, area, &runs, &repo_names, 30, &refreshing_repos, Some(head), &Theme::default());
+                })
+                .unwrap();
+            terminal.backend().buffer().content().iter().map(|cell| cell.symbol()).collect::<String>()
+        };
+
+        let content = render(&head);
+        assert!(content.contains("<- HEAD"));
+        assert!(!content.contains("no CI yet"));
+
+        head.sha = "def456".to_string();
+        let content = render(&head);
+        assert!(!content.contains("<- HEAD"));
+        assert!(content.contains("HEAD def456 on main: no CI yet for your last push"));
     }
 }
\ No newline at end of file
@@ -23,6 +23,18 @@ pub fn calculate_workflow_layout(area: Rect, repo_count: usize) -> Vec<Rect> {
         .to_vec()
 }
 
+/// Rect of at most `width` x `height` centered inside `area`, used for popups.
+pub fn centered_rect(area: Rect, width: u16, height: u16) -> Rect {
+    let width = width.min(area.width);
+    let height = height.min(area.height);
+    Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -200,4 +212,20 @@ mod tests {
         assert_eq!(total_height, area.height);
         assert!(status.height <= 3);
     }
+
+    #[test]
+    fn test_centered_rect() {
+        let area = Rect::new(0, 0, 80, 24);
+        let popup = centered_rect(area, 20, 6);
+
+        assert_eq!(popup, Rect::new(30, 9, 20, 6));
+    }
+
+    #[test]
+    fn test_centered_rect_larger_than_area() {
+        let area = Rect::new(2, 3, 10, 5);
+        let popup = centered_rect(area, 20, 6);
+
+        assert_eq!(popup, area);
+    }
 }
\ No newline at end of file
@@ -1,3 +1,5 @@
 pub mod app;
 pub mod components;
-pub mod layout;
\ No newline at end of file
+pub mod layout;
+pub mod refresh;
+pub mod theme;
\ No newline at end of file
@@ -0,0 +1,433 @@
+use crate::config::settings::Settings;
+use crate::error::AppError;
+use crate::github::client::GithubClient;
+use crate::github::models::{Job, PullRequestStatus, Repository, RunAction, RunHistoryPage, Workflow, WorkflowInput, WorkflowRun};
+use crate::github::pool::ClientPool;
+use crate::ui::components::log_viewer::LogSource;
+use crate::utils::logging::log_warn;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc::{self, UnboundedReceiver, UnboundedSender};
+use tokio::sync::{oneshot, Semaphore};
+
+/// Upper bound for a single repository refresh, retries included.
+const REPO_REFRESH_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Progress of a background refresh, sent from the refresh tasks to the UI loop.
+#[derive(Debug)]
+pub enum RefreshEvent {
+    /// The task got a request slot and is now waiting on GitHub.
+    Started { repo: String, id: u64 },
+    Finished { repo: String, id: u64, result: Result<Vec<WorkflowRun>, AppError> },
+}
+
+impl RefreshEvent {
+    pub fn repo(&self) -> &str {
+        match self {
+            RefreshEvent::Started { repo, .. } | RefreshEvent::Finished { repo, .. } => repo,
+        }
+    }
+
+    fn id(&self) -> u64 {
+        match self {
+            RefreshEvent::Started { id, .. } | RefreshEvent::Finished { id, .. } => *id,
+        }
+    }
+}
+
+/// Spawns one task per repository refresh so a slow repository never holds up the others
+/// or the render loop. Results come back as `RefreshEvent`s.
+pub struct Refresher {
+    sender: UnboundedSender<RefreshEvent>,
+    receiver: UnboundedReceiver<RefreshEvent>,
+    semaphore: Arc<Semaphore>,
+    next_id: u64,
+    /// Latest task spawned for each repository; events from older tasks are stale.
+    in_flight: HashMap<String, u64>,
+}
+
+impl Refresher {
+    pub fn new(max_concurrent_requests: usize) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Refresher {
+            sender,
+            receiver,
+            semaphore: Arc::new(Semaphore::new(max_concurrent_requests.max(1))),
+            next_id: 0,
+            in_flight: HashMap::new(),
+        }
+    }
+
+    pub fn is_in_flight(&self, repo_full_name: &str) -> bool {
+        self.in_flight.contains_key(repo_full_name)
+    }
+
+    pub fn in_flight_count(&self) -> usize {
+        self.in_flight.len()
+    }
+
+    /// Reserve the id of a new refresh of `repo_full_name`, superseding any running one.
+    pub fn register(&mut self, repo_full_name: &str) -> u64 {
+        self.next_id += 1;
+        self.in_flight.insert(repo_full_name.to_string(), self.next_id);
+        self.next_id
+    }
+
+    /// Start fetching the runs of `repo`. A refresh already running for the same repository
+    /// is superseded and its result dropped.
+    pub fn spawn(&mut self, github_client: &GithubClient, repo: &Repository) {
+        let id = self.register(&repo.full_name);
+
+        let github_client = github_client.clone();
+        let sender = self.sender.clone();
+        let semaphore = Arc::clone(&self.semaphore);
+        let (repo_name, owner, name) = (repo.full_name.clone(), repo.owner.clone(), repo.name.clone());
+
+        tokio::spawn(async move {
+            let Ok(_permit) = semaphore.acquire().await else {
+                return;
+            };
+            // The UI may have shut down; there is nobody left to tell
+            if sender.send(RefreshEvent::Started { repo: repo_name.clone(), id }).is_err() {
+                return;
+            }
+
+            let result = tokio::time::timeout(REPO_REFRESH_TIMEOUT, github_client.fetch_workflow_runs(&owner, &name))
+                .await
+                .unwrap_or_else(|_| Err(AppError::GithubError("Refresh operation timed out".to_string())));
+            let _ = sender.send(RefreshEvent::Finished { repo: repo_name, id, result });
+        });
+    }
+
+    /// Next event without waiting, if one is queued.
+    pub fn try_next(&mut self) -> Option<RefreshEvent> {
+        self.receiver.try_recv().ok()
+    }
+
+    /// Wait for the next event. Never returns `None` since the refresher keeps a sender.
+    pub async fn next(&mut self) -> Option<RefreshEvent> {
+        self.receiver.recv().await
+    }
+
+    /// Whether `event` comes from the latest task for its repository. Accepting a
+    /// `Finished` event ends the refresh of that repository.
+    pub fn accept(&mut self, event: &RefreshEvent) -> bool {
+        if self.in_flight.get(event.repo()) != Some(&event.id()) {
+            return false;
+        }
+        if matches!(event, RefreshEvent::Finished { .. }) {
+            self.in_flight.remove(event.repo());
+        }
+        true
+    }
+}
+
+/// A request made for a popup, identifying it so it is only sent once while it is running.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum LoadRequest {
+    Jobs { repo: String, run_id: u64 },
+    Logs { repo: String, source: LogSource },
+    HistoryPage { repo: String, page: u32 },
+    PullRequests { repo: String },
+    Workflows { repo: String },
+    DispatchInputs { repo: String, path: String, git_ref: String },
+    Dispatch { repo: String, workflow_id: u64, git_ref: String },
+    RunAction { repo: String, run_id: u64, action: RunAction },
+}
+
+/// Result of a popup request, sent from its task to the UI loop.
+#[derive(Debug)]
+pub enum LoadEvent {
+    Jobs { run_id: u64, result: Result<Vec<Job>, AppError> },
+    Logs { source: LogSource, result: Result<Vec<String>, AppError> },
+    HistoryPage { repo: String, page: u32, result: Result<RunHistoryPage, AppError> },
+    PullRequests { repo: String, result: Result<Vec<PullRequestStatus>, AppError> },
+    Workflows { repo: String, result: Result<Vec<Workflow>, AppError> },
+    DispatchInputs { repo: String, path: String, result: Result<Option<Vec<WorkflowInput>>, AppError> },
+    Dispatched { repo: String, workflow: String, git_ref: String, result: Result<(), AppError> },
+    RunAction { repo: String, run_id: u64, action: RunAction, result: Result<(), AppError> },
+}
+
+/// Runs the requests of the popups (jobs, logs, history, pull requests, dispatch and run
+/// actions) in background tasks, so the UI keeps drawing while they wait on GitHub.
+/// Results come back as `LoadEvent`s.
+pub struct Loader {
+    sender: UnboundedSender<(LoadRequest, LoadEvent)>,
+    receiver: UnboundedReceiver<(LoadRequest, LoadEvent)>,
+    in_flight: HashSet<LoadRequest>,
+}
+
+impl Default for Loader {
+    fn default() -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        Loader { sender, receiver, in_flight: HashSet::new() }
+    }
+}
+
+impl Loader {
+    pub fn is_in_flight(&self, request: &LoadRequest) -> bool {
+        self.in_flight.contains(request)
+    }
+
+    /// Run `task` for `request`, unless the same request is still running. Returns whether
+    /// it was started.
+    pub fn spawn<F>(&mut self, request: LoadRequest, task: F) -> bool
+    where
+        F: Future<Output = LoadEvent> + Send + 'static,
+    {
+        if !self.in_flight.insert(request.clone()) {
+            return false;
+        }
+        let sender = self.sender.clone();
+        tokio::spawn(async move {
+            let event = task.await;
+            let _ = sender.send((request, event));
+        });
+        true
+    }
+
+    /// Next result without waiting, if one is queued.
+    pub fn try_next(&mut self) -> Option<LoadEvent> {
+        let (request, event) = self.receiver.try_recv().ok()?;
+        self.in_flight.remove(&request);
+        Some(event)
+    }
+
+    /// Wait for the next result. Never returns `None` since the loader keeps a sender.
+    pub async fn next(&mut self) -> Option<LoadEvent> {
+        let (request, event) = self.receiver.recv().await?;
+        self.in_flight.remove(&request);
+        Some(event)
+    }
+}
+
+/// Expands the discovery patterns again in the background, so repositories created after
+/// startup are picked up and deleted or archived ones dropped.
+pub struct DiscoveryTask {
+    last_run: DateTime<Utc>,
+    pending: Option<oneshot::Receiver<Result<Vec<Repository>, AppError>>>,
+}
+
+impl Default for DiscoveryTask {
+    /// The patterns were just expanded by `AppState::new`.
+    fn default() -> Self {
+        DiscoveryTask { last_run: Utc::now(), pending: None }
+    }
+}
+
+impl DiscoveryTask {
+    pub fn is_due(&self, settings: &Settings, now: DateTime<Utc>) -> bool {
+        let interval_minutes = settings.discovery.refresh_interval_minutes;
+        !settings.discovery.patterns.is_empty()
+            && interval_minutes > 0
+            && self.pending.is_none()
+            && now - self.last_run >= chrono::Duration::minutes(i64::try_from(interval_minutes).unwrap_or(i64::MAX / 60_000))
+    }
+
+    /// Resolve the repositories again, reusing the details of `known` ones and fetching those
+    /// of new ones.
+    pub fn spawn(&mut self, github_clients: &ClientPool, settings: &Settings, known: Vec<Repository>) {
+        let (sender, receiver) = oneshot::channel();
+        let (github_clients, settings) = (github_clients.clone(), settings.clone());
+        self.last_run = Utc::now();
+        self.pending = Some(receiver);
+
+        tokio::spawn(async move {
+            let result = async {
+                let mut repositories = Vec::new();
+                for config in github_clients.resolve_repositories(&settings).await? {
+                    let existing = known.iter().find(|repo| {
+                        repo.host == config.host
+                            && repo.owner.eq_ignore_ascii_case(&config.owner)
+                            && repo.name.eq_ignore_ascii_case(&config.name)
+                    });
+                    match existing {
+                        Some(repo) => repositories.push(repo.clone()),
+                        // A new repository that cannot be read is tried again by the next discovery
+                        None => match github_clients.fetch_repositories(std::slice::from_ref(&config)).await {
+                            Ok(found) => repositories.extend(found),
+                            Err(e) => log_warn(format!("Skipping discovered repository {}/{}: {}", config.owner, config.name, e)),
+                        },
+                    }
+                }
+                Ok(repositories)
+            };
+            let _ = sender.send(result.await);
+        });
+    }
+
+    /// Result of the last `spawn`, once it has finished.
+    pub fn try_result(&mut self) -> Option<Result<Vec<Repository>, AppError>> {
+        let result = match self.pending.as_mut()?.try_recv() {
+            Ok(result) => result,
+            Err(oneshot::error::TryRecvError::Empty) => return None,
+            Err(oneshot::error::TryRecvError::Closed) => Err(AppError::GithubError("Repository discovery was aborted".to_string())),
+        };
+        self.pending = None;
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::settings::Settings;
+    use crate::github::client::tests::MockGitHubApiClient;
+    use crate::github::client::Conditional;
+
+    /// Answers run listings, the ones of `owner/slow` only after a delay.
+    fn create_delayed_client() -> GithubClient {
+        let mut mock_client = MockGitHubApiClient::new();
+        // One expectation per repository, since mockall serializes the calls of an expectation
+        mock_client
+            .expect_get_workflow_runs()
+            .withf(|route, _| route.contains("/slow/"))
+            .returning(|_, _| {
+                // Blocks its worker thread; the other repository is served by the other one
+                std::thread::sleep(Duration::from_millis(200));
+                Ok(Conditional::Modified { value: serde_json::from_str(r#"{"workflow_runs": []}"#).unwrap(), etag: None })
+            });
+        mock_client
+            .expect_get_workflow_runs()
+            .withf(|route, _| !route.contains("/slow/"))
+            .returning(|_, _| Ok(Conditional::Modified { value: serde_json::from_str(r#"{"workflow_runs": []}"#).unwrap(), etag: None }));
+        GithubClient::new_with_client(Settings::default(), Box::new(mock_client))
+    }
+
+    fn create_test_repo(owner: &str, name: &str) -> Repository {
+        Repository {
+            id: 0,
+            name: name.to_string(),
+            owner: owner.to_string(),
+            full_name: format!("{}/{}", owner, name),
+            html_url: format!("https://github.com/{}/{}", owner, name),
+            default_branch: Some("main".to_string()),
+            host: None,
+            credential: None,
+        }
+    }
+
+    fn finished(repo: &str, id: u64) -> RefreshEvent {
+        RefreshEvent::Finished { repo: repo.to_string(), id, result: Ok(vec![]) }
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+    async fn test_slow_repository_does_not_block_others() {
+        let client = create_delayed_client();
+        let mut refresher = Refresher::new(5);
+
+        refresher.spawn(&client, &create_test_repo("owner", "slow"));
+        refresher.spawn(&client, &create_test_repo("owner", "fast"));
+        assert_eq!(refresher.in_flight_count(), 2);
+
+        let mut finished_order = vec![];
+        while finished_order.len() < 2 {
+            let event = refresher.next().await.unwrap();
+            assert!(refresher.accept(&event));
+            if let RefreshEvent::Finished { repo, result, .. } = event {
+                assert!(result.is_ok());
+                finished_order.push(repo);
+            }
+        }
+
+        assert_eq!(finished_order, vec!["owner/fast", "owner/slow"]);
+        assert_eq!(refresher.in_flight_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_accept_drops_superseded_events() {
+        let mut refresher = Refresher::new(1);
+        assert_eq!(refresher.register("owner/repo"), 1);
+        assert_eq!(refresher.register("owner/repo"), 2);
+
+        assert!(!refresher.accept(&finished("owner/repo", 1)));
+        assert!(refresher.is_in_flight("owner/repo"));
+
+        assert!(refresher.accept(&RefreshEvent::Started { repo: "owner/repo".to_string(), id: 2 }));
+        assert!(refresher.is_in_flight("owner/repo"));
+
+        assert!(refresher.accept(&finished("owner/repo", 2)));
+        assert!(!refresher.is_in_flight("owner/repo"));
+        assert!(!refresher.accept(&finished("owner/other", 3)));
+    }
+
+    #[tokio::test]
+    async fn test_loader_sends_each_request_once() {
+        let mut loader = Loader::default();
+        let request = LoadRequest::Jobs { repo: "owner/repo".to_string(), run_id: 1 };
+        let (release, wait) = oneshot::channel::<()>();
+
+        assert!(loader.spawn(request.clone(), async move {
+            let _ = wait.await;
+            LoadEvent::Jobs { run_id: 1, result: Ok(vec![]) }
+        }));
+        assert!(!loader.spawn(request.clone(), async { unreachable!() }));
+        assert!(loader.is_in_flight(&request));
+        assert!(loader.try_next().is_none());
+
+        release.send(()).unwrap();
+        assert!(matches!(loader.next().await, Some(LoadEvent::Jobs { run_id: 1, result: Ok(_) })));
+        assert!(!loader.is_in_flight(&request));
+    }
+
+    #[tokio::test]
+    async fn test_discovery_skips_unreadable_new_repositories() {
+        let mut mock_client = MockGitHubApiClient::new();
+        mock_client.expect_get_repository().returning(|route| match route {
+            "/repos/acme/gone" => Err(AppError::GithubError("Not Found".to_string())),
+            _ => Ok(serde_json::from_value(serde_json::json!({
+                "id": 1,
+                "name": "api",
+                "full_name": "acme/api",
+                "html_url": "https://github.com/acme/api",
+                "default_branch": "main",
+                "owner": { "login": "acme" },
+            }))
+            .unwrap()),
+        });
+        let config = |name: &str| crate::config::settings::RepositoryConfig {
+            host: None,
+            credential: None,
+            owner: "acme".to_string(),
+            name: name.to_string(),
+            branch: None,
+            workflows: None,
+            enabled: true,
+            min_refresh_seconds: None,
+            max_refresh_seconds: None,
+            history_runs: None,
+        };
+        let mut settings = Settings::default();
+        settings.set_repositories(vec![config("gone"), config("api")]);
+        let pool = ClientPool::from_clients(vec![GithubClient::new_with_client(settings.clone(), Box::new(mock_client))]);
+
+        let mut discovery = DiscoveryTask::default();
+        discovery.spawn(&pool, &settings, vec![]);
+        let repositories = loop {
+            match discovery.try_result() {
+                Some(result) => break result.unwrap(),
+                None => tokio::time::sleep(Duration::from_millis(10)).await,
+            }
+        };
+
+        assert_eq!(repositories.iter().map(|repo| repo.full_name.as_str()).collect::<Vec<_>>(), vec!["acme/api"]);
+    }
+
+    #[test]
+    fn test_discovery_is_due() {
+        let mut settings = Settings::default();
+        let discovery = DiscoveryTask::default();
+        let later = Utc::now() + chrono::Duration::minutes(61);
+        assert!(!discovery.is_due(&settings, later));
+
+        settings.discovery.patterns = vec![crate::config::discovery::RepositoryPattern::parse("org:acme").unwrap()];
+        assert!(!discovery.is_due(&settings, Utc::now()));
+        assert!(discovery.is_due(&settings, later));
+
+        settings.discovery.refresh_interval_minutes = 0;
+        assert!(!discovery.is_due(&settings, later));
+    }
+}
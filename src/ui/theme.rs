@@ -0,0 +1,204 @@
+use crate::config::settings::UiConfig;
+use crate::error::AppError;
+use crate::utils::icons::Icons;
+use ratatui::style::{Color, Style};
+use std::str::FromStr;
+
+/// Colors and icons every component renders with, resolved once from `ui` settings.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Theme {
+    pub success: Color,
+    pub error: Color,
+    pub warning: Color,
+    pub info: Color,
+    /// Emphasized text such as form labels and menu entries.
+    pub text: Color,
+    /// Regular list entries and hints.
+    pub muted: Color,
+    pub selected_fg: Color,
+    pub selected_bg: Color,
+    pub icons: Icons,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+/// Parse a color name (`light-red`), hex value (`#859900`) or 256-color index (`28`).
+pub fn parse_color(value: &str) -> Option<Color> {
+    Color::from_str(value.trim()).ok()
+}
+
+impl Theme {
+    pub const NAMES: [&'static str; 4] = ["dark", "light", "high-contrast", "solarized"];
+
+    pub fn dark() -> Self {
+        Theme {
+            success: Color::Green,
+            error: Color::Red,
+            warning: Color::Yellow,
+            info: Color::Blue,
+            text: Color::White,
+            muted: Color::Gray,
+            selected_fg: Color::White,
+            selected_bg: Color::DarkGray,
+            icons: Icons::default(),
+        }
+    }
+
+    /// Darker tones from the 256-color palette that stay readable on white backgrounds.
+    pub fn light() -> Self {
+        Theme {
+            success: Color::Indexed(28),
+            error: Color::Indexed(160),
+            warning: Color::Indexed(130),
+            info: Color::Indexed(25),
+            text: Color::Black,
+            muted: Color::Indexed(238),
+            selected_fg: Color::Black,
+            selected_bg: Color::Indexed(252),
+            icons: Icons::default(),
+        }
+    }
+
+    pub fn high_contrast() -> Self {
+        Theme {
+            success: Color::LightGreen,
+            error: Color::LightRed,
+            warning: Color::LightYellow,
+            info: Color::LightCyan,
+            text: Color::White,
+            muted: Color::White,
+            selected_fg: Color::Black,
+            selected_bg: Color::White,
+            icons: Icons::default(),
+        }
+    }
+
+    pub fn solarized() -> Self {
+        Theme {
+            success: Color::Rgb(0x85, 0x99, 0x00),
+            error: Color::Rgb(0xdc, 0x32, 0x2f),
+            warning: Color::Rgb(0xb5, 0x89, 0x00),
+            info: Color::Rgb(0x26, 0x8b, 0xd2),
+            text: Color::Rgb(0x93, 0xa1, 0xa1),
+            muted: Color::Rgb(0x83, 0x94, 0x96),
+            selected_fg: Color::Rgb(0xee, 0xe8, 0xd5),
+            selected_bg: Color::Rgb(0x07, 0x36, 0x42),
+            icons: Icons::default(),
+        }
+    }
+
+    pub fn builtin(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().replace('_', "-").as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            "solarized" => Some(Self::solarized()),
+            _ => None,
+        }
+    }
+
+    /// Start from the named built-in theme and apply the configured colors and icons on top.
+    pub fn from_config(config: &UiConfig) -> Result<Self, AppError> {
+        let theme_config = &config.theme;
+        let mut theme = Self::builtin(&theme_config.name).ok_or_else(|| {
+            AppError::ConfigError(format!(
+                "Unknown theme '{}' for ui.theme.name, expected one of: {}",
+                theme_config.name,
+                Self::NAMES.join(", ")
+            ))
+        })?;
+
+        let overrides = [
+            ("success_color", &theme_config.success_color, &mut theme.success),
+            ("error_color", &theme_config.error_color, &mut theme.error),
+            ("warning_color", &theme_config.warning_color, &mut theme.warning),
+            ("info_color", &theme_config.info_color, &mut theme.info),
+        ];
+        for (key, configured, color) in overrides {
+            if let Some(configured) = configured {
+                *color = parse_color(configured).ok_or_else(|| {
+                    AppError::ConfigError(format!(
+                        "Invalid color '{}' for ui.theme.{}, expected a color name, #rrggbb or 0-255",
+                        configured, key
+                    ))
+                })?;
+            }
+        }
+
+        theme.icons = Icons::from_config(&config.icons)?;
+        Ok(theme)
+    }
+
+    pub fn selected(&self) -> Style {
+        Style::default().fg(self.selected_fg).bg(self.selected_bg)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::settings::ThemeConfig;
+
+    #[test]
+    fn test_parse_color_formats() {
+        assert_eq!(parse_color("green"), Some(Color::Green));
+        assert_eq!(parse_color(" Light-Red "), Some(Color::LightRed));
+        assert_eq!(parse_color("grey"), Some(Color::Gray));
+        assert_eq!(parse_color("#859900"), Some(Color::Rgb(0x85, 0x99, 0x00)));
+        assert_eq!(parse_color("208"), Some(Color::Indexed(208)));
+        assert_eq!(parse_color("256"), None);
+        assert_eq!(parse_color("#85990"), None);
+        assert_eq!(parse_color("chartreuse"), None);
+    }
+
+    #[test]
+    fn test_builtin_themes() {
+        for name in Theme::NAMES {
+            assert!(Theme::builtin(name).is_some(), "missing built-in theme {}", name);
+        }
+        assert_eq!(Theme::builtin("High_Contrast"), Some(Theme::high_contrast()));
+        assert_eq!(Theme::builtin("neon"), None);
+    }
+
+    #[test]
+    fn test_default_config_matches_dark_theme() {
+        let theme = Theme::from_config(&UiConfig::default()).unwrap();
+        assert_eq!(theme, Theme::dark());
+    }
+
+    #[test]
+    fn test_from_config_overrides_colors() {
+        let config = UiConfig {
+            theme: ThemeConfig {
+                name: "solarized".to_string(),
+                error_color: Some("magenta".to_string()),
+                info_color: Some("33".to_string()),
+                ..ThemeConfig::default()
+            },
+            ..UiConfig::default()
+        };
+
+        let theme = Theme::from_config(&config).unwrap();
+
+        assert_eq!(theme.error, Color::Magenta);
+        assert_eq!(theme.info, Color::Indexed(33));
+        assert_eq!(theme.success, Theme::solarized().success);
+    }
+
+    #[test]
+    fn test_from_config_reports_invalid_values() {
+        let mut config = UiConfig::default();
+        config.theme.warning_color = Some("orangeish".to_string());
+        let error = Theme::from_config(&config).unwrap_err().to_string();
+        assert!(error.contains("Invalid color 'orangeish' for ui.theme.warning_color"));
+
+        let mut config = UiConfig::default();
+        config.theme.name = "neon".to_string();
+        let error = Theme::from_config(&config).unwrap_err().to_string();
+        assert!(error.contains("Unknown theme 'neon' for ui.theme.name"));
+    }
+}
@@ -1,21 +1,116 @@
+use crate::config::settings::IconConfig;
+use crate::error::AppError;
 use crate::github::models::{WorkflowStatus, WorkflowConclusion};
 
-pub fn get_status_icon(status: &WorkflowStatus) -> &'static str {
-    match status {
-        WorkflowStatus::Queued => "⏳",
-        WorkflowStatus::InProgress => "🔄",
-        WorkflowStatus::Completed => "✅",  // Default, conclusion handled separately
+/// Glyphs used to show run, job and step states, resolved from `ui.icons`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Icons {
+    pub queued: String,
+    pub running: String,
+    pub success: String,
+    pub failure: String,
+    pub cancelled: String,
+    pub skipped: String,
+    pub timed_out: String,
+    pub unknown: String,
+    pub spinner: &'static [&'static str],
+}
+
+const BRAILLE_SPINNER: &[&str] = &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"];
+
+impl Default for Icons {
+    fn default() -> Self {
+        Self::emoji()
     }
 }
 
-pub fn get_conclusion_icon(conclusion: &Option<WorkflowConclusion>) -> &'static str {
-    match conclusion {
-        Some(WorkflowConclusion::Success) => "✅",
-        Some(WorkflowConclusion::Failure) => "❌",
-        Some(WorkflowConclusion::Cancelled) => "⏹️",
-        Some(WorkflowConclusion::Skipped) => "⏭️",
-        Some(WorkflowConclusion::TimedOut) => "⏰",
-        None => "?",
+impl Icons {
+    pub const SETS: [&'static str; 3] = ["emoji", "unicode", "ascii"];
+
+    fn from_glyphs(glyphs: [&str; 8], spinner: &'static [&'static str]) -> Self {
+        let [queued, running, success, failure, cancelled, skipped, timed_out, unknown] = glyphs.map(str::to_string);
+        Icons { queued, running, success, failure, cancelled, skipped, timed_out, unknown, spinner }
+    }
+
+    pub fn emoji() -> Self {
+        Self::from_glyphs(["⏳", "🔄", "✅", "❌", "⏹️", "⏭️", "⏰", "?"], BRAILLE_SPINNER)
+    }
+
+    pub fn unicode() -> Self {
+        Self::from_glyphs(["⏸", "⟳", "✓", "✗", "⊘", "»", "⧗", "?"], BRAILLE_SPINNER)
+    }
+
+    /// Single-column glyphs for terminals that draw emoji or symbols at the wrong width.
+    pub fn ascii() -> Self {
+        Self::from_glyphs(["-", "~", "+", "x", "/", ">", "!", "?"], &["|", "/", "-", "\\"])
+    }
+
+    pub fn builtin(set: &str) -> Option<Self> {
+        match set.trim().to_lowercase().as_str() {
+            "emoji" => Some(Self::emoji()),
+            "unicode" => Some(Self::unicode()),
+            "ascii" => Some(Self::ascii()),
+            _ => None,
+        }
+    }
+
+    /// Start from the configured glyph set and apply the individual icon overrides.
+    pub fn from_config(config: &IconConfig) -> Result<Self, AppError> {
+        let mut icons = Self::builtin(&config.set).ok_or_else(|| {
+            AppError::ConfigError(format!(
+                "Unknown icon set '{}' for ui.icons.set, expected one of: {}",
+                config.set,
+                Self::SETS.join(", ")
+            ))
+        })?;
+
+        let overrides = [
+            (&config.queued_icon, &mut icons.queued),
+            (&config.running_icon, &mut icons.running),
+            (&config.success_icon, &mut icons.success),
+            (&config.error_icon, &mut icons.failure),
+            (&config.cancelled_icon, &mut icons.cancelled),
+            (&config.skipped_icon, &mut icons.skipped),
+            (&config.timed_out_icon, &mut icons.timed_out),
+        ];
+        for (configured, icon) in overrides {
+            if let Some(configured) = configured {
+                *icon = configured.clone();
+            }
+        }
+
+        Ok(icons)
+    }
+
+    pub fn status(&self, status: &WorkflowStatus) -> &str {
+        match status {
+            WorkflowStatus::Queued => &self.queued,
+            WorkflowStatus::InProgress => &self.running,
+            WorkflowStatus::Completed => &self.success,  // Default, conclusion handled separately
+        }
+    }
+
+    pub fn conclusion(&self, conclusion: &Option<WorkflowConclusion>) -> &str {
+        match conclusion {
+            Some(WorkflowConclusion::Success) => &self.success,
+            Some(WorkflowConclusion::Failure) => &self.failure,
+            Some(WorkflowConclusion::Cancelled) => &self.cancelled,
+            Some(WorkflowConclusion::Skipped) => &self.skipped,
+            Some(WorkflowConclusion::TimedOut) => &self.timed_out,
+            None => &self.unknown,
+        }
+    }
+
+    /// Single icon for a job or step: the conclusion once completed, the status before that.
+    pub fn state(&self, status: &WorkflowStatus, conclusion: &Option<WorkflowConclusion>) -> &str {
+        match status {
+            WorkflowStatus::Completed => self.conclusion(conclusion),
+            _ => self.status(status),
+        }
+    }
+
+    pub fn spinner_frame(&self, tick: usize) -> &str {
+        self.spinner[tick % self.spinner.len()]
     }
 }
 
@@ -42,55 +137,64 @@ mod tests {
 
     #[test]
     fn test_get_status_icon_queued() {
-        let icon = get_status_icon(&WorkflowStatus::Queued);
+        let icons = Icons::emoji();
+        let icon = icons.status(&WorkflowStatus::Queued);
         assert_eq!(icon, "⏳");
     }
 
     #[test]
     fn test_get_status_icon_in_progress() {
-        let icon = get_status_icon(&WorkflowStatus::InProgress);
+        let icons = Icons::emoji();
+        let icon = icons.status(&WorkflowStatus::InProgress);
         assert_eq!(icon, "🔄");
     }
 
     #[test]
     fn test_get_status_icon_completed() {
-        let icon = get_status_icon(&WorkflowStatus::Completed);
+        let icons = Icons::emoji();
+        let icon = icons.status(&WorkflowStatus::Completed);
         assert_eq!(icon, "✅");
     }
 
     #[test]
     fn test_get_conclusion_icon_success() {
-        let icon = get_conclusion_icon(&Some(WorkflowConclusion::Success));
+        let icons = Icons::emoji();
+        let icon = icons.conclusion(&Some(WorkflowConclusion::Success));
         assert_eq!(icon, "✅");
     }
 
     #[test]
     fn test_get_conclusion_icon_failure() {
-        let icon = get_conclusion_icon(&Some(WorkflowConclusion::Failure));
+        let icons = Icons::emoji();
+        let icon = icons.conclusion(&Some(WorkflowConclusion::Failure));
         assert_eq!(icon, "❌");
     }
 
     #[test]
     fn test_get_conclusion_icon_cancelled() {
-        let icon = get_conclusion_icon(&Some(WorkflowConclusion::Cancelled));
+        let icons = Icons::emoji();
+        let icon = icons.conclusion(&Some(WorkflowConclusion::Cancelled));
         assert_eq!(icon, "⏹️");
     }
 
     #[test]
     fn test_get_conclusion_icon_skipped() {
-        let icon = get_conclusion_icon(&Some(WorkflowConclusion::Skipped));
+        let icons = Icons::emoji();
+        let icon = icons.conclusion(&Some(WorkflowConclusion::Skipped));
         assert_eq!(icon, "⏭️");
     }
 
     #[test]
     fn test_get_conclusion_icon_timed_out() {
-        let icon = get_conclusion_icon(&Some(WorkflowConclusion::TimedOut));
+        let icons = Icons::emoji();
+        let icon = icons.conclusion(&Some(WorkflowConclusion::TimedOut));
         assert_eq!(icon, "⏰");
     }
 
     #[test]
     fn test_get_conclusion_icon_none() {
-        let icon = get_conclusion_icon(&None);
+        let icons = Icons::emoji();
+        let icon = icons.conclusion(&None);
         assert_eq!(icon, "?");
     }
 
@@ -126,9 +230,10 @@ mod tests {
 
     #[test]
     fn test_all_status_icons_unique() {
-        let queued_icon = get_status_icon(&WorkflowStatus::Queued);
-        let in_progress_icon = get_status_icon(&WorkflowStatus::InProgress);
-        let completed_icon = get_status_icon(&WorkflowStatus::Completed);
+        let icons = Icons::emoji();
+        let queued_icon = icons.status(&WorkflowStatus::Queued);
+        let in_progress_icon = icons.status(&WorkflowStatus::InProgress);
+        let completed_icon = icons.status(&WorkflowStatus::Completed);
         
         assert_ne!(queued_icon, in_progress_icon);
         assert_ne!(in_progress_icon, completed_icon);
@@ -137,15 +242,16 @@ mod tests {
 
     #[test]
     fn test_all_conclusion_icons_unique() {
-        let success_icon = get_conclusion_icon(&Some(WorkflowConclusion::Success));
-        let failure_icon = get_conclusion_icon(&Some(WorkflowConclusion::Failure));
-        let cancelled_icon = get_conclusion_icon(&Some(WorkflowConclusion::Cancelled));
-        let skipped_icon = get_conclusion_icon(&Some(WorkflowConclusion::Skipped));
-        let timed_out_icon = get_conclusion_icon(&Some(WorkflowConclusion::TimedOut));
-        let none_icon = get_conclusion_icon(&None);
+        let icons = Icons::emoji();
+        let success_icon = icons.conclusion(&Some(WorkflowConclusion::Success));
+        let failure_icon = icons.conclusion(&Some(WorkflowConclusion::Failure));
+        let cancelled_icon = icons.conclusion(&Some(WorkflowConclusion::Cancelled));
+        let skipped_icon = icons.conclusion(&Some(WorkflowConclusion::Skipped));
+        let timed_out_icon = icons.conclusion(&Some(WorkflowConclusion::TimedOut));
+        let none_icon = icons.conclusion(&None);
         
         // Ensure all icons are different
-        let icons = vec![success_icon, failure_icon, cancelled_icon, skipped_icon, timed_out_icon, none_icon];
+        let icons = [success_icon, failure_icon, cancelled_icon, skipped_icon, timed_out_icon, none_icon];
         for (i, icon1) in icons.iter().enumerate() {
             for (j, icon2) in icons.iter().enumerate() {
                 if i != j {
@@ -157,9 +263,10 @@ mod tests {
 
     #[test]
     fn test_status_icons_non_empty() {
-        let queued_icon = get_status_icon(&WorkflowStatus::Queued);
-        let in_progress_icon = get_status_icon(&WorkflowStatus::InProgress);
-        let completed_icon = get_status_icon(&WorkflowStatus::Completed);
+        let icons = Icons::emoji();
+        let queued_icon = icons.status(&WorkflowStatus::Queued);
+        let in_progress_icon = icons.status(&WorkflowStatus::InProgress);
+        let completed_icon = icons.status(&WorkflowStatus::Completed);
         
         assert!(!queued_icon.is_empty());
         assert!(!in_progress_icon.is_empty());
@@ -168,12 +275,13 @@ mod tests {
 
     #[test]
     fn test_conclusion_icons_non_empty() {
-        let success_icon = get_conclusion_icon(&Some(WorkflowConclusion::Success));
-        let failure_icon = get_conclusion_icon(&Some(WorkflowConclusion::Failure));
-        let cancelled_icon = get_conclusion_icon(&Some(WorkflowConclusion::Cancelled));
-        let skipped_icon = get_conclusion_icon(&Some(WorkflowConclusion::Skipped));
-        let timed_out_icon = get_conclusion_icon(&Some(WorkflowConclusion::TimedOut));
-        let none_icon = get_conclusion_icon(&None);
+        let icons = Icons::emoji();
+        let success_icon = icons.conclusion(&Some(WorkflowConclusion::Success));
+        let failure_icon = icons.conclusion(&Some(WorkflowConclusion::Failure));
+        let cancelled_icon = icons.conclusion(&Some(WorkflowConclusion::Cancelled));
+        let skipped_icon = icons.conclusion(&Some(WorkflowConclusion::Skipped));
+        let timed_out_icon = icons.conclusion(&Some(WorkflowConclusion::TimedOut));
+        let none_icon = icons.conclusion(&None);
         
         assert!(!success_icon.is_empty());
         assert!(!failure_icon.is_empty());
@@ -204,4 +312,39 @@ mod tests {
             assert_eq!(text, expected_text, "Failed for status: {:?}, conclusion: {:?}", status, conclusion);
         }
     }
+
+    #[test]
+    fn test_ascii_icons_are_single_column() {
+        let icons = Icons::ascii();
+        for icon in [&icons.queued, &icons.running, &icons.success, &icons.failure, &icons.cancelled, &icons.skipped, &icons.timed_out, &icons.unknown] {
+            assert!(icon.is_ascii() && icon.len() == 1, "{:?} is not a single ASCII character", icon);
+        }
+        assert_eq!(icons.spinner_frame(5), "/");
+    }
+
+    #[test]
+    fn test_icons_from_config_overrides() {
+        let config = IconConfig {
+            set: "ASCII".to_string(),
+            error_icon: Some("F".to_string()),
+            ..IconConfig::default()
+        };
+
+        let icons = Icons::from_config(&config).unwrap();
+
+        assert_eq!(icons.conclusion(&Some(WorkflowConclusion::Failure)), "F");
+        assert_eq!(icons.conclusion(&Some(WorkflowConclusion::Success)), "+");
+        assert_eq!(icons.state(&WorkflowStatus::InProgress, &None), "~");
+    }
+
+    #[test]
+    fn test_icons_from_config_unknown_set() {
+        let config = IconConfig {
+            set: "nerdfont".to_string(),
+            ..IconConfig::default()
+        };
+
+        let error = Icons::from_config(&config).unwrap_err().to_string();
+        assert!(error.contains("Unknown icon set 'nerdfont' for ui.icons.set"));
+    }
 }
@@ -0,0 +1,243 @@
+use crate::config::settings::{normalize_host, parse_remote_url};
+use crate::github::models::WorkflowRun;
+use git2::{BranchType, Oid, Repository};
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// How often the checkout is read again to notice commits and pushes.
+const CHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Whether the local HEAD commit has reached the remote.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PushState {
+    /// HEAD is on its branch's upstream, or on a remote branch when there is no upstream.
+    Pushed,
+    /// Commits the upstream does not have yet.
+    Ahead(usize),
+    /// No remote branch contains HEAD and the branch has no upstream.
+    NotPushed,
+}
+
+/// HEAD of the git checkout nighthub was started in, and the GitHub repository its `origin`
+/// remote points at.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LocalHead {
+    /// Host as in `RepositoryConfig`, `None` for github.com.
+    pub host: Option<String>,
+    pub owner: String,
+    pub name: String,
+    /// Checked out branch, `None` when HEAD is detached.
+    pub branch: Option<String>,
+    pub sha: String,
+    pub push_state: PushState,
+}
+
+impl LocalHead {
+    /// Read HEAD of the repository containing `path`. `None` outside a git repository, before
+    /// the first commit, or when neither `origin` nor `upstream` is a GitHub-style remote.
+    pub fn detect(path: &Path) -> Option<LocalHead> {
+        let repo = Repository::discover(path).ok()?;
+        let remote = repo.find_remote("origin").or_else(|_| repo.find_remote("upstream")).ok()?;
+        let (host, owner, name) = parse_remote_url(remote.url()?)?;
+
+        let head = repo.head().ok()?;
+        let oid = head.peel_to_commit().ok()?.id();
+        let branch = if head.is_branch() { head.shorthand().map(str::to_string) } else { None };
+        let push_state = push_state(&repo, branch.as_deref(), oid);
+
+        Some(LocalHead {
+            host: normalize_host(&host),
+            owner,
+            name,
+            branch,
+            sha: oid.to_string(),
+            push_state,
+        })
+    }
+
+    /// Whether `repo_full_name`, as shown in the UI, is the repository of this checkout.
+    pub fn is_repository(&self, repo_full_name: &str) -> bool {
+        let full_name = match &self.host {
+            Some(host) => format!("{}/{}/{}", host, self.owner, self.name),
+            None => format!("{}/{}", self.owner, self.name),
+        };
+        full_name.eq_ignore_ascii_case(repo_full_name)
+    }
+
+    pub fn short_sha(&self) -> &str {
+        &self.sha[..self.sha.len().min(7)]
+    }
+
+    /// What to show below the repository's `runs` about HEAD; `None` once a run for it exists.
+    pub fn status_line(&self, runs: &[WorkflowRun]) -> Option<String> {
+        let head = match &self.branch {
+            Some(branch) => format!("HEAD {} on {}", self.short_sha(), branch),
+            None => format!("HEAD {} (detached)", self.short_sha()),
+        };
+        match self.push_state {
+            PushState::NotPushed => Some(format!("{}: not pushed", head)),
+            PushState::Ahead(1) => Some(format!("{}: 1 commit not pushed", head)),
+            PushState::Ahead(commits) => Some(format!("{}: {} commits not pushed", head, commits)),
+            PushState::Pushed if runs.iter().any(|run| run.commit_sha == self.sha) => None,
+            PushState::Pushed => Some(format!("{}: no CI yet for your last push", head)),
+        }
+    }
+}
+
+fn push_state(repo: &Repository, branch: Option<&str>, head: Oid) -> PushState {
+    if let Some(branch) = branch
+        && let Ok(upstream) = repo.find_branch(branch, BranchType::Local).and_then(|branch| branch.upstream())
+        && let Some(upstream) = upstream.get().target()
+    {
+        return match repo.graph_ahead_behind(head, upstream) {
+            Ok((0, _)) => PushState::Pushed,
+            Ok((ahead, _)) => PushState::Ahead(ahead),
+            Err(_) => PushState::NotPushed,
+        };
+    }
+
+    // Without an upstream, HEAD counts as pushed once any remote branch contains it
+    let on_remote = repo
+        .branches(Some(BranchType::Remote))
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter_map(|(branch, _)| branch.get().target())
+        .any(|remote| remote == head || repo.graph_descendant_of(remote, head).unwrap_or(false));
+    if on_remote { PushState::Pushed } else { PushState::NotPushed }
+}
+
+/// Full SHA of the commit `revision`, like `HEAD` or a branch name, points at in the
+/// repository containing `path`.
+pub fn resolve_revision(path: &Path, revision: &str) -> Option<String> {
+    let repo = Repository::discover(path).ok()?;
+    let commit = repo.revparse_single(revision).ok()?.peel_to_commit().ok()?;
+    Some(commit.id().to_string())
+}
+
+/// The local checkout's HEAD, read again every few seconds while nighthub runs.
+#[derive(Debug, Default)]
+pub struct LocalCheckout {
+    pub head: Option<LocalHead>,
+    checked_at: Option<Instant>,
+}
+
+impl LocalCheckout {
+    pub fn detect() -> Self {
+        LocalCheckout {
+            head: LocalHead::detect(Path::new(".")),
+            checked_at: Some(Instant::now()),
+        }
+    }
+
+    /// Read HEAD again if the check interval has passed. Returns whether it moved or was
+    /// pushed since the last check.
+    pub fn poll(&mut self) -> bool {
+        if self.checked_at.is_some_and(|checked_at| checked_at.elapsed() < CHECK_INTERVAL) {
+            return false;
+        }
+        self.checked_at = Some(Instant::now());
+        let head = LocalHead::detect(Path::new("."));
+        let changed = head != self.head;
+        self.head = head;
+        changed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::github::models::WorkflowStatus;
+    use chrono::Utc;
+    use git2::{RepositoryInitOptions, Signature};
+
+    fn commit(repo: &Repository, message: &str) -> Oid {
+        let signature = Signature::now("Test", "test@example.com").unwrap();
+        let tree_id = repo.index().unwrap().write_tree().unwrap();
+        let tree = repo.find_tree(tree_id).unwrap();
+        let parent = repo.head().ok().and_then(|head| head.peel_to_commit().ok());
+        let parents: Vec<&git2::Commit> = parent.iter().collect();
+        repo.commit(Some("HEAD"), &signature, &signature, message, &tree, &parents).unwrap()
+    }
+
+    fn create_test_run(sha: &str) -> WorkflowRun {
+        WorkflowRun {
+            id: 1,
+            name: "CI".to_string(),
+            status: WorkflowStatus::Queued,
+            conclusion: None,
+            run_attempt: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+            branch: "main".to_string(),
+            commit_sha: sha.to_string(),
+            actor: "testuser".to_string(),
+            html_url: "https://github.com/acme/website/actions/runs/1".to_string(),
+            logs_url: None,
+            pull_requests: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_detect_push_state() {
+        let dir = tempfile::tempdir().unwrap();
+        let repo = Repository::init_opts(dir.path(), RepositoryInitOptions::new().initial_head("main")).unwrap();
+        repo.remote("origin", "git@github.com:acme/website.git").unwrap();
+        let first = commit(&repo, "first");
+
+        let head = LocalHead::detect(dir.path()).unwrap();
+        assert!(head.is_repository("Acme/website"));
+        assert_eq!(head.branch.as_deref(), Some("main"));
+        assert_eq!(head.sha, first.to_string());
+        assert_eq!(head.push_state, PushState::NotPushed);
+
+        // What `git push -u origin main` leaves behind
+        repo.reference("refs/remotes/origin/main", first, true, "push").unwrap();
+        repo.find_branch("main", BranchType::Local).unwrap().set_upstream(Some("origin/main")).unwrap();
+        assert_eq!(LocalHead::detect(dir.path()).unwrap().push_state, PushState::Pushed);
+
+        commit(&repo, "second");
+        let third = commit(&repo, "third");
+        assert_eq!(LocalHead::detect(dir.path()).unwrap().push_state, PushState::Ahead(2));
+
+        assert_eq!(resolve_revision(dir.path(), "HEAD"), Some(third.to_string()));
+        assert_eq!(resolve_revision(dir.path(), "origin/main"), Some(first.to_string()));
+        assert_eq!(resolve_revision(dir.path(), "no-such-branch"), None);
+    }
+
+    #[test]
+    fn test_detect_outside_github_checkout() {
+        let dir = tempfile::tempdir().unwrap();
+        assert_eq!(LocalHead::detect(dir.path()), None);
+
+        // No commit yet
+        let repo = Repository::init(dir.path()).unwrap();
+        repo.remote("origin", "https://github.com/acme/website.git").unwrap();
+        assert_eq!(LocalHead::detect(dir.path()), None);
+    }
+
+    #[test]
+    fn test_status_line() {
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        let mut head = LocalHead {
+            host: Some("github.example.com".to_string()),
+            owner: "acme".to_string(),
+            name: "website".to_string(),
+            branch: Some("main".to_string()),
+            sha: sha.to_string(),
+            push_state: PushState::Pushed,
+        };
+        assert!(head.is_repository("github.example.com/acme/website"));
+        assert!(!head.is_repository("acme/website"));
+
+        assert_eq!(head.status_line(&[create_test_run("fedcba")]).unwrap(), "HEAD 0123456 on main: no CI yet for your last push");
+        assert_eq!(head.status_line(&[create_test_run(sha)]), None);
+
+        head.push_state = PushState::Ahead(1);
+        assert_eq!(head.status_line(&[]).unwrap(), "HEAD 0123456 on main: 1 commit not pushed");
+
+        head.push_state = PushState::NotPushed;
+        head.branch = None;
+        assert_eq!(head.status_line(&[]).unwrap(), "HEAD 0123456 (detached): not pushed");
+    }
+}
@@ -1,4 +1,5 @@
 pub mod icons;
+pub mod local_git;
 pub mod logging;
 pub mod repository_state;
 pub mod time;
\ No newline at end of file
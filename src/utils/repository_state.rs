@@ -60,11 +60,16 @@ mod tests {
 
     fn create_test_repo_config() -> RepositoryConfig {
         RepositoryConfig {
+            host: None,
+            credential: None,
             owner: "testowner".to_string(),
             name: "testrepo".to_string(),
             branch: None,
             workflows: None,
             enabled: true,
+            min_refresh_seconds: None,
+            max_refresh_seconds: None,
+            history_runs: None,
         }
     }
 
@@ -74,6 +79,7 @@ mod tests {
             name: "CI".to_string(),
             status: WorkflowStatus::Completed,
             conclusion,
+            run_attempt: 1,
             created_at: Utc::now(),
             updated_at: Utc::now(),
             branch: "main".to_string(),
@@ -81,6 +87,7 @@ mod tests {
             actor: "testuser".to_string(),
             html_url: format!("https://github.com/test/repo/run/{}", id),
             logs_url: Some(format!("https://github.com/test/repo/run/{}/logs", id)),
+            pull_requests: Vec::new(),
         }
     }
 
@@ -10,6 +10,8 @@ pub fn create_mock_repository(name: &str, owner: &str) -> Repository {
         full_name: format!("{}/{}", owner, name),
         html_url: format!("https://github.com/{}/{}", owner, name),
         default_branch: Some("main".to_string()),
+        host: None,
+        credential: None,
     }
 }
 
@@ -19,6 +21,7 @@ pub fn create_mock_workflow_run(id: u64, name: &str, status: WorkflowStatus) ->
         name: name.to_string(),
         status,
         conclusion: Some(WorkflowConclusion::Success),
+        run_attempt: 1,
         created_at: Utc::now(),
         updated_at: Utc::now(),
         branch: "main".to_string(),
@@ -26,5 +29,6 @@ pub fn create_mock_workflow_run(id: u64, name: &str, status: WorkflowStatus) ->
         actor: "testuser".to_string(),
         html_url: format!("https://github.com/test/repo/run/{}", id),
         logs_url: Some(format!("https://github.com/test/repo/logs/{}", id)),
+        pull_requests: Vec::new(),
     }
 }
\ No newline at end of file
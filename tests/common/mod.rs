@@ -1,2 +1,4 @@
+#![allow(dead_code)]
+
 pub mod mocks;
 pub mod test_utils;
\ No newline at end of file
@@ -4,11 +4,16 @@ use nighthub::github::models::{Repository, WorkflowRun, WorkflowStatus, Workflow
 
 pub fn create_test_repository_config(owner: &str, name: &str) -> RepositoryConfig {
     RepositoryConfig {
+        host: None,
+        credential: None,
         owner: owner.to_string(),
         name: name.to_string(),
         branch: None,
         workflows: None,
         enabled: true,
+        min_refresh_seconds: None,
+        max_refresh_seconds: None,
+        history_runs: None,
     }
 }
 
@@ -20,6 +25,8 @@ pub fn create_test_repository(id: u64, owner: &str, name: &str) -> Repository {
         full_name: format!("{}/{}", owner, name),
         html_url: format!("https://github.com/{}/{}", owner, name),
         default_branch: Some("main".to_string()),
+        host: None,
+        credential: None,
     }
 }
 
@@ -35,6 +42,7 @@ pub fn create_test_workflow_run(
         name: name.to_string(),
         status,
         conclusion,
+        run_attempt: 1,
         created_at: updated_at,
         updated_at,
         branch: "main".to_string(),
@@ -42,6 +50,7 @@ pub fn create_test_workflow_run(
         actor: "testuser".to_string(),
         html_url: format!("https://github.com/test/repo/run/{}", id),
         logs_url: Some(format!("https://github.com/test/repo/run/{}/logs", id)),
+        pull_requests: Vec::new(),
     }
 }
 
@@ -51,26 +51,44 @@ impl MockEventStream {
 
 fn create_test_settings() -> Settings {
     Settings {
-        github_token: "test_token".to_string(),
+        github_token: Some(secrecy::SecretString::new("test_token".to_string())),
+        token_file: None,
+        token_command: None,
         repositories: vec![
             RepositoryConfig {
+                host: None,
+                credential: None,
                 owner: "owner1".to_string(),
                 name: "repo1".to_string(),
                 branch: None,
                 workflows: None,
                 enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
             },
             RepositoryConfig {
+                host: None,
+                credential: None,
                 owner: "owner2".to_string(),
                 name: "repo2".to_string(),
                 branch: None,
                 workflows: None,
                 enabled: true,
+                min_refresh_seconds: None,
+                max_refresh_seconds: None,
+                history_runs: None,
             },
         ],
+        discovery: Default::default(),
+        hosts: vec![],
+        github_app: None,
+        credentials: Default::default(),
         monitoring: MonitoringConfig::default(),
         ui: UiConfig::default(),
         logging: LoggingConfig::default(),
+        notifications: Default::default(),
+        hooks: Default::default(),
     }
 }
 
@@ -84,6 +102,8 @@ fn create_mock_app_state() -> AppState {
             full_name: "owner1/repo1".to_string(),
             html_url: "https://github.com/owner1/repo1".to_string(),
             default_branch: Some("main".to_string()),
+            host: None,
+            credential: None,
         },
         Repository {
             id: 2,
@@ -92,6 +112,8 @@ fn create_mock_app_state() -> AppState {
             full_name: "owner2/repo2".to_string(),
             html_url: "https://github.com/owner2/repo2".to_string(),
             default_branch: Some("main".to_string()),
+            host: None,
+            credential: None,
         },
     ];
 
@@ -104,6 +126,7 @@ fn create_mock_app_state() -> AppState {
             name: "CI".to_string(),
             status: WorkflowStatus::Completed,
             conclusion: Some(WorkflowConclusion::Success),
+            run_attempt: 1,
             created_at: now,
             updated_at: now,
             branch: "main".to_string(),
@@ -111,12 +134,14 @@ fn create_mock_app_state() -> AppState {
             actor: "user1".to_string(),
             html_url: "https://github.com/owner1/repo1/run/1".to_string(),
             logs_url: Some("https://github.com/owner1/repo1/run/1/logs".to_string()),
+            pull_requests: Vec::new(),
         },
         WorkflowRun {
             id: 2,
             name: "Deploy".to_string(),
             status: WorkflowStatus::InProgress,
             conclusion: None,
+            run_attempt: 1,
             created_at: now,
             updated_at: now,
             branch: "main".to_string(),
@@ -124,6 +149,7 @@ fn create_mock_app_state() -> AppState {
             actor: "user2".to_string(),
             html_url: "https://github.com/owner1/repo1/run/2".to_string(),
             logs_url: Some("https://github.com/owner1/repo1/run/2/logs".to_string()),
+            pull_requests: Vec::new(),
         }
     ]);
 
@@ -133,6 +159,7 @@ fn create_mock_app_state() -> AppState {
             name: "Tests".to_string(),
             status: WorkflowStatus::Completed,
             conclusion: Some(WorkflowConclusion::Failure),
+            run_attempt: 1,
             created_at: now - chrono::Duration::hours(25),
             updated_at: now - chrono::Duration::hours(25),
             branch: "main".to_string(),
@@ -140,6 +167,7 @@ fn create_mock_app_state() -> AppState {
             actor: "user3".to_string(),
             html_url: "https://github.com/owner2/repo2/run/3".to_string(),
             logs_url: Some("https://github.com/owner2/repo2/run/3/logs".to_string()),
+            pull_requests: Vec::new(),
         }
     ]);
 
@@ -150,10 +178,25 @@ fn create_mock_app_state() -> AppState {
         selected_run: None,
         popup: None,
         context_menu: nighthub::ui::components::context_menu::ContextMenuComponent::new(),
+        job_list: nighthub::ui::components::job_list::JobListComponent::new(),
+        run_history: nighthub::ui::components::run_history::RunHistoryComponent::new(),
+        pull_request_list: nighthub::ui::components::pull_request_list::PullRequestListComponent::new(),
+        log_viewer: nighthub::ui::components::log_viewer::LogViewerComponent::new(),
+        confirm_dialog: nighthub::ui::components::confirm_dialog::ConfirmDialogComponent::new(),
+        dispatch_form: nighthub::ui::components::dispatch_form::DispatchFormComponent::new(),
+        theme: nighthub::ui::theme::Theme::default(),
         settings,
-        github_client: nighthub::github::client::GithubClient::new(create_test_settings()).unwrap(),
+        github_clients: nighthub::github::pool::ClientPool::new(&create_test_settings()).unwrap(),
         last_repo_refresh_times: HashMap::new(),
         refreshing_repos: std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashSet::new())),
+        refresher: nighthub::ui::refresh::Refresher::new(5),
+        loader: Default::default(),
+        discovery: nighthub::ui::refresh::DiscoveryTask::default(),
+        local_checkout: nighthub::utils::local_git::LocalCheckout::default(),
+        notifications: nighthub::notify::Notifications::default(),
+        hooks: nighthub::notify::hooks::ShellHooks::default(),
+        transitions: Default::default(),
+        fetched_runs: HashMap::new(),
     }
 }
 
@@ -240,8 +283,8 @@ mod main_application_tests {
     async fn test_keyboard_event_handling_popup() {
         let mut app_state = create_mock_app_state();
         
-        // Test Enter key - open context menu
-        app_state.handle_key("enter");
+        // Test m key - open context menu
+        app_state.handle_key("m");
         assert!(app_state.popup.is_some());
         assert_eq!(app_state.popup, Some(nighthub::ui::app::PopupType::ContextMenu));
         
@@ -285,7 +328,9 @@ mod main_application_tests {
         
         // Verify data preparation for rendering
         assert_eq!(repo_names.len(), 2);
-        assert!(seconds_until_refresh >= 0);
+        assert_eq!(seconds_until_refresh, 0); // Never refreshed
+        assert_eq!(workflow_list.selected_repo_index, 0);
+        assert_eq!(workflow_list.selected_run_index, 0);
         assert!(app_state.workflow_runs.contains_key("owner1/repo1"));
         assert!(app_state.workflow_runs.contains_key("owner2/repo2"));
     }
@@ -364,7 +409,7 @@ mod main_application_tests {
         
         // Test rendering data preparation with popup
         let repo_names: Vec<String> = app_state.repositories.iter().map(|r| r.full_name.clone()).collect();
-        let seconds_until_refresh = app_state.seconds_until_refresh();
+        let _seconds_until_refresh = app_state.seconds_until_refresh();
         
         // Verify popup state and rendering data
         assert!(app_state.popup.is_some());
@@ -434,13 +479,12 @@ mod main_application_tests {
         while let Some(event) = event_stream.next_event() {
             if let Event::Key(key) = event && key.kind == KeyEventKind::Press {
                 match key.code {
-                    KeyCode::Char('j') | KeyCode::Down => {
-                        if app_state.popup.is_none() {
+                    KeyCode::Char('j') | KeyCode::Down
+                        if app_state.popup.is_none() => {
                             workflow_list.next_repo(app_state.repositories.len());
                             app_state.selected_repo = Some(workflow_list.selected_repo_index);
                             app_state.selected_run = Some(workflow_list.selected_run_index);
                         }
-                    }
                     _ => {}
                 }
             }
@@ -471,8 +515,8 @@ mod main_application_tests {
         for event in exit_events {
             let mut should_exit = false;
             
-            if let Event::Key(key) = event {
-                if key.kind == KeyEventKind::Press {
+            if let Event::Key(key) = event
+                && key.kind == KeyEventKind::Press {
                     match key.code {
                         KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
                             should_exit = true;
@@ -483,7 +527,6 @@ mod main_application_tests {
                         _ => {}
                     }
                 }
-            }
             
             assert!(should_exit);
         }
@@ -520,11 +563,19 @@ mod main_application_tests {
     async fn test_empty_repositories_handling() {
         // Test application behavior with no repositories
         let settings = Settings {
-            github_token: "test_token".to_string(),
+            github_token: Some(secrecy::SecretString::new("test_token".to_string())),
+            token_file: None,
+            token_command: None,
             repositories: vec![],
+            discovery: Default::default(),
+            hosts: vec![],
+            github_app: None,
+            credentials: Default::default(),
             monitoring: MonitoringConfig::default(),
             ui: UiConfig::default(),
             logging: LoggingConfig::default(),
+            notifications: Default::default(),
+            hooks: Default::default(),
         };
         
         let app_state = AppState {
@@ -534,10 +585,25 @@ mod main_application_tests {
             selected_run: None,
             popup: None,
             context_menu: nighthub::ui::components::context_menu::ContextMenuComponent::new(),
+            job_list: nighthub::ui::components::job_list::JobListComponent::new(),
+            run_history: nighthub::ui::components::run_history::RunHistoryComponent::new(),
+            pull_request_list: nighthub::ui::components::pull_request_list::PullRequestListComponent::new(),
+            log_viewer: nighthub::ui::components::log_viewer::LogViewerComponent::new(),
+            confirm_dialog: nighthub::ui::components::confirm_dialog::ConfirmDialogComponent::new(),
+            dispatch_form: nighthub::ui::components::dispatch_form::DispatchFormComponent::new(),
+            theme: nighthub::ui::theme::Theme::default(),
             settings,
-            github_client: nighthub::github::client::GithubClient::new(create_test_settings()).unwrap(),
+            github_clients: nighthub::github::pool::ClientPool::new(&create_test_settings()).unwrap(),
             last_repo_refresh_times: HashMap::new(),
             refreshing_repos: std::sync::Arc::new(std::sync::RwLock::new(std::collections::HashSet::new())),
+            refresher: nighthub::ui::refresh::Refresher::new(5),
+            loader: Default::default(),
+            discovery: nighthub::ui::refresh::DiscoveryTask::default(),
+            local_checkout: nighthub::utils::local_git::LocalCheckout::default(),
+            notifications: nighthub::notify::Notifications::default(),
+            hooks: nighthub::notify::hooks::ShellHooks::default(),
+            transitions: Default::default(),
+            fetched_runs: HashMap::new(),
         };
         
         let mut workflow_list = WorkflowListComponent::new();
@@ -594,7 +660,7 @@ mod main_application_tests {
 
     #[tokio::test]
     async fn test_manual_refresh_with_f_key() {
-        let mut app_state = create_mock_app_state();
+        let app_state = create_mock_app_state();
 
         // Verify that no popup is open (condition for refresh)
         assert!(app_state.popup.is_none());
@@ -613,7 +679,7 @@ mod main_application_tests {
         app_state.open_context_menu(); // Open a popup
         
         // Simulate 'f' key press when popup is open
-        let f_key_event = Event::Key(KeyEvent {
+        let _f_key_event = Event::Key(KeyEvent {
             code: KeyCode::Char('f'),
             modifiers: crossterm::event::KeyModifiers::NONE,
             kind: KeyEventKind::Press,
@@ -625,6 +691,6 @@ mod main_application_tests {
 
         // Verify that refresh would not be triggered when popup is open
         // This test verifies the condition prevents refresh
-        assert!(true); // Placeholder - actual behavior testing
+        assert_eq!(app_state.popup, Some(nighthub::ui::app::PopupType::ContextMenu));
     }
 }
\ No newline at end of file